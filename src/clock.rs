@@ -3,14 +3,14 @@ use crate::{Instant, LocalDateTime, ZoneId, ZoneOffset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FixedClock {
     instant: Instant,
     zone: ZoneId,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SystemClock {
     zone: ZoneId,
@@ -35,7 +35,7 @@ pub struct SystemClock {
 ///     Clock::System(_) => println!("This is a system clock."),
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Clock {
     Fixed(FixedClock),
@@ -74,7 +74,7 @@ impl Clock {
     /// let fixed_clock = Clock::fixed_at_local(datetime, ZoneId::UTC);
     /// ```
     pub fn fixed_at_local(ldt: LocalDateTime, zone: ZoneId) -> Self {
-        Clock::fixed(ldt.at_zone(zone).to_instant(), zone)
+        Clock::fixed(ldt.at_zone(zone.clone()).to_instant(), zone)
     }
 
     /// Creates a new `Clock` instance configured to use the system clock in the specified `zone`.
@@ -86,13 +86,14 @@ impl Clock {
         Clock::System(SystemClock { zone })
     }
 
-    /// Creates a new `Clock` instance configured to use the system clock in the system default time zone.
+    /// Creates a new `Clock` instance configured to use the system clock in the system default time zone,
+    /// as determined by [`ZoneId::system_default`] when the clock is created.
     ///
     /// ```rust
     /// let system_clock = Clock::system_default_zone();
     /// ```
     pub fn system_default_zone() -> Self {
-        Clock::System(SystemClock { zone: ZoneId::UTC })
+        Clock::System(SystemClock { zone: ZoneId::system_default() })
     }
 
     /// Creates a new `Clock` instance configured to use the system clock in the UTC time zone..
@@ -131,10 +132,10 @@ impl Clock {
     /// let system_clock = Clock::System(SystemClock { zone: ZoneId::Local });
     /// assert_eq!(system_clock.zone(), ZoneId::Local);
     /// ```
-    pub fn zone(self) -> ZoneId {
+    pub fn zone(&self) -> ZoneId {
         match self {
-            Clock::Fixed(fc) => fc.zone,
-            Clock::System(sc) => sc.zone,
+            Clock::Fixed(fc) => fc.zone.clone(),
+            Clock::System(sc) => sc.zone.clone(),
         }
     }
 
@@ -154,7 +155,7 @@ impl Clock {
     /// let timestamp = some_object.millis();
     /// println!("Milliseconds since epoch: {}", timestamp);
     /// ```
-    pub fn milliseconds(self) -> i64 {
+    pub fn milliseconds(&self) -> i64 {
         let odt = self
             .instant()
             .at_offset(ZoneOffset::of_hours(0))
@@ -171,7 +172,7 @@ impl Clock {
     /// assert!(matches!(updated_clock, Clock::Fixed(_)));
     ///
     /// let system_clock = Clock::System(SystemClock { zone: ZoneId::
-    pub fn with_zone(self, zone: ZoneId) -> Self {
+    pub fn with_zone(&self, zone: ZoneId) -> Self {
         match self {
             Clock::Fixed(fc) => Clock::Fixed(FixedClock {
                 instant: fc.instant,
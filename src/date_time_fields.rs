@@ -0,0 +1,306 @@
+use crate::{
+    DateTimeFormatter, DateTimeParseError, Instant, LocalDate, LocalDateTime, LocalTime, Month, MonthDay, OffsetDateTime,
+    Year, YearMonth, YearWeek, ZoneId, ZoneOffset, ZoneResolver, ZonedDateTime,
+};
+
+/// The fields of a date-time value, as read for formatting or produced by parsing with a
+/// [`DateTimeFormatter`].
+///
+/// Every field is optional: a `LocalDate` has no time, a `YearMonth` has no day and only a
+/// `ZonedDateTime` has a zone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DateTimeFields {
+    pub(crate) year: Option<i32>,
+    pub(crate) month: Option<Month>,
+    pub(crate) day: Option<u8>,
+    pub(crate) date: Option<LocalDate>,
+    pub(crate) time: Option<LocalTime>,
+    pub(crate) offset: Option<ZoneOffset>,
+    pub(crate) zone: Option<ZoneId>,
+    /// Whether a parsed zone name was the daylight (`Some(true)`) or standard
+    /// (`Some(false)`) name, such as `EDT` or `EST`.
+    pub(crate) daylight: Option<bool>,
+    /// The ISO week-based year and week of a value without a full date, such as a
+    /// [`YearWeek`](crate::YearWeek).
+    pub(crate) week: Option<(i32, u8)>,
+}
+
+impl DateTimeFields {
+    /// Returns the proleptic year, where 0 is 1 BC.
+    pub fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Returns the month of the year.
+    pub fn month(&self) -> Option<Month> {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day_of_month(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Returns the date, if the year, month and day are all known.
+    pub fn date(&self) -> Option<LocalDate> {
+        self.date
+    }
+
+    /// Returns the time of day.
+    pub fn time(&self) -> Option<LocalTime> {
+        self.time
+    }
+
+    /// Returns the offset from UTC.
+    pub fn offset(&self) -> Option<ZoneOffset> {
+        self.offset
+    }
+
+    /// Returns the time zone.
+    pub fn zone(&self) -> Option<ZoneId> {
+        self.zone.clone()
+    }
+
+    /// Returns the ISO week-based year, see [`LocalDate::week_based_year`].
+    pub fn week_based_year(&self) -> Option<i32> {
+        self.date.map(LocalDate::week_based_year).or(self.week.map(|(year, _)| year))
+    }
+
+    /// Returns the ISO week of the week-based year, see [`LocalDate::week_of_week_based_year`].
+    pub fn week_of_week_based_year(&self) -> Option<u8> {
+        self.date.map(|date| date.week_of_week_based_year() as u8).or(self.week.map(|(_, week)| week))
+    }
+
+    /// Returns the local date-time, if both the date and the time are known.
+    pub fn local_date_time(&self) -> Option<LocalDateTime> {
+        Some(LocalDateTime::of_date_time(self.date?, self.time?))
+    }
+
+    /// Returns the zoned date-time, if the date, the time and an offset or a zone are known.
+    ///
+    /// A parsed offset fixes the instant and the zone then gives the local date-time, like
+    /// `java.time.ZonedDateTime.from`. Without an offset the local date-time is resolved in
+    /// the zone, taking a parsed standard or daylight zone name into account.
+    ///
+    /// Returns `None` as well if the instant needed for that is outside the supported range.
+    pub fn zoned_date_time(&self) -> Option<ZonedDateTime> {
+        self.try_zoned_date_time()?.ok()
+    }
+
+    /// Returns the instant, if the date, the time and an offset or a zone are known and the
+    /// instant is inside the supported range.
+    pub fn instant(&self) -> Option<Instant> {
+        self.try_instant()?.ok()
+    }
+
+    /// Like [`DateTimeFields::zoned_date_time`], but tells missing fields (`None`) apart
+    /// from an instant outside the supported range.
+    fn try_zoned_date_time(&self) -> Option<Result<ZonedDateTime, &'static str>> {
+        let ldt = self.local_date_time()?;
+        let same_instant = |offset: ZoneOffset, zone: ZoneId| {
+            ZonedDateTime::checked_resolve_instant(ldt.at_offset(offset).inner(), zone).ok_or("Instant exceeds the supported range")
+        };
+        match (self.zone.clone(), self.offset) {
+            (Some(zone), Some(offset)) => Some(same_instant(offset, zone)),
+            (None, Some(offset)) => Some(Ok(ZonedDateTime::of(ldt, ZoneId::from(offset)))),
+            (Some(zone), None) => {
+                let zdt = match ZoneResolver::ShiftForward.resolve(ldt, zone.clone()) {
+                    Ok(zdt) => zdt,
+                    Err(message) => return Some(Err(message)),
+                };
+                let Some(daylight) = self.daylight else { return Some(Ok(zdt)) };
+                let instant = match zdt.try_to_instant() {
+                    Ok(instant) => instant,
+                    Err(message) => return Some(Err(message)),
+                };
+                let rules = zone.rules();
+                if rules.is_daylight_savings(instant) == daylight {
+                    return Some(Ok(zdt));
+                }
+                let standard = rules.standard_offset(instant);
+                let offset = if daylight {
+                    // The amount of daylight saving time, 30 minutes on Lord Howe Island,
+                    // is that of the nearest daylight saving period.
+                    let nearest = [
+                        rules.next_transition(instant).map(|transition| transition.instant()),
+                        rules.previous_transition(instant).map(|transition| transition.instant().minus_seconds(1)),
+                    ];
+                    let amount = nearest.into_iter().flatten().map(|at| rules.daylight_savings(at)).find(|amount| !amount.is_zero());
+                    let Some(amount) = amount else { return Some(Ok(zdt)) };
+                    ZoneOffset::of_total_seconds(standard.total_seconds() + amount.to_seconds() as i32)
+                } else {
+                    standard
+                };
+                Some(same_instant(offset, zone))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Like [`DateTimeFields::instant`], but tells missing fields (`None`) apart from an
+    /// instant outside the supported range.
+    pub(crate) fn try_instant(&self) -> Option<Result<Instant, &'static str>> {
+        Some(self.try_zoned_date_time()?.and_then(|zdt| zdt.try_to_instant()))
+    }
+
+    fn of_date(date: LocalDate) -> Self {
+        DateTimeFields {
+            year: Some(date.year()),
+            month: Some(date.month()),
+            day: Some(date.day_of_month() as u8),
+            date: Some(date),
+            ..Self::default()
+        }
+    }
+}
+
+/// A date-time type that a [`DateTimeFormatter`] can format and
+/// parse, in the style of `java.time.temporal.TemporalAccessor` and the `from` methods of
+/// the java.time types.
+///
+/// Implemented by every date and time type of this crate.
+pub trait TemporalAccessor: Sized {
+    /// Returns the fields of this value.
+    fn to_fields(&self) -> DateTimeFields;
+
+    /// Obtains a value from the fields, typically the result of parsing.
+    ///
+    /// # Errors
+    /// Returns an error if the fields do not contain what this type needs.
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str>;
+
+    /// Formats this value with the formatter, like `LocalDate.format(formatter)` in java.time.
+    ///
+    /// # Panics
+    /// Panics if the pattern uses a field this value does not have.
+    fn format(&self, formatter: &DateTimeFormatter) -> String {
+        formatter.format(self)
+    }
+
+    /// Parses text with the formatter, like `LocalDate.parse(text, formatter)` in java.time.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("dd.MM.uuuu");
+    /// assert_eq!(LocalDate::parse_with("05.03.2024", &formatter), Ok(LocalDate::of(2024, 3, 5)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the text does not match the pattern or does not make up a `Self`.
+    fn parse_with(text: &str, formatter: &DateTimeFormatter) -> Result<Self, DateTimeParseError> {
+        formatter.parse(text)
+    }
+}
+
+impl TemporalAccessor for LocalDate {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields::of_date(*self)
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.date.ok_or("Unable to obtain LocalDate from the parsed fields")
+    }
+}
+
+impl TemporalAccessor for LocalTime {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { time: Some(*self), ..DateTimeFields::default() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.time.ok_or("Unable to obtain LocalTime from the parsed fields")
+    }
+}
+
+impl TemporalAccessor for LocalDateTime {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { time: Some(self.to_local_time()), ..DateTimeFields::of_date(self.to_local_date()) }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.local_date_time().ok_or("Unable to obtain LocalDateTime from the parsed fields")
+    }
+}
+
+impl TemporalAccessor for OffsetDateTime {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { offset: Some(ZoneOffset::from(self.inner().offset())), ..self.to_local_date_time().to_fields() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        match (fields.local_date_time(), fields.offset) {
+            (Some(ldt), Some(offset)) => Ok(ldt.at_offset(offset)),
+            _ => Ok(fields.try_zoned_date_time().ok_or("Unable to obtain OffsetDateTime from the parsed fields")??.to_offset_date_time()),
+        }
+    }
+}
+
+impl TemporalAccessor for ZonedDateTime {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { offset: Some(self.offset()), zone: Some(self.zone()), ..self.to_local_date_time().to_fields() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.try_zoned_date_time().ok_or("Unable to obtain ZonedDateTime from the parsed fields")?
+    }
+}
+
+/// An instant has no date or time of its own; it is formatted in UTC unless the formatter
+/// has a zone, see [`DateTimeFormatter::with_zone`](crate::DateTimeFormatter::with_zone).
+impl TemporalAccessor for Instant {
+    fn to_fields(&self) -> DateTimeFields {
+        self.at_offset(ZoneOffset::UTC).to_fields()
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.try_instant().ok_or("Unable to obtain Instant from the parsed fields")?
+    }
+}
+
+impl TemporalAccessor for Year {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { year: Some(self.value()), ..DateTimeFields::default() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        fields.year.map(Year::of).ok_or("Unable to obtain Year from the parsed fields")
+    }
+}
+
+impl TemporalAccessor for YearMonth {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { year: Some(self.year()), month: Some(self.month()), ..DateTimeFields::default() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        match (fields.year, fields.month) {
+            (Some(year), Some(month)) => Ok(YearMonth::of_year_month(year, month)),
+            _ => Err("Unable to obtain YearMonth from the parsed fields"),
+        }
+    }
+}
+
+impl TemporalAccessor for YearWeek {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { week: Some((self.year(), self.week() as u8)), ..DateTimeFields::default() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        match (fields.week_based_year(), fields.week_of_week_based_year()) {
+            (Some(year), Some(week)) => YearWeek::try_of(year, i32::from(week)),
+            _ => Err("Unable to obtain YearWeek from the parsed fields"),
+        }
+    }
+}
+
+impl TemporalAccessor for MonthDay {
+    fn to_fields(&self) -> DateTimeFields {
+        DateTimeFields { month: Some(self.month()), day: Some(self.day_of_month()), ..DateTimeFields::default() }
+    }
+
+    fn from_fields(fields: &DateTimeFields) -> Result<Self, &'static str> {
+        match (fields.month, fields.day) {
+            (Some(month), Some(day)) if day <= month.max_length() as u8 => Ok(MonthDay::of_month_day(month, day)),
+            _ => Err("Unable to obtain MonthDay from the parsed fields"),
+        }
+    }
+}
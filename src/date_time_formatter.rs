@@ -0,0 +1,1729 @@
+use crate::zone_id::{localized_offset, parse_zone_name};
+use crate::{Clock, DateTimeFields, DateTimeParseError, DayOfWeek, FormatStyle, LocalDate, LocalTime, Locale, Month, OffsetConflict, ResolverStyle, TemporalAccessor, TextStyle, ZoneId, ZoneOffset, ZonedDateTime};
+
+/// A formatter for printing and parsing date-time values, in the style of
+/// `java.time.format.DateTimeFormatter`.
+///
+/// Create one from a pattern with [`DateTimeFormatter::of_pattern`], then format any date or
+/// time type of this crate with [`DateTimeFormatter::format`] and parse text back with
+/// [`DateTimeFormatter::parse`]. The pattern letters are those of java.time:
+///
+/// | Letter | Meaning                    | Examples                                   |
+/// |--------|----------------------------|--------------------------------------------|
+/// | `G`    | era                        | `AD`; `Anno Domini`; `A`                   |
+/// | `u`    | year                       | `2004`; `04`                               |
+/// | `y`    | year-of-era                | `2004`; `04`                               |
+/// | `D`    | day-of-year                | `189`                                      |
+/// | `M`/`L`| month-of-year              | `7`; `07`; `Jul`; `July`; `J`              |
+/// | `d`    | day-of-month               | `10`                                       |
+/// | `E`    | day-of-week                | `Tue`; `Tuesday`; `T`                      |
+/// | `Y`    | week-based-year            | `1996`; `96`                               |
+/// | `w`    | week-of-week-based-year    | `27`                                       |
+/// | `e`/`c`| day-of-week                | `2`; `Tue`; `Tuesday`; `T`                 |
+/// | `a`    | am-pm-of-day               | `PM`                                       |
+/// | `h`    | clock-hour-of-am-pm (1-12) | `12`                                       |
+/// | `K`    | hour-of-am-pm (0-11)       | `0`                                        |
+/// | `k`    | clock-hour-of-day (1-24)   | `24`                                       |
+/// | `H`    | hour-of-day (0-23)         | `0`                                        |
+/// | `m`    | minute-of-hour             | `30`                                       |
+/// | `s`    | second-of-minute           | `55`                                       |
+/// | `S`    | fraction-of-second         | `978`                                      |
+/// | `n`    | nano-of-second             | `987654321`                                |
+/// | `VV`   | time-zone ID               | `America/Los_Angeles`; `Z`; `-08:30`       |
+/// | `z`    | time-zone name             | `PST`; `Pacific Standard Time`             |
+/// | `O`    | localized zone-offset      | `GMT+8`; `GMT+08:00`                       |
+/// | `X`    | zone-offset, `Z` for zero  | `Z`; `-08`; `-0830`; `-08:30`              |
+/// | `x`    | zone-offset                | `+0000`; `-08`; `-0830`; `-08:30`          |
+/// | `Z`    | zone-offset                | `+0000`; `-0800`; `-08:00`                 |
+///
+/// Text between single quotes is literal (`''` is a single quote), other characters that are
+/// not letters are literal, and `[` … `]` encloses an optional section. Text fields such as
+/// month and day names are matched without regard to case when parsing. They are in the
+/// formatter's locale, `Locale::US` unless set with [`DateTimeFormatter::with_locale`]; `L`
+/// gives the standalone form of the month name. The week fields `Y`, `w`, `e` and `c` are
+/// those of ISO-8601 weeks, which start on Monday, whatever the locale: `e` is 1 for Monday.
+///
+/// ```rust
+/// let formatter = DateTimeFormatter::of_pattern("yyyy-MM-dd HH:mm:ss.SSS XXX");
+/// let odt = OffsetDateTime::of(LocalDateTime::of(2024, 3, 5, 14, 7, 9), ZoneOffset::of_hours(2));
+///
+/// assert_eq!(formatter.format(&odt), "2024-03-05 14:07:09.000 +02:00");
+/// assert_eq!(formatter.parse::<OffsetDateTime>("2024-03-05 14:07:09.000 +02:00"), Ok(odt));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeFormatter {
+    elements: Vec<Element>,
+    zone: Option<ZoneId>,
+    offset_conflict: OffsetConflict,
+    locale: Locale,
+    resolver_style: ResolverStyle,
+    clock: Clock,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Element {
+    Literal(String),
+    /// A numeric field of `min` to `max` digits. `reserved` is the number of digits taken
+    /// by the fixed-width numeric fields that directly follow, which are left to them when
+    /// parsing (`yyyyMMdd`).
+    Number { field: Field, min: usize, max: usize, sign: SignStyle, reserved: usize },
+    /// A two-digit year in the range 2000 to 2099.
+    ReducedYear(Field),
+    /// The fraction of the second in `min` to `max` digits, truncated and without trailing
+    /// zeros beyond `min`. With `decimal_point` it is preceded by `.`, which is left out
+    /// together with the digits when there are none to print.
+    Fraction { min: usize, max: usize, decimal_point: bool },
+    Text { field: TextField, style: TextStyle },
+    Offset { pattern: OffsetPattern, zero: &'static str },
+    LocalizedOffset { full: bool },
+    ZoneText { full: bool },
+    /// The zone ID; with `region_only`, zones that are just an offset are not printed.
+    ZoneId { region_only: bool },
+    /// The optional RFC 9557 suffix: a region ID in brackets, such as `[Europe/Paris]`,
+    /// followed when parsing by any number of tags such as `[u-ca=iso8601]`.
+    Rfc9557Suffix,
+    /// A whole RFC 2822 date-time such as `Sun, 06 Nov 1994 08:49:37 +0000`, printed by
+    /// `format`, with `GMT` instead of the offset for HTTP dates. Parsing also accepts the
+    /// obsolete RFC 2822 syntax and the RFC 850 and asctime forms of HTTP dates.
+    LegacyDateTime { gmt: bool, format: Vec<Element> },
+    Optional(Vec<Element>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Year,
+    YearOfEra,
+    MonthOfYear,
+    DayOfMonth,
+    DayOfYear,
+    WeekBasedYear,
+    WeekOfWeekBasedYear,
+    /// The ISO day-of-week number, 1 for Monday to 7 for Sunday.
+    DayOfWeek,
+    HourOfDay,
+    ClockHourOfDay,
+    HourOfAmPm,
+    ClockHourOfAmPm,
+    MinuteOfHour,
+    SecondOfMinute,
+    NanoOfSecond,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextField {
+    Era,
+    MonthOfYear,
+    DayOfWeek,
+    AmPm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignStyle {
+    /// A sign only for negative values.
+    Normal,
+    /// A sign for negative values and for positive values wider than the minimum width.
+    ExceedsPad,
+    NotNegative,
+}
+
+/// The shape of an offset such as `+HH:MM`: hours are always present, minutes and seconds
+/// are `Required`, `Optional` (only when not zero) or `Absent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct OffsetPattern {
+    minutes: Presence,
+    seconds: Presence,
+    colons: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presence {
+    Absent,
+    Optional,
+    Required,
+}
+
+/// Why formatting an element failed: a field the value does not have, which makes an
+/// optional section be skipped, or a value that cannot be printed.
+enum FormatError {
+    Unsupported(&'static str),
+    Invalid(&'static str),
+}
+
+/// Where parsing failed and what was expected there.
+struct ParseError {
+    index: usize,
+    expected: &'static str,
+}
+
+/// The values read while parsing, before they are resolved into [`DateTimeFields`].
+#[derive(Debug, Clone, Default)]
+struct Parsed {
+    values: [Option<i64>; SLOTS],
+    offset: Option<ZoneOffset>,
+    zone: Option<ZoneId>,
+    daylight: Option<bool>,
+    /// Where each value, the offset and the zone start in the text, to report errors found
+    /// when resolving at the offending field.
+    starts: [Option<usize>; SLOTS],
+    offset_start: Option<usize>,
+    zone_start: Option<usize>,
+}
+
+const SLOTS: usize = 17;
+
+/// The slots a year can be parsed into, where errors about the year are reported.
+const YEAR: &[Slot] = &[Slot::Year, Slot::YearOfEra, Slot::WeekBasedYear];
+/// The slots an hour can be parsed into, where errors about the hour are reported.
+const HOURS: &[Slot] = &[Slot::HourOfDay, Slot::ClockHourOfDay, Slot::HourOfAmPm, Slot::ClockHourOfAmPm];
+
+/// The parsed values, indexing [`Parsed::values`].
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    Era,
+    Year,
+    YearOfEra,
+    MonthOfYear,
+    DayOfMonth,
+    DayOfYear,
+    DayOfWeek,
+    WeekBasedYear,
+    WeekOfWeekBasedYear,
+    HourOfDay,
+    ClockHourOfDay,
+    HourOfAmPm,
+    ClockHourOfAmPm,
+    AmPm,
+    MinuteOfHour,
+    SecondOfMinute,
+    NanoOfSecond,
+}
+
+const ERAS: [[&str; 2]; 3] = [["Before Christ", "Anno Domini"], ["BC", "AD"], ["B", "A"]];
+
+impl DateTimeFormatter {
+    /// Creates a formatter from a pattern of java.time pattern letters, such as
+    /// `"yyyy-MM-dd HH:mm:ss"`. See [`DateTimeFormatter`] for the letters.
+    ///
+    /// # Panics
+    /// Panics if the pattern is invalid, see [`DateTimeFormatter::try_of_pattern`].
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("EEEE, d MMMM uuuu");
+    /// assert_eq!(formatter.format(&LocalDate::of(2024, 7, 4)), "Thursday, 4 July 2024");
+    /// ```
+    pub fn of_pattern(pattern: &str) -> Self {
+        Self::try_of_pattern(pattern).expect("invalid pattern")
+    }
+
+    /// Creates a formatter from a pattern of java.time pattern letters.
+    ///
+    /// # Errors
+    /// Returns an error for an unknown pattern letter, a letter repeated more times than it
+    /// allows, an unterminated quote or an unbalanced optional section.
+    pub fn try_of_pattern(pattern: &str) -> Result<Self, &'static str> {
+        let mut chars = pattern.chars().peekable();
+        let elements = compile(&mut chars, false)?;
+        Ok(DateTimeFormatter::of_elements(elements))
+    }
+
+    /// Creates a formatter for the localized date format of a locale, like
+    /// `DateTimeFormatter.ofLocalizedDate(dateStyle).withLocale(locale)`. The patterns are
+    /// those of CLDR.
+    ///
+    /// ```rust
+    /// let date = LocalDate::of(2025, 1, 5);
+    /// assert_eq!(DateTimeFormatter::of_localized_date(FormatStyle::Medium, Locale::US).format(&date), "Jan 5, 2025");
+    /// assert_eq!(DateTimeFormatter::of_localized_date(FormatStyle::Medium, Locale::FRANCE).format(&date), "5 janv. 2025");
+    /// assert_eq!(DateTimeFormatter::of_localized_date(FormatStyle::Short, Locale::GERMANY).format(&date), "05.01.25");
+    /// ```
+    pub fn of_localized_date(date_style: FormatStyle, locale: Locale) -> Self {
+        Self::of_pattern(locale.patterns().dates[date_style as usize]).with_locale(locale)
+    }
+
+    /// Creates a formatter for the localized time format of a locale, like
+    /// `DateTimeFormatter.ofLocalizedTime(timeStyle).withLocale(locale)`.
+    ///
+    /// The full and long styles include the time-zone name, so they can only format values
+    /// with a zone, such as a `ZonedDateTime`. Zone names are in English for English
+    /// locales, and in the localized GMT format of the language otherwise, such as
+    /// `UTC−05:00` in French.
+    ///
+    /// ```rust
+    /// let time = LocalTime::of(15, 30, 0);
+    /// assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Short, Locale::US).format(&time), "3:30 PM");
+    /// assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Medium, Locale::GERMANY).format(&time), "15:30:00");
+    /// ```
+    pub fn of_localized_time(time_style: FormatStyle, locale: Locale) -> Self {
+        Self::of_pattern(locale.patterns().times[time_style as usize]).with_locale(locale)
+    }
+
+    /// Creates a formatter for the localized date-time format of a locale, with the same
+    /// style for the date and the time, like
+    /// `DateTimeFormatter.ofLocalizedDateTime(dateTimeStyle).withLocale(locale)`.
+    ///
+    /// ```rust
+    /// let ldt = LocalDateTime::of(2025, 1, 5, 15, 30, 0);
+    /// let formatter = DateTimeFormatter::of_localized_date_time(FormatStyle::Medium, Locale::US);
+    /// assert_eq!(formatter.format(&ldt), "Jan 5, 2025, 3:30:00 PM");
+    /// ```
+    pub fn of_localized_date_time(date_time_style: FormatStyle, locale: Locale) -> Self {
+        Self::of_localized_date_time_styles(date_time_style, date_time_style, locale)
+    }
+
+    /// Creates a formatter for the localized date-time format of a locale, with a style
+    /// each for the date and the time, like
+    /// `DateTimeFormatter.ofLocalizedDateTime(dateStyle, timeStyle).withLocale(locale)`.
+    /// The date style chooses how the two are joined.
+    ///
+    /// ```rust
+    /// let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(2025, 1, 5, 15, 30, 0), paris);
+    /// let formatter = DateTimeFormatter::of_localized_date_time_styles(FormatStyle::Full, FormatStyle::Short, Locale::FRANCE);
+    /// assert_eq!(formatter.format(&zdt), "dimanche 5 janvier 2025 à 15:30");
+    /// ```
+    pub fn of_localized_date_time_styles(date_style: FormatStyle, time_style: FormatStyle, locale: Locale) -> Self {
+        let patterns = locale.patterns();
+        let pattern = patterns.date_times[date_style as usize]
+            .replace("{1}", patterns.dates[date_style as usize])
+            .replace("{0}", patterns.times[time_style as usize]);
+        Self::of_pattern(&pattern).with_locale(locale)
+    }
+
+    /// Returns the ISO-8601 date formatter, such as `2024-03-05`, like
+    /// `DateTimeFormatter.ISO_LOCAL_DATE`. Years outside 0000 to 9999 have a sign.
+    pub fn iso_local_date() -> Self {
+        Self::of_pattern("uuuu-MM-dd").with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 week date formatter, such as `2024-W05-3` for Wednesday of the
+    /// fifth week of 2024, like `DateTimeFormatter.ISO_WEEK_DATE`. The day of the week is
+    /// optional, so that it also formats and parses a [`YearWeek`](crate::YearWeek) as
+    /// `2024-W05`.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::iso_week_date();
+    /// assert_eq!(formatter.format(&LocalDate::of(2024, 1, 31)), "2024-W05-3");
+    /// assert_eq!(formatter.format(&LocalDate::of(2024, 12, 30)), "2025-W01-1");
+    /// assert_eq!(formatter.parse::<LocalDate>("2020-W53-5"), Ok(LocalDate::of(2021, 1, 1)));
+    /// ```
+    pub fn iso_week_date() -> Self {
+        let number = |field, min, max, sign| Element::Number { field, min, max, sign, reserved: 0 };
+        let elements = vec![
+            number(Field::WeekBasedYear, 4, 19, SignStyle::ExceedsPad),
+            Element::Literal("-W".to_string()),
+            number(Field::WeekOfWeekBasedYear, 2, 2, SignStyle::NotNegative),
+            Element::Optional(vec![Element::Literal("-".to_string()), number(Field::DayOfWeek, 1, 1, SignStyle::NotNegative)]),
+        ];
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 ordinal date formatter, such as `2024-045` for February 14, like
+    /// `DateTimeFormatter.ISO_ORDINAL_DATE`.
+    pub fn iso_ordinal_date() -> Self {
+        Self::of_pattern("uuuu-DDD").with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 time formatter, such as `14:07:09` or `14:07:09.123`, like
+    /// `DateTimeFormatter.ISO_LOCAL_TIME`. The fraction is printed only when not zero, and
+    /// the seconds are optional when parsing.
+    pub fn iso_local_time() -> Self {
+        DateTimeFormatter::of_elements(iso_time()).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 date-time formatter, such as `2024-03-05T14:07:09`, like
+    /// `DateTimeFormatter.ISO_LOCAL_DATE_TIME`.
+    pub fn iso_local_date_time() -> Self {
+        let mut elements = Self::iso_local_date().elements;
+        elements.push(Element::Literal("T".to_string()));
+        elements.extend(iso_time());
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 date-time formatter with an offset, such as
+    /// `2024-03-05T14:07:09+01:00`, like `DateTimeFormatter.ISO_OFFSET_DATE_TIME`.
+    pub fn iso_offset_date_time() -> Self {
+        let mut elements = Self::iso_local_date_time().elements;
+        elements.extend(Self::of_pattern("XXXXX").elements);
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-like date-time formatter with an offset and a zone, such as
+    /// `2024-03-05T14:07:09+01:00[Europe/Paris]`, like `DateTimeFormatter.ISO_ZONED_DATE_TIME`.
+    /// The zone in brackets is only printed for region IDs and is optional when parsing.
+    pub fn iso_zoned_date_time() -> Self {
+        let mut elements = Self::iso_offset_date_time().elements;
+        elements.push(Element::Optional(vec![
+            Element::Literal("[".to_string()),
+            Element::ZoneId { region_only: true },
+            Element::Literal("]".to_string()),
+        ]));
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the RFC 9557 formatter, such as `2024-03-10T03:30:00-04:00[America/New_York]`.
+    ///
+    /// It prints like [`DateTimeFormatter::iso_zoned_date_time`], always with the seconds,
+    /// where `ZonedDateTime`'s `Display` leaves out zero seconds (`03:30-04:00`). Both forms
+    /// parse back to the same value. When parsing it also accepts the critical flag (`[!America/New_York]`) and
+    /// suffix tags such as `[u-ca=iso8601]`. Unknown tags are ignored unless they are marked
+    /// critical, and calendars other than `iso8601` are rejected when critical.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::rfc_9557();
+    /// let zdt: ZonedDateTime = formatter.parse("2024-03-10T03:30-04:00[!America/New_York][u-ca=iso8601]").unwrap();
+    /// assert_eq!(formatter.format(&zdt), "2024-03-10T03:30:00-04:00[America/New_York]");
+    /// ```
+    pub fn rfc_9557() -> Self {
+        let mut elements = Self::iso_offset_date_time().elements;
+        elements.push(Element::Rfc9557Suffix);
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the RFC 1123 formatter for HTTP dates, such as `Sun, 06 Nov 1994 08:49:37 GMT`,
+    /// the `IMF-fixdate` of RFC 9110 used by the `Date`, `Last-Modified` and `Expires` headers.
+    ///
+    /// It formats in GMT, converting values with an instant. When parsing it also accepts the
+    /// two obsolete forms RFC 9110 requires recipients to accept, RFC 850
+    /// (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime (`Sun Nov  6 08:49:37 1994`), as well as
+    /// any RFC 2822 date-time, see [`DateTimeFormatter::rfc_2822_date_time`]. The two-digit
+    /// year of RFC 850 is the one with those digits that is at most 50 years after the
+    /// current year in UTC, as RFC 9110 requires; the current year comes from the formatter's
+    /// clock, see [`DateTimeFormatter::with_clock`].
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::rfc_1123_date_time();
+    /// let instant = Instant::of_epoch_second(784_111_777);
+    /// assert_eq!(formatter.format(&instant), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// assert_eq!(formatter.parse::<Instant>("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(instant));
+    /// assert_eq!(formatter.parse::<Instant>("Sun Nov  6 08:49:37 1994"), Ok(instant));
+    /// ```
+    pub fn rfc_1123_date_time() -> Self {
+        let format = Self::of_pattern("EEE, dd MMM uuuu HH:mm:ss 'GMT'").elements;
+        DateTimeFormatter::of_elements(vec![Element::LegacyDateTime { gmt: true, format }])
+            .with_zone(ZoneId::UTC)
+            .with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the RFC 2822 formatter for email dates, such as
+    /// `Sun, 06 Nov 1994 08:49:37 -0500`, keeping the offset of the value.
+    ///
+    /// Parsing follows the obsolete syntax of RFC 2822 as well: the day of the week is
+    /// optional, seconds are optional, two-digit years 00 to 49 are 2000 to 2049 and 50 to 99
+    /// are 1950 to 1999, comments such as `(PST)` and folding whitespace may appear between any
+    /// two tokens and the zone may be a name. The names are `UT`, `GMT`, `EST`, `EDT`, `CST`,
+    /// `CDT`, `MST`, `MDT`, `PST` and `PDT`; single-letter military zones are read as UTC, as
+    /// RFC 5322 recommends because their signs were used inconsistently.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::rfc_2822_date_time();
+    /// let odt: OffsetDateTime = formatter.parse("6 Nov 94 08:49 PDT").unwrap();
+    /// assert_eq!(odt.offset(), -7 * 3600);
+    /// assert_eq!(formatter.format(&odt), "Sun, 06 Nov 1994 08:49:00 -0700");
+    /// ```
+    pub fn rfc_2822_date_time() -> Self {
+        let format = Self::of_pattern("EEE, dd MMM uuuu HH:mm:ss xx").elements;
+        DateTimeFormatter::of_elements(vec![Element::LegacyDateTime { gmt: false, format }]).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the formatter of the Common Log Format of Apache and nginx, such as
+    /// `10/Oct/2000:13:55:36 -0700`, the `%t` of Apache and the `$time_local` of nginx
+    /// without the brackets. See [`LogTimestampParser`](crate::LogTimestampParser) for the
+    /// other log formats.
+    ///
+    /// ```rust
+    /// let odt = OffsetDateTime::of(LocalDateTime::of(2000, 10, 10, 13, 55, 36), ZoneOffset::of_hours(-7));
+    /// assert_eq!(DateTimeFormatter::common_log_format().format(&odt), "10/Oct/2000:13:55:36 -0700");
+    /// ```
+    pub fn common_log_format() -> Self {
+        Self::of_pattern("dd/MMM/uuuu:HH:mm:ss xx").with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the formatter of RFC 3339 timestamps as RFC 5424 syslog restricts them, such
+    /// as `2003-08-24T05:14:15.000003-07:00`: seconds are required, the fraction has at most
+    /// six digits, the year has four and `T` and `Z` are upper case.
+    pub(crate) fn rfc_5424_timestamp() -> Self {
+        let number = |field, min, max| Element::Number { field, min, max, sign: SignStyle::NotNegative, reserved: 0 };
+        let mut elements = vec![number(Field::Year, 4, 4)];
+        elements.extend(Self::of_pattern("-MM-dd'T'HH:mm:ss").elements);
+        elements.push(Element::Fraction { min: 0, max: 6, decimal_point: true });
+        elements.extend(Self::of_pattern("XXX").elements);
+        DateTimeFormatter::of_elements(elements).with_resolver_style(ResolverStyle::Strict)
+    }
+
+    /// Returns the ISO-8601 instant formatter, such as `2024-03-05T13:07:09Z`, like
+    /// `DateTimeFormatter.ISO_INSTANT`. It formats in UTC and parses any offset.
+    pub fn iso_instant() -> Self {
+        Self::iso_offset_date_time().with_zone(ZoneId::UTC)
+    }
+
+    /// Returns a copy of this formatter with an override zone, like
+    /// `java.time.format.DateTimeFormatter.withZone`.
+    ///
+    /// When formatting, a value with an instant (an `Instant`, `OffsetDateTime` or
+    /// `ZonedDateTime`) is first converted to this zone. When parsing, the zone is used if
+    /// the text contains neither a zone nor an offset.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("yyyy-MM-dd HH:mm z").with_zone(ZoneId::try_of("America/New_York").unwrap());
+    /// assert_eq!(formatter.format(&Instant::of_epoch_second(1_720_000_000)), "2024-07-03 05:46 EDT");
+    /// ```
+    pub fn with_zone(mut self, zone: ZoneId) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Returns the override zone, see [`DateTimeFormatter::with_zone`].
+    pub fn zone(&self) -> Option<ZoneId> {
+        self.zone.clone()
+    }
+
+    /// Returns a copy of this formatter with a policy for parsed text whose offset does not
+    /// agree with its zone. The default is [`OffsetConflict::Reject`].
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::rfc_9557().with_offset_conflict(OffsetConflict::PreferZone);
+    /// let zdt: ZonedDateTime = formatter.parse("2024-03-10T03:30-05:00[America/New_York]").unwrap();
+    /// assert_eq!(zdt.offset(), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn with_offset_conflict(mut self, offset_conflict: OffsetConflict) -> Self {
+        self.offset_conflict = offset_conflict;
+        self
+    }
+
+    /// Returns the policy for conflicting offsets and zones, see
+    /// [`DateTimeFormatter::with_offset_conflict`].
+    pub fn offset_conflict(&self) -> OffsetConflict {
+        self.offset_conflict
+    }
+
+    /// Returns a copy of this formatter that prints and parses month, day-of-week and am-pm
+    /// names in a locale, like `java.time.format.DateTimeFormatter.withLocale`.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("EEEE d MMMM uuuu").with_locale(Locale::FRANCE);
+    /// assert_eq!(formatter.format(&LocalDate::of(2024, 9, 3)), "mardi 3 septembre 2024");
+    /// assert_eq!(formatter.parse::<LocalDate>("Mardi 3 Septembre 2024"), Ok(LocalDate::of(2024, 9, 3)));
+    /// ```
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Returns the locale of the text fields, see [`DateTimeFormatter::with_locale`].
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns a copy of this formatter that combines parsed fields with a resolver style,
+    /// like `java.time.format.DateTimeFormatter.withResolverStyle`.
+    ///
+    /// Formatters from patterns use [`ResolverStyle::Smart`] and the ISO formatters use
+    /// [`ResolverStyle::Strict`], as in java.time. The style decides what happens to a
+    /// day-of-month past the end of the month, to `24:00` and to other values out of range.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd HH:mm").with_resolver_style(ResolverStyle::Lenient);
+    /// assert_eq!(formatter.parse::<LocalDateTime>("2024-12-31 24:30"), Ok(LocalDateTime::of(2025, 1, 1, 0, 30, 0)));
+    /// assert_eq!(formatter.parse::<LocalDateTime>("2024-14-00 10:75"), Ok(LocalDateTime::of(2025, 1, 31, 11, 15, 0)));
+    /// ```
+    pub fn with_resolver_style(mut self, resolver_style: ResolverStyle) -> Self {
+        self.resolver_style = resolver_style;
+        self
+    }
+
+    /// Returns the resolver style, see [`DateTimeFormatter::with_resolver_style`].
+    pub fn resolver_style(&self) -> ResolverStyle {
+        self.resolver_style
+    }
+
+    /// Returns a copy of this formatter that takes the current year from `clock`, which the
+    /// two-digit years of RFC 850 dates are read relative to, see
+    /// [`DateTimeFormatter::rfc_1123_date_time`]. The default is the system clock.
+    ///
+    /// ```rust
+    /// let clock = Clock::fixed_at_local(LocalDateTime::of(2090, 1, 1, 0, 0, 0), ZoneId::UTC);
+    /// let formatter = DateTimeFormatter::rfc_1123_date_time().with_clock(clock);
+    /// let instant: Instant = formatter.parse("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+    /// assert_eq!(instant.at_offset(ZoneOffset::UTC).year(), 2094);
+    /// ```
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the clock giving the current year, see [`DateTimeFormatter::with_clock`].
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Formats a date-time value with this formatter.
+    ///
+    /// # Panics
+    /// Panics if the pattern uses a field the value does not have, such as an hour when
+    /// formatting a `LocalDate`. See [`DateTimeFormatter::try_format`].
+    pub fn format<T: TemporalAccessor>(&self, temporal: &T) -> String {
+        self.try_format(temporal).expect("unable to format the value with this pattern")
+    }
+
+    /// Formats a date-time value with this formatter.
+    ///
+    /// # Errors
+    /// Returns an error if the pattern uses a field the value does not have, if a value
+    /// is wider than its field allows, or if the formatter has a zone and the instant of the
+    /// value or its local date-time in that zone is outside the supported range.
+    pub fn try_format<T: TemporalAccessor>(&self, temporal: &T) -> Result<String, &'static str> {
+        let mut fields = temporal.to_fields();
+        if let Some(zone) = &self.zone
+            && let Some(instant) = fields.try_instant()
+        {
+            let zdt = ZonedDateTime::checked_resolve_instant(instant?.inner(), zone.clone());
+            fields = zdt.ok_or("Local date-time exceeds the supported range")?.to_fields();
+        }
+        let mut out = String::new();
+        format_elements(&self.elements, &fields, self.locale, &mut out).map_err(|error| match error {
+            FormatError::Unsupported(message) | FormatError::Invalid(message) => message,
+        })?;
+        Ok(out)
+    }
+
+    /// Parses text into a date-time value. The whole text must match the pattern.
+    ///
+    /// ```rust
+    /// let formatter = DateTimeFormatter::of_pattern("dd/MM/yyyy hh:mm a");
+    /// let parsed: LocalDateTime = formatter.parse("05/03/2024 02:07 PM").unwrap();
+    /// assert_eq!(parsed, LocalDateTime::of(2024, 3, 5, 14, 7, 0));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the text does not match the pattern, if a value is out of range,
+    /// or if the parsed fields do not make up a `T`.
+    pub fn parse<T: TemporalAccessor>(&self, text: &str) -> Result<T, DateTimeParseError> {
+        let (fields, parsed) = self.parse_resolved(text)?;
+        T::from_fields(&fields).map_err(|message| DateTimeParseError::new(text, parsed.conversion_error_index(message), message))
+    }
+
+    /// Parses text into its fields without choosing a type.
+    ///
+    /// # Errors
+    /// Returns an error if the text does not match the pattern or a value is out of range.
+    pub fn parse_fields(&self, text: &str) -> Result<DateTimeFields, DateTimeParseError> {
+        self.parse_resolved(text).map(|(fields, _)| fields)
+    }
+
+    /// Parses text into its fields, keeping the parsed values to report later errors at.
+    fn parse_resolved(&self, text: &str) -> Result<(DateTimeFields, Parsed), DateTimeParseError> {
+        let mut parsed = Parsed::default();
+        let end = parse_elements(&self.elements, text, 0, self.locale, &self.clock, &mut parsed)
+            .map_err(|error| DateTimeParseError::new(text, error.index, error.expected))?;
+        if end != text.len() {
+            return Err(DateTimeParseError::new(text, end, "Unparsed text found after the end of the pattern"));
+        }
+        let mut fields = parsed.resolve(self.resolver_style).map_err(|error| DateTimeParseError::new(text, error.index, error.expected))?;
+        if fields.zone.is_none() && fields.offset.is_none() {
+            fields.zone = self.zone.clone();
+        }
+        if let (Some(ldt), Some(offset), Some(zone)) = (fields.local_date_time(), fields.offset, &fields.zone)
+            && !zone.rules().is_valid_offset(ldt, offset)
+        {
+            match self.offset_conflict {
+                OffsetConflict::Reject => {
+                    let index = parsed.offset_start.unwrap_or(0);
+                    return Err(DateTimeParseError::new(text, index, "Offset does not match the time zone"));
+                }
+                OffsetConflict::PreferOffset => {}
+                OffsetConflict::PreferZone => fields.offset = None,
+            }
+        }
+        Ok((fields, parsed))
+    }
+
+    fn of_elements(elements: Vec<Element>) -> Self {
+        DateTimeFormatter {
+            elements,
+            zone: None,
+            offset_conflict: OffsetConflict::default(),
+            locale: Locale::US,
+            resolver_style: ResolverStyle::default(),
+            clock: Clock::system_utc(),
+        }
+    }
+}
+
+fn compile(chars: &mut std::iter::Peekable<std::str::Chars>, optional: bool) -> Result<Vec<Element>, &'static str> {
+    let mut elements = Vec::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let mut literal = String::new();
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    push_literal(&mut elements, "'");
+                    continue;
+                }
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            literal.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(c) => literal.push(c),
+                        None => return Err("Pattern ends with an incomplete string literal"),
+                    }
+                }
+                push_literal(&mut elements, &literal);
+            }
+            '[' => elements.push(Element::Optional(compile(chars, true)?)),
+            ']' if optional => {
+                set_reserved(&mut elements);
+                return Ok(elements);
+            }
+            ']' => return Err("Pattern invalid as it contains ] without previous ["),
+            '{' | '}' | '#' => return Err("Pattern includes reserved character"),
+            c if c.is_ascii_alphabetic() => {
+                let mut count = 1;
+                while chars.peek() == Some(&c) {
+                    chars.next();
+                    count += 1;
+                }
+                elements.push(letter(c, count)?);
+            }
+            c => push_literal(&mut elements, c.encode_utf8(&mut [0; 4])),
+        }
+    }
+    if optional {
+        return Err("Pattern invalid as it contains [ without matching ]");
+    }
+    set_reserved(&mut elements);
+    Ok(elements)
+}
+
+/// Returns `HH:mm[:ss[.nnnnnnnnn]]`, the time part of the ISO formatters.
+fn iso_time() -> Vec<Element> {
+    let number = |field| Element::Number { field, min: 2, max: 2, sign: SignStyle::NotNegative, reserved: 0 };
+    vec![
+        number(Field::HourOfDay),
+        Element::Literal(":".to_string()),
+        number(Field::MinuteOfHour),
+        Element::Optional(vec![
+            Element::Literal(":".to_string()),
+            number(Field::SecondOfMinute),
+            Element::Fraction { min: 0, max: 9, decimal_point: true },
+        ]),
+    ]
+}
+
+fn push_literal(elements: &mut Vec<Element>, text: &str) {
+    if let Some(Element::Literal(literal)) = elements.last_mut() {
+        literal.push_str(text);
+    } else {
+        elements.push(Element::Literal(text.to_string()));
+    }
+}
+
+/// Compiles a run of `count` pattern letters `c`, following `DateTimeFormatterBuilder.appendPattern`.
+fn letter(c: char, count: usize) -> Result<Element, &'static str> {
+    let number = |field, min, max| Element::Number { field, min, max, sign: SignStyle::NotNegative, reserved: 0 };
+    let text = |field, count| match count {
+        1..=3 => Ok(Element::Text { field, style: TextStyle::Short }),
+        4 => Ok(Element::Text { field, style: TextStyle::Full }),
+        5 => Ok(Element::Text { field, style: TextStyle::Narrow }),
+        _ => Err("Too many pattern letters"),
+    };
+    let offset = |minutes, seconds, colons, zero| Element::Offset { pattern: OffsetPattern { minutes, seconds, colons }, zero };
+    use Presence::{Absent, Optional, Required};
+    match (c, count) {
+        ('G', _) => text(TextField::Era, count),
+        ('y' | 'u' | 'Y', _) => {
+            let field = match c {
+                'y' => Field::YearOfEra,
+                'u' => Field::Year,
+                _ => Field::WeekBasedYear,
+            };
+            Ok(match count {
+                2 => Element::ReducedYear(field),
+                1 | 3 => Element::Number { field, min: count, max: 19, sign: SignStyle::Normal, reserved: 0 },
+                _ => Element::Number { field, min: count, max: 19, sign: SignStyle::ExceedsPad, reserved: 0 },
+            })
+        }
+        ('M' | 'L', 1) => Ok(number(Field::MonthOfYear, 1, 2)),
+        ('M' | 'L', 2) => Ok(number(Field::MonthOfYear, 2, 2)),
+        ('M', _) => text(TextField::MonthOfYear, count),
+        ('L', _) => text(TextField::MonthOfYear, count).map(|element| match element {
+            Element::Text { field, style } => Element::Text { field, style: style.as_standalone() },
+            element => element,
+        }),
+        ('E', _) => text(TextField::DayOfWeek, count),
+        ('e', 1 | 2) | ('c', 1) => Ok(number(Field::DayOfWeek, count, count)),
+        ('e', _) => text(TextField::DayOfWeek, count),
+        ('c', 2) => Err("Pattern letter count must not be 2: c"),
+        ('c', _) => text(TextField::DayOfWeek, count).map(|element| match element {
+            Element::Text { field, style } => Element::Text { field, style: style.as_standalone() },
+            element => element,
+        }),
+        ('w', 1 | 2) => Ok(number(Field::WeekOfWeekBasedYear, count, 2)),
+        ('a', 1) => Ok(Element::Text { field: TextField::AmPm, style: TextStyle::Short }),
+        ('D', 1) => Ok(number(Field::DayOfYear, 1, 3)),
+        ('D', 2) => Ok(number(Field::DayOfYear, 2, 3)),
+        ('D', 3) => Ok(number(Field::DayOfYear, 3, 3)),
+        ('d' | 'H' | 'k' | 'K' | 'h' | 'm' | 's', 1 | 2) => {
+            let field = match c {
+                'd' => Field::DayOfMonth,
+                'H' => Field::HourOfDay,
+                'k' => Field::ClockHourOfDay,
+                'K' => Field::HourOfAmPm,
+                'h' => Field::ClockHourOfAmPm,
+                'm' => Field::MinuteOfHour,
+                _ => Field::SecondOfMinute,
+            };
+            Ok(number(field, count, 2))
+        }
+        ('S', 1..=9) => Ok(Element::Fraction { min: count, max: count, decimal_point: false }),
+        ('n', 1..=9) => Ok(number(Field::NanoOfSecond, count, 9)),
+        ('V', 2) => Ok(Element::ZoneId { region_only: false }),
+        ('V', _) => Err("Pattern letter count must be 2: V"),
+        ('z', 1..=3) => Ok(Element::ZoneText { full: false }),
+        ('z', 4) => Ok(Element::ZoneText { full: true }),
+        ('O', 1) => Ok(Element::LocalizedOffset { full: false }),
+        ('O', 4) => Ok(Element::LocalizedOffset { full: true }),
+        ('O', _) => Err("Pattern letter count must be 1 or 4: O"),
+        ('Z', 1..=3) => Ok(offset(Required, Absent, false, "+0000")),
+        ('Z', 4) => Ok(Element::LocalizedOffset { full: true }),
+        ('Z', 5) => Ok(offset(Required, Optional, true, "Z")),
+        ('X' | 'x', 1..=5) => {
+            let zero = match (c, count) {
+                ('X', _) => "Z",
+                (_, 1) => "+00",
+                (_, 2 | 4) => "+0000",
+                _ => "+00:00",
+            };
+            Ok(match count {
+                1 => offset(Optional, Absent, false, zero),
+                2 => offset(Required, Absent, false, zero),
+                3 => offset(Required, Absent, true, zero),
+                4 => offset(Required, Optional, false, zero),
+                _ => offset(Required, Optional, true, zero),
+            })
+        }
+        ('a' | 'D' | 'd' | 'w' | 'H' | 'k' | 'K' | 'h' | 'm' | 's' | 'S' | 'n' | 'z' | 'Z' | 'X' | 'x', _) => {
+            Err("Too many pattern letters")
+        }
+        _ => Err("Unknown pattern letter"),
+    }
+}
+
+/// Sets how many digits each variable-width number leaves to the fixed-width numbers that
+/// directly follow it, so that `yyyyMMdd` parses `20240305`.
+fn set_reserved(elements: &mut [Element]) {
+    for index in 0..elements.len() {
+        let reserved = elements[index + 1..]
+            .iter()
+            .map_while(|element| match element {
+                Element::Number { min, max, .. } if min == max => Some(*min),
+                Element::ReducedYear(_) => Some(2),
+                Element::Fraction { min, max, decimal_point: false } if min == max => Some(*min),
+                _ => None,
+            })
+            .sum();
+        if let Element::Number { min, max, reserved: slot, .. } = &mut elements[index]
+            && min != max
+        {
+            *slot = reserved;
+        }
+    }
+}
+
+fn format_elements(elements: &[Element], fields: &DateTimeFields, locale: Locale, out: &mut String) -> Result<(), FormatError> {
+    for element in elements {
+        match element {
+            Element::Literal(literal) => out.push_str(literal),
+            Element::Number { field, min, max, sign, .. } => {
+                let value = field_value(*field, fields)?;
+                let digits = value.unsigned_abs().to_string();
+                if digits.len() > *max {
+                    return Err(FormatError::Invalid("Field exceeds the maximum print width"));
+                }
+                if value < 0 {
+                    out.push('-');
+                } else if *sign == SignStyle::ExceedsPad && digits.len() > *min {
+                    out.push('+');
+                }
+                out.push_str(&"0".repeat(min.saturating_sub(digits.len())));
+                out.push_str(&digits);
+            }
+            Element::ReducedYear(field) => {
+                let value = field_value(*field, fields)?;
+                out.push_str(&format!("{:02}", value.rem_euclid(100)));
+            }
+            Element::Fraction { min, max, decimal_point } => {
+                let nanos = fields.time.ok_or(FormatError::Unsupported("Unsupported field: NanoOfSecond"))?.nanosecond();
+                let digits = format!("{nanos:09}");
+                let digits = digits[..*max].trim_end_matches('0');
+                let digits = format!("{digits:0<min$}");
+                if !digits.is_empty() {
+                    if *decimal_point {
+                        out.push('.');
+                    }
+                    out.push_str(&digits);
+                }
+            }
+            Element::Text { field, style } => out.push_str(&text_value(*field, *style, fields, locale)?),
+            Element::Offset { pattern, zero } => format_offset(offset_of(fields)?, *pattern, zero, out),
+            Element::LocalizedOffset { full } => out.push_str(&localized_offset(offset_of(fields)?, *full)),
+            Element::ZoneText { full } => {
+                let zone = fields.zone.clone().or(fields.offset.map(ZoneId::from));
+                let unsupported = FormatError::Unsupported("Unable to extract the zone name: a zone and an instant are required");
+                let (zone, instant) = zone.zip(fields.instant()).ok_or(unsupported)?;
+                // Languages other than English use the localized GMT format, as CLDR does for
+                // zones without names in the language.
+                match locale.names().localized_gmt(zone.rules().offset(instant), *full) {
+                    Some(name) => out.push_str(&name),
+                    None => out.push_str(&zone.display_name_at(instant, if *full { TextStyle::Full } else { TextStyle::Short })),
+                }
+            }
+            Element::ZoneId { region_only: false } => {
+                let zone = fields.zone.clone().or(fields.offset.map(ZoneId::from));
+                out.push_str(zone.ok_or(FormatError::Unsupported("Unable to extract ZoneId"))?.id());
+            }
+            Element::Rfc9557Suffix => {
+                let mut section = String::new();
+                match format_elements(&[Element::ZoneId { region_only: true }], fields, locale, &mut section) {
+                    Ok(()) => out.push_str(&format!("[{section}]")),
+                    Err(FormatError::Unsupported(_)) => {}
+                    Err(error) => return Err(error),
+                }
+            }
+            Element::LegacyDateTime { gmt, format } => {
+                if *gmt && offset_of(fields)?.total_seconds() != 0 {
+                    return Err(FormatError::Invalid("HTTP dates must be in GMT"));
+                }
+                format_elements(format, fields, Locale::US, out)?;
+            }
+            Element::ZoneId { region_only: true } => match (&fields.zone, fields.offset) {
+                (Some(zone), Some(offset)) if zone.id() == offset.id() => {
+                    return Err(FormatError::Unsupported("Unable to extract a region ZoneId"));
+                }
+                (Some(zone), _) => out.push_str(zone.id()),
+                (None, _) => return Err(FormatError::Unsupported("Unable to extract a region ZoneId")),
+            },
+            Element::Optional(elements) => {
+                let mut section = String::new();
+                match format_elements(elements, fields, locale, &mut section) {
+                    Ok(()) => out.push_str(&section),
+                    Err(FormatError::Unsupported(_)) => {}
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn field_value(field: Field, fields: &DateTimeFields) -> Result<i64, FormatError> {
+    let time = || fields.time.ok_or(FormatError::Unsupported("Unsupported field: time of day"));
+    let value = match field {
+        Field::Year => fields.year.ok_or(FormatError::Unsupported("Unsupported field: Year"))?,
+        Field::YearOfEra => {
+            let year = fields.year.ok_or(FormatError::Unsupported("Unsupported field: YearOfEra"))?;
+            if year >= 1 { year } else { 1 - year }
+        }
+        Field::MonthOfYear => fields.month.ok_or(FormatError::Unsupported("Unsupported field: MonthOfYear"))?.value(),
+        Field::DayOfMonth => i32::from(fields.day.ok_or(FormatError::Unsupported("Unsupported field: DayOfMonth"))?),
+        Field::DayOfYear => fields.date.ok_or(FormatError::Unsupported("Unsupported field: DayOfYear"))?.day_of_year(),
+        Field::WeekBasedYear => fields.week_based_year().ok_or(FormatError::Unsupported("Unsupported field: WeekBasedYear"))?,
+        Field::WeekOfWeekBasedYear => {
+            i32::from(fields.week_of_week_based_year().ok_or(FormatError::Unsupported("Unsupported field: WeekOfWeekBasedYear"))?)
+        }
+        Field::DayOfWeek => fields.date.ok_or(FormatError::Unsupported("Unsupported field: DayOfWeek"))?.day_of_week().value(),
+        Field::HourOfDay => time()?.hour(),
+        Field::ClockHourOfDay => match time()?.hour() {
+            0 => 24,
+            hour => hour,
+        },
+        Field::HourOfAmPm => time()?.hour() % 12,
+        Field::ClockHourOfAmPm => match time()?.hour() % 12 {
+            0 => 12,
+            hour => hour,
+        },
+        Field::MinuteOfHour => time()?.minute(),
+        Field::SecondOfMinute => time()?.second(),
+        Field::NanoOfSecond => time()?.nanosecond(),
+    };
+    Ok(i64::from(value))
+}
+
+fn text_value(field: TextField, style: TextStyle, fields: &DateTimeFields, locale: Locale) -> Result<String, FormatError> {
+    let text = match field {
+        TextField::Era => {
+            let year = fields.year.ok_or(FormatError::Unsupported("Unsupported field: Era"))?;
+            ERAS[style_index(style)][usize::from(year >= 1)].to_string()
+        }
+        TextField::MonthOfYear => {
+            let month = fields.month.ok_or(FormatError::Unsupported("Unsupported field: MonthOfYear"))?;
+            locale.names().month(month, style).to_string()
+        }
+        TextField::DayOfWeek => {
+            let date = fields.date.ok_or(FormatError::Unsupported("Unsupported field: DayOfWeek"))?;
+            locale.names().day(date.day_of_week(), style).to_string()
+        }
+        TextField::AmPm => {
+            let time = fields.time.ok_or(FormatError::Unsupported("Unsupported field: AmPmOfDay"))?;
+            locale.names().am_pm[usize::from(time.hour() >= 12)].to_string()
+        }
+    };
+    Ok(text)
+}
+
+/// Returns the index of the style in [`ERAS`]: full, short or narrow.
+fn style_index(style: TextStyle) -> usize {
+    match style.as_normal() {
+        TextStyle::Full => 0,
+        TextStyle::Short => 1,
+        _ => 2,
+    }
+}
+
+fn offset_of(fields: &DateTimeFields) -> Result<ZoneOffset, FormatError> {
+    fields.offset.ok_or(FormatError::Unsupported("Unsupported field: OffsetSeconds"))
+}
+
+fn format_offset(offset: ZoneOffset, pattern: OffsetPattern, zero: &str, out: &mut String) {
+    let total = offset.total_seconds();
+    let (hours, minutes, seconds) = (total.abs() / 3600, total.abs() / 60 % 60, total.abs() % 60);
+    let show_seconds = pattern.seconds == Presence::Required || (pattern.seconds == Presence::Optional && seconds != 0);
+    let show_minutes = pattern.minutes == Presence::Required || (pattern.minutes == Presence::Optional && minutes != 0) || show_seconds;
+    // A zero offset, or one that rounds to zero in this pattern, prints as the zero text.
+    if total == 0 || (hours == 0 && (minutes == 0 || !show_minutes) && (seconds == 0 || !show_seconds)) {
+        out.push_str(zero);
+        return;
+    }
+    out.push(if total < 0 { '-' } else { '+' });
+    out.push_str(&format!("{hours:02}"));
+    let separator = if pattern.colons { ":" } else { "" };
+    if show_minutes {
+        out.push_str(&format!("{separator}{minutes:02}"));
+    }
+    if show_seconds {
+        out.push_str(&format!("{separator}{seconds:02}"));
+    }
+}
+
+fn parse_elements(
+    elements: &[Element],
+    text: &str,
+    mut index: usize,
+    locale: Locale,
+    clock: &Clock,
+    parsed: &mut Parsed,
+) -> Result<usize, ParseError> {
+    for element in elements {
+        index = match element {
+            Element::Literal(literal) => {
+                if !text[index..].starts_with(literal.as_str()) {
+                    return Err(ParseError { index, expected: "Text did not match the literal in the pattern" });
+                }
+                index + literal.len()
+            }
+            Element::Number { field, min, max, sign, reserved } => {
+                let (value, end) = parse_number(text, index, *min, *max, *sign, *reserved)?;
+                parsed.set(field_slot(*field), value, index)?;
+                end
+            }
+            Element::ReducedYear(field) => {
+                let (value, end) = parse_number(text, index, 2, 2, SignStyle::NotNegative, 0)?;
+                parsed.set(field_slot(*field), 2000 + value, index)?;
+                end
+            }
+            Element::Fraction { min, max, decimal_point } => {
+                let start = if *decimal_point && text[index..].starts_with('.') { index + 1 } else { index };
+                if *decimal_point && start == index {
+                    if *min > 0 {
+                        return Err(ParseError { index, expected: "Expected a decimal point" });
+                    }
+                    index
+                } else {
+                    let available = text[start..].bytes().take_while(u8::is_ascii_digit).count();
+                    let width = available.clamp((*min).max(1), *max);
+                    let (value, end) = parse_number(text, start, width, width, SignStyle::NotNegative, 0)?;
+                    parsed.set(Slot::NanoOfSecond, value * 10_i64.pow(9 - (end - start) as u32), index)?;
+                    end
+                }
+            }
+            Element::Text { field, style } => parse_text(text, index, *field, *style, locale, parsed)?,
+            Element::Offset { pattern, zero } => {
+                let (offset, end) = parse_offset(text, index, *pattern, zero)?;
+                parsed.set_offset(offset, index)?;
+                end
+            }
+            Element::LocalizedOffset { .. } => {
+                let (offset, end) = parse_localized_offset(text, index)?;
+                parsed.set_offset(offset, index)?;
+                end
+            }
+            Element::ZoneText { .. } => {
+                let by_name = parse_zone_name(&text[index..]);
+                let by_id = parse_zone_id(text, index);
+                match (by_name, by_id) {
+                    (Some((zone, daylight, length)), by_id) if by_id.as_ref().is_none_or(|(_, end)| index + length > *end) => {
+                        parsed.set_zone(zone, index);
+                        parsed.daylight = daylight;
+                        index + length
+                    }
+                    (_, Some((zone, end))) => {
+                        parsed.set_zone(zone, index);
+                        end
+                    }
+                    _ => return Err(ParseError { index, expected: "Expected a time-zone name" }),
+                }
+            }
+            Element::ZoneId { .. } => {
+                let (zone, end) = parse_zone_id(text, index).ok_or(ParseError { index, expected: "Expected a time-zone ID" })?;
+                parsed.set_zone(zone, index);
+                end
+            }
+            Element::Rfc9557Suffix => parse_rfc9557_suffix(text, index, parsed)?,
+            Element::LegacyDateTime { .. } => parse_legacy_date_time(text, index, clock, parsed)?,
+            Element::Optional(elements) => {
+                let mut attempt = parsed.clone();
+                match parse_elements(elements, text, index, locale, clock, &mut attempt) {
+                    Ok(end) => {
+                        *parsed = attempt;
+                        end
+                    }
+                    Err(_) => index,
+                }
+            }
+        };
+    }
+    Ok(index)
+}
+
+fn parse_number(
+    text: &str,
+    index: usize,
+    min: usize,
+    max: usize,
+    sign: SignStyle,
+    reserved: usize,
+) -> Result<(i64, usize), ParseError> {
+    let bytes = text.as_bytes();
+    let (negative, start) = match bytes.get(index) {
+        Some(b'-') if sign != SignStyle::NotNegative => (true, index + 1),
+        Some(b'+') if sign == SignStyle::ExceedsPad => (false, index + 1),
+        _ => (false, index),
+    };
+    let available = bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let width = if min == max { min } else { available.saturating_sub(reserved).clamp(min, max) };
+    if available < width {
+        return Err(ParseError { index: start, expected: "Expected a number with more digits" });
+    }
+    let value: i64 = text[start..start + width]
+        .parse()
+        .map_err(|_| ParseError { index: start, expected: "Number is out of range" })?;
+    // Like java.time's strict parsing, the sign must be one that formatting would print.
+    if negative && value == 0 {
+        return Err(ParseError { index, expected: "Expected no minus sign for zero" });
+    }
+    if start > index && !negative && width <= min {
+        return Err(ParseError { index, expected: "Expected a plus sign only for values wider than the minimum width" });
+    }
+    Ok((if negative { -value } else { value }, start + width))
+}
+
+fn field_slot(field: Field) -> Slot {
+    match field {
+        Field::Year => Slot::Year,
+        Field::YearOfEra => Slot::YearOfEra,
+        Field::MonthOfYear => Slot::MonthOfYear,
+        Field::DayOfMonth => Slot::DayOfMonth,
+        Field::DayOfYear => Slot::DayOfYear,
+        Field::WeekBasedYear => Slot::WeekBasedYear,
+        Field::WeekOfWeekBasedYear => Slot::WeekOfWeekBasedYear,
+        Field::DayOfWeek => Slot::DayOfWeek,
+        Field::HourOfDay => Slot::HourOfDay,
+        Field::ClockHourOfDay => Slot::ClockHourOfDay,
+        Field::HourOfAmPm => Slot::HourOfAmPm,
+        Field::ClockHourOfAmPm => Slot::ClockHourOfAmPm,
+        Field::MinuteOfHour => Slot::MinuteOfHour,
+        Field::SecondOfMinute => Slot::SecondOfMinute,
+        Field::NanoOfSecond => Slot::NanoOfSecond,
+    }
+}
+
+fn parse_text(
+    text: &str,
+    index: usize,
+    field: TextField,
+    style: TextStyle,
+    locale: Locale,
+    parsed: &mut Parsed,
+) -> Result<usize, ParseError> {
+    let names = locale.names();
+    let (slot, candidates): (Slot, Vec<(&str, i64)>) = match field {
+        TextField::Era => (Slot::Era, ERAS[style_index(style)].iter().copied().zip(0..).collect()),
+        TextField::MonthOfYear => (Slot::MonthOfYear, (1..=12).map(|month| (names.month(Month::of(month), style), i64::from(month))).collect()),
+        TextField::DayOfWeek => (Slot::DayOfWeek, (1..=7).map(|day| (names.day(DayOfWeek::of(day), style), i64::from(day))).collect()),
+        TextField::AmPm => (Slot::AmPm, names.am_pm.iter().copied().zip(0..).collect()),
+    };
+    let rest = &text[index..];
+    let (name, value) = candidates
+        .iter()
+        .filter(|(name, _)| rest.get(..name.len()).is_some_and(|start| start.to_lowercase() == name.to_lowercase()))
+        .max_by_key(|(name, _)| name.len())
+        .ok_or(ParseError { index, expected: "Text did not match any known name" })?;
+    parsed.set(slot, *value, index)?;
+    Ok(index + name.len())
+}
+
+fn parse_offset(text: &str, index: usize, pattern: OffsetPattern, zero: &str) -> Result<(ZoneOffset, usize), ParseError> {
+    let bytes = text.as_bytes();
+    let sign = match bytes.get(index) {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ if text[index..].starts_with(zero) => return Ok((ZoneOffset::UTC, index + zero.len())),
+        _ => return Err(ParseError { index, expected: "Expected an offset such as +01:00" }),
+    };
+    let two_digits = |at: usize| {
+        bytes
+            .get(at..at + 2)
+            .filter(|digits| digits.iter().all(u8::is_ascii_digit))
+            .map(|digits| i32::from(digits[0] - b'0') * 10 + i32::from(digits[1] - b'0'))
+    };
+    let hours = two_digits(index + 1).ok_or(ParseError { index: index + 1, expected: "Expected two digits for the offset hours" })?;
+    let mut end = index + 3;
+    let component = |presence: Presence, end: &mut usize| -> Result<i32, ParseError> {
+        let at = *end + usize::from(pattern.colons);
+        let separated = !pattern.colons || bytes.get(*end) == Some(&b':');
+        match (presence, separated.then(|| two_digits(at)).flatten()) {
+            (Presence::Absent, _) => Ok(0),
+            (_, Some(value)) => {
+                *end = at + 2;
+                Ok(value)
+            }
+            (Presence::Optional, None) => Ok(0),
+            (Presence::Required, None) => Err(ParseError { index: *end, expected: "Expected two digits for the offset minutes" }),
+        }
+    };
+    let minutes = component(pattern.minutes, &mut end)?;
+    let seconds = if pattern.minutes == Presence::Absent || end == index + 3 { 0 } else { component(pattern.seconds, &mut end)? };
+    if hours > 18 || minutes > 59 || seconds > 59 {
+        return Err(ParseError { index, expected: "Offset is out of range" });
+    }
+    let total = sign * (hours * 3600 + minutes * 60 + seconds);
+    if total.abs() > 18 * 3600 {
+        return Err(ParseError { index, expected: "Offset is out of range" });
+    }
+    Ok((ZoneOffset::of_total_seconds(total), end))
+}
+
+/// Parses the localized GMT format, such as `GMT`, `GMT+8` or `GMT+08:00`.
+fn parse_localized_offset(text: &str, index: usize) -> Result<(ZoneOffset, usize), ParseError> {
+    if !text[index..].starts_with("GMT") {
+        return Err(ParseError { index, expected: "Expected a GMT offset such as GMT+08:00" });
+    }
+    let start = index + 3;
+    let sign = match text.as_bytes().get(start) {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Ok((ZoneOffset::UTC, start)),
+    };
+    let mut end = start + 1;
+    let mut parts = Vec::new();
+    loop {
+        let digits = text[end..].bytes().take_while(u8::is_ascii_digit).count();
+        let limit = if parts.is_empty() { 2 } else { 2.min(digits) };
+        if digits == 0 || (!parts.is_empty() && digits != 2) {
+            return Err(ParseError { index: end, expected: "Expected digits in the GMT offset" });
+        }
+        let width = digits.min(limit);
+        parts.push(text[end..end + width].parse::<i32>().expect("ASCII digits"));
+        end += width;
+        if parts.len() == 3 || !text[end..].starts_with(':') {
+            break;
+        }
+        end += 1;
+    }
+    let (hours, minutes, seconds) = (parts[0], parts.get(1).copied().unwrap_or(0), parts.get(2).copied().unwrap_or(0));
+    if hours > 18 || minutes > 59 || seconds > 59 || hours * 3600 + minutes * 60 + seconds > 18 * 3600 {
+        return Err(ParseError { index, expected: "Offset is out of range" });
+    }
+    Ok((ZoneOffset::of_total_seconds(sign * (hours * 3600 + minutes * 60 + seconds)), end))
+}
+
+/// Parses the bracketed suffix of RFC 9557: an optional time-zone annotation followed by
+/// suffix tags, each of which may be marked critical with `!`.
+fn parse_rfc9557_suffix(text: &str, mut index: usize, parsed: &mut Parsed) -> Result<usize, ParseError> {
+    let mut first = true;
+    while text[index..].starts_with('[') {
+        let start = index + 1;
+        let critical = text[start..].starts_with('!');
+        let content_start = start + usize::from(critical);
+        let end = content_start
+            + text[content_start..].find(']').ok_or(ParseError { index, expected: "Expected ] to close the annotation" })?;
+        let content = &text[content_start..end];
+        match content.split_once('=') {
+            None if first => {
+                let (zone, zone_end) = parse_zone_id(text, content_start)
+                    .filter(|&(_, zone_end)| zone_end == end)
+                    .ok_or(ParseError { index: content_start, expected: "Expected a time-zone ID" })?;
+                parsed.set_zone(zone, content_start);
+                index = zone_end + 1;
+            }
+            None => return Err(ParseError { index: content_start, expected: "Expected a suffix tag such as u-ca=iso8601" }),
+            Some((key, value)) => {
+                if !is_annotation_key(key) || !is_annotation_value(value) {
+                    return Err(ParseError { index: content_start, expected: "Expected a suffix tag such as u-ca=iso8601" });
+                }
+                if critical && key == "u-ca" && !value.eq_ignore_ascii_case("iso8601") {
+                    return Err(ParseError { index: content_start, expected: "Unsupported calendar in critical annotation" });
+                }
+                if critical && key != "u-ca" {
+                    return Err(ParseError { index: content_start, expected: "Unknown critical annotation" });
+                }
+                index = end + 1;
+            }
+        }
+        first = false;
+    }
+    Ok(index)
+}
+
+/// Parses an RFC 2822 date-time including its obsolete syntax, such as
+/// `Sun, 6 Nov 94 08 : 49 EST (comment)`, or one of the RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`)
+/// and asctime (`Sun Nov  6 08:49:37 1994`) forms of HTTP dates. Names are in English.
+fn parse_legacy_date_time(text: &str, mut index: usize, clock: &Clock, parsed: &mut Parsed) -> Result<usize, ParseError> {
+    let names = Locale::US.names();
+    let space = |at: usize| match skip_cfws(text, at)? {
+        end if end > at => Ok(end),
+        _ => Err(ParseError { index: at, expected: "Expected a space" }),
+    };
+    let word = |at: usize| &text[at..at + text[at..].bytes().take_while(u8::is_ascii_alphabetic).count()];
+    let month = |at: usize, parsed: &mut Parsed| {
+        let month = names.find_month(word(at)).ok_or(ParseError { index: at, expected: "Expected a month name such as Nov" })?;
+        parsed.set(Slot::MonthOfYear, i64::from(month.value()), at)?;
+        Ok::<usize, ParseError>(at + word(at).len())
+    };
+
+    index = skip_cfws(text, index)?;
+    let name = word(index);
+    if !name.is_empty() && names.find_month(name).is_none() {
+        let day = names.find_day(name).ok_or(ParseError { index, expected: "Expected a day-of-week name such as Sun" })?;
+        parsed.set(Slot::DayOfWeek, i64::from(day.value()), index)?;
+        index = skip_cfws(text, index + name.len())?;
+        if text[index..].starts_with(',') {
+            index = skip_cfws(text, index + 1)?;
+        }
+    }
+
+    if !word(index).is_empty() {
+        // asctime: Sun Nov  6 08:49:37 1994, always in GMT.
+        index = space(month(index, parsed)?)?;
+        let (day, end) = parse_number(text, index, 1, 2, SignStyle::NotNegative, 0)?;
+        parsed.set(Slot::DayOfMonth, day, index)?;
+        index = parse_legacy_time(text, space(end)?, parsed)?;
+        index = space(index)?;
+        let (year, end) = parse_number(text, index, 4, 4, SignStyle::NotNegative, 0)?;
+        parsed.set(Slot::Year, year, index)?;
+        parsed.set_offset(ZoneOffset::UTC, index)?;
+        return Ok(end);
+    }
+
+    let (day, end) = parse_number(text, index, 1, 2, SignStyle::NotNegative, 0)?;
+    parsed.set(Slot::DayOfMonth, day, index)?;
+    // RFC 850 separates the date with dashes: 06-Nov-94.
+    let dashed = text[end..].starts_with('-');
+    index = if dashed { end + 1 } else { space(end)? };
+    index = month(index, parsed)?;
+    index = if dashed && text[index..].starts_with('-') { index + 1 } else { space(index)? };
+    let (year, end) = parse_number(text, index, 2, 4, SignStyle::NotNegative, 0)?;
+    let year = match end - index {
+        // RFC 9110: an RFC 850 year more than 50 years in the future is in the past century.
+        2 if dashed => {
+            let current = i64::from(clock.instant().at_offset(ZoneOffset::UTC).year());
+            let year = current - current.rem_euclid(100) + year;
+            if year > current + 50 { year - 100 } else { year }
+        }
+        2 if year < 50 => 2000 + year,
+        2 | 3 => 1900 + year,
+        _ => year,
+    };
+    parsed.set(Slot::Year, year, index)?;
+    index = parse_legacy_time(text, space(end)?, parsed)?;
+    index = space(index)?;
+    let (offset, end) = parse_legacy_zone(text, index)?;
+    parsed.set_offset(offset, index)?;
+    skip_cfws(text, end)
+}
+
+/// Skips the folding whitespace and comments that the obsolete RFC 2822 syntax allows between
+/// tokens. Comments are in parentheses, may nest and may quote a character with a backslash.
+fn skip_cfws(text: &str, mut index: usize) -> Result<usize, ParseError> {
+    let bytes = text.as_bytes();
+    while let Some(&byte) = bytes.get(index) {
+        match byte {
+            b' ' | b'\t' | b'\r' | b'\n' => index += 1,
+            b'(' => {
+                let (start, mut depth) = (index, 0);
+                loop {
+                    match bytes.get(index) {
+                        None => return Err(ParseError { index: start, expected: "Expected ) to close the comment" }),
+                        Some(b'\\') => index += 1,
+                        Some(b'(') => depth += 1,
+                        Some(b')') => depth -= 1,
+                        Some(_) => {}
+                    }
+                    index += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            _ => break,
+        }
+    }
+    Ok(index)
+}
+
+/// Parses `HH:mm` with optional seconds, allowing comments and whitespace around the colons.
+fn parse_legacy_time(text: &str, index: usize, parsed: &mut Parsed) -> Result<usize, ParseError> {
+    let mut index = index;
+    for (position, slot) in [Slot::HourOfDay, Slot::MinuteOfHour, Slot::SecondOfMinute].into_iter().enumerate() {
+        if position > 0 {
+            let colon = skip_cfws(text, index)?;
+            if !text[colon..].starts_with(':') {
+                if position == 2 {
+                    break;
+                }
+                return Err(ParseError { index: colon, expected: "Expected : in the time" });
+            }
+            index = skip_cfws(text, colon + 1)?;
+        }
+        let (value, end) = parse_number(text, index, 2, 2, SignStyle::NotNegative, 0)?;
+        parsed.set(slot, value, index)?;
+        index = end;
+    }
+    Ok(index)
+}
+
+/// Parses an RFC 2822 zone: `+hhmm`, `-hhmm` or an obsolete zone name.
+fn parse_legacy_zone(text: &str, index: usize) -> Result<(ZoneOffset, usize), ParseError> {
+    let expected = ParseError { index, expected: "Expected a zone such as +0000 or GMT" };
+    if text[index..].starts_with(['+', '-']) {
+        let digits = text.get(index + 1..index + 5).filter(|digits| digits.bytes().all(|b| b.is_ascii_digit())).ok_or(expected)?;
+        let (hours, minutes) = (digits[..2].parse::<i32>().unwrap_or(0), digits[2..].parse::<i32>().unwrap_or(0));
+        if hours > 18 || minutes > 59 || hours * 60 + minutes > 18 * 60 {
+            return Err(ParseError { index, expected: "Offset is out of range" });
+        }
+        let sign = if text[index..].starts_with('-') { -1 } else { 1 };
+        return Ok((ZoneOffset::of_total_seconds(sign * (hours * 3600 + minutes * 60)), index + 5));
+    }
+    let name = &text[index..index + text[index..].bytes().take_while(u8::is_ascii_alphabetic).count()];
+    let hours = match name.to_ascii_uppercase().as_str() {
+        "UT" | "UTC" | "GMT" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        // Military zones, J excepted.
+        letter if letter.len() == 1 && letter != "J" => 0,
+        _ => return Err(expected),
+    };
+    Ok((ZoneOffset::of_hours(hours), index + name.len()))
+}
+
+/// Checks the RFC 9557 suffix key syntax: `[a-z_][a-z0-9_-]*`.
+fn is_annotation_key(key: &str) -> bool {
+    let mut bytes = key.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_lowercase() || b == b'_')
+        && bytes.all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+}
+
+/// Checks the RFC 9557 suffix value syntax: alphanumeric parts separated by `-`.
+fn is_annotation_value(value: &str) -> bool {
+    value.split('-').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+/// Parses the longest prefix of the text at `index` that is a valid zone ID.
+fn parse_zone_id(text: &str, index: usize) -> Option<(ZoneId, usize)> {
+    let run = text[index..]
+        .bytes()
+        .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'/' | b'_' | b'+' | b'-' | b':' | b'.' | b'~'))
+        .count();
+    (1..=run).rev().find_map(|length| ZoneId::try_of(&text[index..index + length]).ok().map(|zone| (zone, index + length)))
+}
+
+impl Parsed {
+    fn get(&self, slot: Slot) -> Option<i64> {
+        self.values[slot as usize]
+    }
+
+    fn set(&mut self, slot: Slot, value: i64, index: usize) -> Result<(), ParseError> {
+        match self.values[slot as usize] {
+            Some(existing) if existing != value => Err(ParseError { index, expected: "Conflicting values for the same field" }),
+            _ => {
+                self.values[slot as usize] = Some(value);
+                self.starts[slot as usize].get_or_insert(index);
+                Ok(())
+            }
+        }
+    }
+
+    fn set_offset(&mut self, offset: ZoneOffset, index: usize) -> Result<(), ParseError> {
+        match self.offset {
+            Some(existing) if existing != offset => Err(ParseError { index, expected: "Conflicting values for the offset" }),
+            _ => {
+                self.offset = Some(offset);
+                self.offset_start.get_or_insert(index);
+                Ok(())
+            }
+        }
+    }
+
+    fn set_zone(&mut self, zone: ZoneId, index: usize) {
+        self.zone = Some(zone);
+        self.zone_start.get_or_insert(index);
+    }
+
+    /// Returns where the first of `slots` that was parsed starts, or 0.
+    fn start(&self, slots: &[Slot]) -> usize {
+        slots.iter().find_map(|&slot| self.starts[slot as usize]).unwrap_or(0)
+    }
+
+    /// Returns an error at the start of the first of `slots` that was parsed.
+    fn error(&self, slots: &[Slot], expected: &'static str) -> ParseError {
+        ParseError { index: self.start(slots), expected }
+    }
+
+    /// Returns where an error of [`TemporalAccessor::from_fields`] is reported: at the
+    /// offset or zone when the instant is out of range, at the week for an invalid week, and
+    /// at 0 when the fields the type needs are missing.
+    fn conversion_error_index(&self, message: &str) -> usize {
+        match message {
+            "Instant exceeds the supported range" | "Local date-time exceeds the supported range" => {
+                self.offset_start.or(self.zone_start).unwrap_or_else(|| self.start(YEAR))
+            }
+            "Invalid value for WeekBasedYear" => self.start(&[Slot::WeekBasedYear]),
+            "Invalid value for WeekOfWeekBasedYear" => self.start(&[Slot::WeekOfWeekBasedYear]),
+            _ => 0,
+        }
+    }
+
+    /// Returns the value of the slot after checking that it is in `range`.
+    fn checked(&self, slot: Slot, range: std::ops::RangeInclusive<i64>, message: &'static str) -> Result<Option<i64>, ParseError> {
+        match self.get(slot) {
+            Some(value) if !range.contains(&value) => Err(self.error(&[slot], message)),
+            value => Ok(value),
+        }
+    }
+
+    /// Combines the parsed values into dates and times, like `java.time` does with the
+    /// resolver style. Time values past the end of the day, such as `24:00`, move the date
+    /// on by the excess days.
+    fn resolve(&self, style: ResolverStyle) -> Result<DateTimeFields, ParseError> {
+        let lenient = style == ResolverStyle::Lenient;
+        let year_of_era = self.checked(Slot::YearOfEra, 1..=999_999_999, "Invalid value for YearOfEra")?;
+        let year = match (self.get(Slot::Year), year_of_era) {
+            (Some(year), Some(year_of_era)) => {
+                let era = self.get(Slot::Era).unwrap_or(if year >= 1 { 1 } else { 0 });
+                if (if era == 1 { year_of_era } else { 1 - year_of_era }) != year {
+                    return Err(self.error(&[Slot::YearOfEra], "Conflict found: Year differs from YearOfEra"));
+                }
+                Some(year)
+            }
+            (Some(year), None) => Some(year),
+            (None, Some(year_of_era)) => Some(if self.get(Slot::Era).unwrap_or(1) == 1 { year_of_era } else { 1 - year_of_era }),
+            (None, None) => None,
+        };
+        let (year, month) = match (year, self.get(Slot::MonthOfYear)) {
+            // Months past December roll over into the following years.
+            (Some(year), Some(month)) if lenient => {
+                let months = year.checked_mul(12).and_then(|months| months.checked_add(month - 1)).ok_or(self.error(YEAR, "Invalid value for Year"))?;
+                (Some(months.div_euclid(12)), Some(months.rem_euclid(12) + 1))
+            }
+            (year, month) => (year, month),
+        };
+        if year.is_some_and(|year| !(-999_999_999..=999_999_999).contains(&year)) {
+            return Err(self.error(YEAR, "Invalid value for Year"));
+        }
+        let year = year.map(|year| year as i32);
+        let month = match month {
+            Some(month) if !(1..=12).contains(&month) => return Err(self.error(&[Slot::MonthOfYear], "Invalid value for MonthOfYear")),
+            month => month.map(|month| Month::of(month as i32)),
+        };
+        let day = if lenient && year.is_some() && month.is_some() {
+            self.get(Slot::DayOfMonth)
+        } else {
+            self.checked(Slot::DayOfMonth, 1..=31, "Invalid value for DayOfMonth")?
+        };
+        let day_of_year = if lenient && year.is_some() {
+            self.get(Slot::DayOfYear)
+        } else {
+            self.checked(Slot::DayOfYear, 1..=366, "Invalid value for DayOfYear")?
+        };
+
+        let year_error = |message| self.error(YEAR, message);
+        let mut date = match (year, month, day, day_of_year) {
+            (Some(year), Some(month), Some(day), _) => Some(match style {
+                ResolverStyle::Strict => {
+                    check_year(year).map_err(year_error)?;
+                    date_of(year, month, day as u8)
+                        .map_err(|_| self.error(&[Slot::DayOfMonth], "Invalid date: the day-of-month is past the end of the month"))?
+                }
+                ResolverStyle::Smart => {
+                    let last = month.length(crate::Year::of(year).is_leap()) as u8;
+                    date_of(year, month, (day as u8).min(last)).map_err(year_error)?
+                }
+                ResolverStyle::Lenient => date_of(year, month, 1).and_then(|first| plus_days(first, day - 1)).map_err(year_error)?,
+            }),
+            (Some(year), _, _, Some(day_of_year)) if lenient => {
+                Some(date_of(year, Month::January, 1).and_then(|first| plus_days(first, day_of_year - 1)).map_err(year_error)?)
+            }
+            (Some(year), _, _, Some(day_of_year)) => {
+                if day_of_year > i64::from(crate::Year::of(year).length()) {
+                    return Err(self.error(&[Slot::DayOfYear], "Invalid date: day-of-year 366 is only valid in a leap year"));
+                }
+                let date = time::Date::from_ordinal_date(year, day_of_year as u16).map_err(|_| year_error("Invalid date: year out of range"))?;
+                Some(LocalDate::from(date))
+            }
+            _ => None,
+        };
+
+        let week_based_year = self.checked(Slot::WeekBasedYear, -999_999_999..=999_999_999, "Invalid value for WeekBasedYear")?;
+        let week = if lenient {
+            self.get(Slot::WeekOfWeekBasedYear)
+        } else {
+            self.checked(Slot::WeekOfWeekBasedYear, 1..=53, "Invalid value for WeekOfWeekBasedYear")?
+        };
+        let day_of_week = self.checked(Slot::DayOfWeek, 1..=7, "Invalid value for DayOfWeek")?;
+        let mut year_week = None;
+        if let (Some(year), Some(week)) = (week_based_year, week) {
+            let week_date = week_date(year as i32, week, day_of_week.unwrap_or(1), style).map_err(|message| {
+                let slot = if message == "Invalid date: out of range" { Slot::WeekBasedYear } else { Slot::WeekOfWeekBasedYear };
+                self.error(&[slot], message)
+            })?;
+            match date {
+                Some(date) if !lenient && (date.week_based_year(), date.week_of_week_based_year()) != (year as i32, week as i32) => {
+                    return Err(self.error(&[Slot::WeekOfWeekBasedYear], "Conflict found: WeekOfWeekBasedYear differs from the date"));
+                }
+                Some(_) => {}
+                None if day_of_week.is_some() => date = Some(week_date),
+                None => year_week = Some((week_date.week_based_year(), week_date.week_of_week_based_year() as u8)),
+            }
+        }
+        if let (Some(date), Some(day_of_year)) = (date, day_of_year)
+            && !lenient
+            && i64::from(date.day_of_year()) != day_of_year
+        {
+            return Err(self.error(&[Slot::DayOfYear], "Conflict found: DayOfYear differs from the date"));
+        }
+
+        let (time, excess_days) = self.resolve_time(style)?;
+        if let Some(resolved) = date {
+            date = Some(plus_days(resolved, excess_days).map_err(|message| self.error(HOURS, message))?);
+        }
+        if let (Some(date), Some(day_of_week)) = (date, self.get(Slot::DayOfWeek))
+            && i64::from(date.day_of_week().value()) != day_of_week
+        {
+            return Err(self.error(&[Slot::DayOfWeek], "Conflict found: DayOfWeek differs from the date"));
+        }
+
+        Ok(DateTimeFields {
+            year: date.map_or(year, |date| Some(date.year())),
+            month: date.map_or(month, |date| Some(date.month())),
+            day: date.map_or(day.map(|day| day as u8), |date| Some(date.day_of_month() as u8)),
+            date,
+            time,
+            offset: self.offset,
+            zone: self.zone.clone(),
+            daylight: self.daylight,
+            week: year_week,
+        })
+    }
+
+    /// Resolves the time of day and the number of days it overflows into.
+    fn resolve_time(&self, style: ResolverStyle) -> Result<(Option<LocalTime>, i64), ParseError> {
+        let lenient = style == ResolverStyle::Lenient;
+        let checked = |slot, range: std::ops::RangeInclusive<i64>, message| {
+            if lenient { Ok(self.get(slot)) } else { self.checked(slot, range, message) }
+        };
+        // Like java.time, smart resolving also accepts 0 for the clock hours.
+        let smart_zero = i64::from(style == ResolverStyle::Smart);
+        let clock_hour_of_day = checked(Slot::ClockHourOfDay, 1 - smart_zero..=24, "Invalid value for ClockHourOfDay")?;
+        let clock_hour_of_am_pm = checked(Slot::ClockHourOfAmPm, 1 - smart_zero..=12, "Invalid value for ClockHourOfAmPm")?;
+        let mut hour_of_am_pm = checked(Slot::HourOfAmPm, 0..=11, "Invalid value for HourOfAmPm")?;
+        let mut hour = checked(Slot::HourOfDay, 0..=23 + smart_zero, "Invalid value for HourOfDay")?;
+
+        let merge = |target: &mut Option<i64>, value: Option<i64>, slot: Slot, message: &'static str| match (*target, value) {
+            (Some(existing), Some(value)) if existing != value => Err(self.error(&[slot], message)),
+            (None, value) => {
+                *target = value;
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        let clock_hour_of_day = clock_hour_of_day.map(|hour| if hour == 24 { 0 } else { hour });
+        merge(&mut hour, clock_hour_of_day, Slot::ClockHourOfDay, "Conflict found: HourOfDay differs from ClockHourOfDay")?;
+        let clock_hour_of_am_pm = clock_hour_of_am_pm.map(|hour| if hour == 12 { 0 } else { hour });
+        merge(&mut hour_of_am_pm, clock_hour_of_am_pm, Slot::ClockHourOfAmPm, "Conflict found: HourOfAmPm differs from ClockHourOfAmPm")?;
+        if let (Some(am_pm), Some(hour_of_am_pm)) = (self.get(Slot::AmPm), hour_of_am_pm) {
+            merge(&mut hour, Some(am_pm * 12 + hour_of_am_pm), Slot::AmPm, "Conflict found: HourOfDay differs from AmPmOfDay")?;
+        }
+        if let (Some(hour), Some(am_pm)) = (hour, self.get(Slot::AmPm))
+            && !lenient
+            && hour / 12 != am_pm
+        {
+            return Err(self.error(&[Slot::AmPm], "Conflict found: HourOfDay differs from AmPmOfDay"));
+        }
+
+        let Some(hour) = hour else {
+            return Ok((None, 0));
+        };
+        let minute = checked(Slot::MinuteOfHour, 0..=59, "Invalid value for MinuteOfHour")?.unwrap_or(0);
+        let second = checked(Slot::SecondOfMinute, 0..=59, "Invalid value for SecondOfMinute")?.unwrap_or(0);
+        let nano = checked(Slot::NanoOfSecond, 0..=999_999_999, "Invalid value for NanoOfSecond")?.unwrap_or(0);
+        if lenient {
+            const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+            let nanos = ((i128::from(hour) * 60 + i128::from(minute)) * 60 + i128::from(second)) * 1_000_000_000 + i128::from(nano);
+            let excess_days = i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).map_err(|_| self.error(HOURS, "Invalid value for HourOfDay"))?;
+            let nano_of_day = nanos.rem_euclid(NANOS_PER_DAY) as i64;
+            let seconds = (nano_of_day / 1_000_000_000) as i32;
+            let time = LocalTime::of_hms_nano(seconds / 3600, seconds / 60 % 60, seconds % 60, (nano_of_day % 1_000_000_000) as i32);
+            return Ok((Some(time), excess_days));
+        }
+        if hour == 24 {
+            return if minute == 0 && second == 0 && nano == 0 {
+                Ok((Some(LocalTime::of(0, 0, 0)), 1))
+            } else {
+                Err(self.error(HOURS, "Invalid value for HourOfDay"))
+            };
+        }
+        Ok((Some(LocalTime::of_hms_nano(hour as i32, minute as i32, second as i32, nano as i32)), 0))
+    }
+}
+
+/// Adds days to a date, failing instead of leaving the supported range of years.
+fn plus_days(date: LocalDate, days: i64) -> Result<LocalDate, &'static str> {
+    let duration = days.checked_mul(86_400).map(time::Duration::seconds);
+    duration.and_then(|duration| date.inner().checked_add(duration)).map(LocalDate::from).ok_or("Invalid date: out of range")
+}
+
+/// Returns the date of an ISO week date. Week 53 of a week-based year of 52 weeks is the
+/// first week of the next one, except when resolving strictly.
+fn week_date(year: i32, week: i64, day_of_week: i64, style: ResolverStyle) -> Result<LocalDate, &'static str> {
+    let monday = time::Date::from_iso_week_date(year, 1, time::Weekday::Monday).map_err(|_| "Invalid date: out of range")?;
+    if style == ResolverStyle::Strict && week > i64::from(time::util::weeks_in_year(year)) {
+        return Err("Invalid date: week 53 is only valid in a week-based year of 53 weeks");
+    }
+    plus_days(LocalDate::from(monday), (week - 1) * 7 + day_of_week - 1)
+}
+
+/// Checks that a year is in the range of `LocalDate`, -9999 to 9999.
+fn check_year(year: i32) -> Result<(), &'static str> {
+    if (time::Date::MIN.year()..=time::Date::MAX.year()).contains(&year) { Ok(()) } else { Err("Invalid date: year out of range") }
+}
+
+fn date_of(year: i32, month: Month, day: u8) -> Result<LocalDate, &'static str> {
+    check_year(year)?;
+    time::Date::from_calendar_date(year, month.into(), day).map(LocalDate::from).map_err(|_| "Invalid date")
+}
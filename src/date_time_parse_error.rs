@@ -0,0 +1,57 @@
+use std::fmt;
+
+/// The error returned when text cannot be parsed into a date-time value, in the style of
+/// `java.time.format.DateTimeParseException`.
+///
+/// It keeps the rejected input, the byte offset in it where parsing failed and a description
+/// of what was expected there, so callers can point at the offending part of the text.
+///
+/// # Example
+/// ```rust
+/// use joda_rs::LocalDate;
+///
+/// let err = LocalDate::try_parse("2024-1-05").unwrap_err();
+/// assert_eq!(err.input(), "2024-1-05");
+/// assert_eq!(err.error_index(), 5);
+/// assert_eq!(err.to_string(), "Text \"2024-1-05\" could not be parsed at index 5: Expected a number with more digits");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeParseError {
+    input: String,
+    index: usize,
+    expected: &'static str,
+}
+
+impl DateTimeParseError {
+    pub(crate) fn new(input: &str, index: usize, expected: &'static str) -> Self {
+        DateTimeParseError { input: input.to_string(), index, expected }
+    }
+
+    /// Returns the text that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte offset in the input where parsing failed.
+    ///
+    /// Errors found after the text matched are reported where the offending field starts,
+    /// such as the day of an invalid date like `2023-02-30` with a strict pattern or the
+    /// offset of an instant out of range. Fields missing for the requested type are reported
+    /// at 0.
+    pub fn error_index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns what was expected at the error index, or why the parsed values were rejected.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+}
+
+impl fmt::Display for DateTimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Text \"{}\" could not be parsed at index {}: {}", self.input, self.index, self.expected)
+    }
+}
+
+impl std::error::Error for DateTimeParseError {}
@@ -36,6 +36,8 @@
 /// let today = DayOfWeek::Wednesday;
 /// println!("Day number: {}", today.value()); // Outputs: Day number: 3
 /// ```
+use crate::{Locale, TextStyle};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -253,6 +255,46 @@ impl DayOfWeek {
     pub fn minus(self, days: i64) -> Self {
         self.plus(-(days))
     }
+
+    /// Returns the localized name of the day, like `java.time.DayOfWeek.getDisplayName`.
+    ///
+    /// # Example
+    /// ```
+    /// use joda_rs::{DayOfWeek, Locale, TextStyle};
+    ///
+    /// assert_eq!(DayOfWeek::Tuesday.get_display_name(TextStyle::Short, Locale::US), "Tue");
+    /// assert_eq!(DayOfWeek::Tuesday.get_display_name(TextStyle::Full, Locale::SPAIN), "martes");
+    /// assert_eq!(DayOfWeek::Wednesday.get_display_name(TextStyle::Narrow, Locale::SPAIN), "X");
+    /// assert_eq!(DayOfWeek::Sunday.get_display_name(TextStyle::Short, Locale::CHINA), "周日");
+    /// ```
+    pub fn get_display_name(self, style: TextStyle, locale: Locale) -> String {
+        locale.names().day(self, style).to_string()
+    }
+
+    /// Obtains a `DayOfWeek` from its full or short name in a locale, such as `"Tue"` or
+    /// `"mardi"`.
+    ///
+    /// # Panics
+    /// Panics if the text is not a day name, see [`DayOfWeek::try_parse_display_name`].
+    pub fn parse_display_name(text: &str, locale: Locale) -> Self {
+        Self::try_parse_display_name(text, locale).expect("invalid day-of-week name")
+    }
+
+    /// Obtains a `DayOfWeek` from its full or short name in a locale, in either the normal
+    /// or the standalone form. Case, surrounding whitespace and a trailing period are
+    /// ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use joda_rs::{DayOfWeek, Locale};
+    ///
+    /// assert_eq!(DayOfWeek::try_parse_display_name("tue", Locale::ENGLISH), Ok(DayOfWeek::Tuesday));
+    /// assert_eq!(DayOfWeek::try_parse_display_name("Mi", Locale::GERMAN), Ok(DayOfWeek::Wednesday));
+    /// assert_eq!(DayOfWeek::try_parse_display_name("Tues", Locale::ENGLISH), Err("invalid day-of-week name"));
+    /// ```
+    pub fn try_parse_display_name(text: &str, locale: Locale) -> Result<Self, &'static str> {
+        locale.names().find_day(text).ok_or("invalid day-of-week name")
+    }
 }
 
 impl From<time::Weekday> for DayOfWeek {
@@ -1,4 +1,5 @@
 use crate::temporal::TemporalInstant;
+use crate::DateTimeParseError;
 use rust_decimal::Decimal;
 use std::fmt;
 
@@ -39,6 +40,44 @@ impl Duration {
         Duration(time::Duration::nanoseconds(nanoseconds))
     }
 
+    /// Obtains a `Duration` from ISO-8601 text such as `PT8H6M12.345S`, like
+    /// `java.time.Duration.parse`.
+    ///
+    /// # Panics
+    /// Panics if the text cannot be parsed, see [`Duration::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid Duration string")
+    }
+
+    /// Obtains a `Duration` from ISO-8601 text `PnDTnHnMn.nS`, like `java.time.Duration.parse`.
+    ///
+    /// Letters may be upper or lower case and a day is 24 hours. Each amount, and the
+    /// whole text, may have a sign; the fraction of the seconds takes the sign of the
+    /// seconds and may use `.` or `,`.
+    ///
+    /// ```rust
+    /// assert_eq!(Duration::try_parse("PT8H6M12.345S"), Ok(Duration::of_milliseconds(29_172_345)));
+    /// assert_eq!(Duration::try_parse("P2DT3H"), Ok(Duration::of_hours(51)));
+    /// assert_eq!(Duration::try_parse("PT-6H3M"), Ok(Duration::of_minutes(-357)));
+    /// assert_eq!(Duration::try_parse("-PT6H3M"), Ok(Duration::of_minutes(-363)));
+    /// assert_eq!(Duration::try_parse("PT-0.5S"), Ok(Duration::of_milliseconds(-500)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DateTimeParseError`] if the text is not an ISO-8601 duration or is out of
+    /// range.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        let nanos = parse_iso(text).map_err(|(index, expected)| DateTimeParseError::new(text, index, expected))?;
+        let seconds = i64::try_from(nanos / 1_000_000_000)
+            .map_err(|_| DateTimeParseError::new(text, 0, "Duration is out of range"))?;
+        Ok(Duration(time::Duration::new(seconds, (nanos % 1_000_000_000) as i32)))
+    }
+
+    /// Returns the ISO-8601 representation, such as `PT8H6M12.345S`; the same as `Display`.
+    pub fn to_iso_string(self) -> String {
+        self.to_string()
+    }
+
     pub fn to_weeks(self) -> i64 {
         self.0.whole_weeks()
     }
@@ -216,8 +255,158 @@ impl Duration {
     }
 }
 
+/// Parses `[-+]P[nD][T[nH][nM][n[.n]S]]` into nanoseconds, or returns where it failed and
+/// what was expected there.
+fn parse_iso(text: &str) -> Result<i128, (usize, &'static str)> {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+    let negated = match bytes.first() {
+        Some(b'-') => {
+            index += 1;
+            true
+        }
+        Some(b'+') => {
+            index += 1;
+            false
+        }
+        _ => false,
+    };
+    if !bytes.get(index).is_some_and(|b| b.eq_ignore_ascii_case(&b'P')) {
+        return Err((index, "Expected P to start the duration"));
+    }
+    index += 1;
+
+    let mut total: i128 = 0;
+    let mut found = false;
+    if let Some((days, end)) = amount(bytes, index, b'D')? {
+        total += days * 86_400_000_000_000;
+        (index, found) = (end, true);
+    }
+    if bytes.get(index).is_some_and(|b| b.eq_ignore_ascii_case(&b'T')) {
+        index += 1;
+        let time_start = index;
+        for (unit, nanos) in [(b'H', 3_600_000_000_000), (b'M', 60_000_000_000)] {
+            if let Some((value, end)) = amount(bytes, index, unit)? {
+                total += value * nanos;
+                index = end;
+            }
+        }
+        if let Some((nanos, end)) = seconds(bytes, index)? {
+            total += nanos;
+            index = end;
+        }
+        if index == time_start {
+            return Err((index, "Expected hours, minutes or seconds after T"));
+        }
+        found = true;
+    }
+    if !found {
+        return Err((index, "Expected a day, hour, minute or second amount"));
+    }
+    if index != bytes.len() {
+        return Err((index, "Unparsed text found after the end of the duration"));
+    }
+    Ok(if negated { -total } else { total })
+}
+
+/// Reads a signed integer at `index`, returning its value and the index after its sign,
+/// digits and the given `unit` letter, or `None` if no such amount starts there.
+fn amount(bytes: &[u8], index: usize, unit: u8) -> Result<Option<(i128, usize)>, (usize, &'static str)> {
+    let Some((value, end)) = integer(bytes, index)? else {
+        return Ok(None);
+    };
+    if bytes.get(end).is_some_and(|b| b.eq_ignore_ascii_case(&unit)) {
+        Ok(Some((value, end + 1)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads `n[.n]S` at `index` as nanoseconds; the fraction takes the sign of the seconds.
+fn seconds(bytes: &[u8], index: usize) -> Result<Option<(i128, usize)>, (usize, &'static str)> {
+    let Some((whole, mut end)) = integer(bytes, index)? else {
+        return Ok(None);
+    };
+    let mut fraction = 0;
+    if matches!(bytes.get(end), Some(b'.' | b',')) {
+        let start = end + 1;
+        let digits = bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits > 9 {
+            return Err((start + 9, "Expected at most nine digits of fraction"));
+        }
+        fraction = bytes[start..start + digits].iter().fold(0, |value, b| value * 10 + i128::from(b - b'0'));
+        fraction *= 10_i128.pow(9 - digits as u32);
+        end = start + digits;
+    }
+    if !bytes.get(end).is_some_and(|b| b.eq_ignore_ascii_case(&b'S')) {
+        return Err((end, "Expected S after the seconds"));
+    }
+    let negative = bytes[index] == b'-';
+    Ok(Some((whole * 1_000_000_000 + if negative { -fraction } else { fraction }, end + 1)))
+}
+
+/// Reads an optionally signed integer of at most 19 digits at `index`.
+fn integer(bytes: &[u8], index: usize) -> Result<Option<(i128, usize)>, (usize, &'static str)> {
+    let start = index + usize::from(matches!(bytes.get(index), Some(b'-' | b'+')));
+    let digits = bytes[start.min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        return Ok(None);
+    }
+    if digits > 19 {
+        return Err((start, "Duration is out of range"));
+    }
+    let value = bytes[start..start + digits].iter().fold(0, |value, b| value * 10 + i128::from(b - b'0'));
+    Ok(Some((if bytes[index] == b'-' { -value } else { value }, start + digits)))
+}
+
+impl std::str::FromStr for Duration {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for Duration {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
+}
+
+/// Formats as ISO-8601 in hours, minutes and seconds, such as `PT8H6M12.345S`, `PT-0.5S` or
+/// `PT0S`, like `java.time.Duration.toString`. Days are shown as 24 hours.
 impl fmt::Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        if self.is_zero() {
+            return f.write_str("PT0S");
+        }
+        let total = self.to_nanos();
+        let (seconds, nanos) = (total.div_euclid(1_000_000_000), total.rem_euclid(1_000_000_000));
+        // Seconds and fraction share the sign of the whole value: -0.5s is -1s + 0.5s.
+        let effective = if seconds < 0 && nanos > 0 { seconds + 1 } else { seconds };
+        let (hours, minutes, secs) = (effective / 3600, effective % 3600 / 60, effective % 60);
+        f.write_str("PT")?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if secs == 0 && nanos == 0 {
+            return Ok(());
+        }
+        if seconds < 0 && nanos > 0 && secs == 0 {
+            f.write_str("-0")?;
+        } else {
+            write!(f, "{secs}")?;
+        }
+        if nanos > 0 {
+            let fraction = if seconds < 0 { 1_000_000_000 - nanos } else { nanos };
+            let digits = format!("{fraction:09}");
+            write!(f, ".{}", digits.trim_end_matches('0'))?;
+        }
+        f.write_str("S")
     }
 }
@@ -0,0 +1,284 @@
+use crate::{DateTimeFields, DateTimeFormatter, ResolverStyle};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The type that an [`InferredFormat`] parses: the most precise one that every reading of
+/// the pattern provides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TemporalKind {
+    /// A date without a time, such as `05/03/2024`.
+    LocalDate,
+    /// A date and time without an offset, such as `2024-03-05 14:07:09`.
+    LocalDateTime,
+    /// A date and time with an offset, such as `2024-03-05T14:07:09+02:00`.
+    OffsetDateTime,
+}
+
+/// The pattern that best reads a column of sample strings, such as the dates of a CSV file,
+/// with how many samples it reads and where other patterns read them differently.
+///
+/// Every candidate pattern is tried on the samples, numeric dates in the year-first,
+/// day-first and month-first orders with any separator of `-`, `/` and `.`, dates with month
+/// names, and times with or without seconds, fractions, AM/PM and offsets. The pattern that
+/// reads the most samples wins; between equally good ones, zero-padded fields win over
+/// unpadded ones, and year-first over day-first over month-first dates. Another pattern that
+/// reads as many samples but gives different values, as `MM/dd/uuuu` does for `05/03/2024`,
+/// is reported as an [`Ambiguity`]. Blank samples are ignored.
+///
+/// ```rust
+/// let inferred = InferredFormat::infer(&["05/03/2024", "17/03/2024", "01/04/2024"]);
+/// assert_eq!(inferred.pattern(), "dd/MM/uuuu");
+/// assert_eq!(inferred.kind(), TemporalKind::LocalDate);
+/// assert!(!inferred.is_ambiguous());
+/// let date: LocalDate = inferred.formatter().parse("29/02/2024").unwrap();
+///
+/// let inferred = InferredFormat::infer(&["05/03/2024", "01/04/2024"]);
+/// assert_eq!(inferred.ambiguities()[0].pattern(), "MM/dd/uuuu");
+/// assert_eq!(inferred.confidence(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredFormat {
+    pattern: String,
+    formatter: DateTimeFormatter,
+    kind: TemporalKind,
+    total: usize,
+    unmatched: Vec<usize>,
+    ambiguities: Vec<Ambiguity>,
+}
+
+/// Another pattern that reads as many samples as an [`InferredFormat`], but gives different
+/// values for some of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity {
+    pattern: String,
+    samples: Vec<usize>,
+}
+
+/// Numeric date templates, `{d}` and `{M}` standing for a padded or unpadded day and month.
+const NUMERIC_DATES: [&str; 6] = [
+    "uuuu{s}{M}{s}{d}",
+    "{d}{s}{M}{s}uuuu",
+    "{d}{s}{M}{s}yy",
+    "{M}{s}{d}{s}uuuu",
+    "{M}{s}{d}{s}yy",
+    "yy{s}{M}{s}{d}",
+];
+
+const TEXT_DATES: [&str; 10] = [
+    "{d} MMM uuuu",
+    "{d} MMMM uuuu",
+    "{d}-MMM-uuuu",
+    "{d}-MMM-yy",
+    "MMM {d}, uuuu",
+    "MMMM {d}, uuuu",
+    "MMM {d} uuuu",
+    "EEE, {d} MMM uuuu",
+    "EEEE, {d} MMMM uuuu",
+    "EEEE, MMMM {d}, uuuu",
+];
+
+/// Time templates, `{H}` and `{h}` standing for a padded or unpadded hour.
+const TIMES: [&str; 9] = [
+    "{H}:mm:ss.SSSSSSSSS",
+    "{H}:mm:ss.SSSSSS",
+    "{H}:mm:ss.SSS",
+    "{H}:mm:ss",
+    "{H}:mm",
+    "{h}:mm:ss a",
+    "{h}:mm a",
+    "{h}:mm:ssa",
+    "{h}:mma",
+];
+
+/// Offsets after the time, `xxx` before `XXX` so that `+00:00` is kept when no sample has `Z`.
+const OFFSETS: [&str; 13] = ["", "xxx", "XXX", "xx", "XX", " xxx", " XXX", " xx", " XX", "x", "X", " x", " X"];
+
+impl InferredFormat {
+    /// Infers the pattern of the samples.
+    ///
+    /// # Panics
+    /// Panics if no pattern reads any sample, see [`InferredFormat::try_infer`].
+    pub fn infer<S: AsRef<str>>(samples: &[S]) -> Self {
+        Self::try_infer(samples).expect("unable to infer a format from the samples")
+    }
+
+    /// Infers the pattern of the samples.
+    ///
+    /// # Errors
+    /// Returns an error if there are no samples that are not blank, or if no pattern reads
+    /// any of them.
+    pub fn try_infer<S: AsRef<str>>(samples: &[S]) -> Result<Self, &'static str> {
+        let samples: Vec<(usize, &str)> = samples
+            .iter()
+            .map(|sample| sample.as_ref().trim())
+            .enumerate()
+            .filter(|(_, sample)| !sample.is_empty())
+            .collect();
+        if samples.is_empty() {
+            return Err("No samples to infer a format from");
+        }
+
+        // Dates and times are inferred separately, splitting the samples that have a time at
+        // the digits before the first colon.
+        let split: Vec<(&str, char, &str)> = samples.iter().filter_map(|&(_, sample)| split_time(sample)).collect();
+        let (dates, separator, time): (Vec<&str>, _, _) = if split.len() * 2 > samples.len() {
+            let t = split.iter().filter(|(_, separator, _)| *separator == 'T').count();
+            let separator = if t * 2 >= split.len() { "'T'" } else { " " };
+            let times: Vec<&str> = split.iter().map(|&(_, _, time)| time).collect();
+            let (time, _) = best(&time_patterns(), &times).ok_or("No format matches the samples")?;
+            (split.iter().map(|&(date, _, _)| date).collect(), separator, time)
+        } else {
+            (samples.iter().map(|&(_, sample)| sample).collect(), "", String::new())
+        };
+        let date_patterns = date_patterns();
+        let (date, count) = best(&date_patterns, &dates).ok_or("No format matches the samples")?;
+
+        let compile = |date: &str| {
+            let pattern = format!("{date}{separator}{time}");
+            let formatter = DateTimeFormatter::of_pattern(&pattern).with_resolver_style(ResolverStyle::Strict);
+            let readings: Vec<Option<DateTimeFields>> =
+                samples.iter().map(|&(_, sample)| formatter.parse_fields(sample).ok()).collect();
+            (pattern, formatter, readings)
+        };
+        let (pattern, formatter, readings) = compile(&date);
+        let mut seen = vec![readings.clone()];
+        let mut ambiguities = Vec::new();
+        for other in date_patterns.iter().filter(|other| **other != date && matches(other, &dates) == count) {
+            let (pattern, _, other_readings) = compile(other);
+            if !seen.contains(&other_readings) {
+                let differing = samples.iter().zip(readings.iter().zip(&other_readings));
+                let samples = differing.filter(|(_, (a, b))| a != b).map(|(&(index, _), _)| index).collect();
+                ambiguities.push(Ambiguity { pattern, samples });
+                seen.push(other_readings);
+            }
+        }
+
+        let kind = match (time.is_empty(), time.contains(['x', 'X'])) {
+            (true, _) => TemporalKind::LocalDate,
+            (false, false) => TemporalKind::LocalDateTime,
+            (false, true) => TemporalKind::OffsetDateTime,
+        };
+        let unmatched = samples.iter().zip(&readings).filter(|(_, reading)| reading.is_none()).map(|(&(index, _), _)| index).collect();
+        Ok(InferredFormat { pattern, formatter, kind, total: samples.len(), unmatched, ambiguities })
+    }
+
+    /// Returns the inferred pattern, such as `dd/MM/uuuu`.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns a formatter for the pattern, with [`ResolverStyle::Strict`], that parses and
+    /// formats values of the [`kind`](InferredFormat::kind) of this format.
+    pub fn formatter(&self) -> &DateTimeFormatter {
+        &self.formatter
+    }
+
+    /// Returns the type that the pattern parses.
+    pub fn kind(&self) -> TemporalKind {
+        self.kind
+    }
+
+    /// Returns how sure the inference is, from 0 to 1: the share of the samples that the
+    /// pattern reads, divided between the pattern and its ambiguities.
+    pub fn confidence(&self) -> f64 {
+        self.matched() as f64 / self.total as f64 / (1 + self.ambiguities.len()) as f64
+    }
+
+    /// Returns the number of samples that the pattern reads.
+    pub fn matched(&self) -> usize {
+        self.total - self.unmatched.len()
+    }
+
+    /// Returns the number of samples that are not blank.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the indexes of the samples that the pattern does not read.
+    pub fn unmatched(&self) -> &[usize] {
+        &self.unmatched
+    }
+
+    /// Returns whether another pattern reads the samples as well but differently.
+    pub fn is_ambiguous(&self) -> bool {
+        !self.ambiguities.is_empty()
+    }
+
+    /// Returns the other patterns that read the samples as well but differently, in the
+    /// order of preference.
+    pub fn ambiguities(&self) -> &[Ambiguity] {
+        &self.ambiguities
+    }
+}
+
+impl Ambiguity {
+    /// Returns the other pattern, such as `MM/dd/uuuu`.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the indexes of the samples that the other pattern reads differently, or does
+    /// not read.
+    pub fn samples(&self) -> &[usize] {
+        &self.samples
+    }
+}
+
+/// Splits a sample into the date, the separator and the time at the digits before its
+/// first colon, when the separator is a space or `T`.
+fn split_time(sample: &str) -> Option<(&str, char, &str)> {
+    let colon = sample.find(':')?;
+    let start = sample[..colon].trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let date = sample[..start].strip_suffix([' ', 'T'])?;
+    Some((date, sample[date.len()..].chars().next()?, &sample[start..]))
+}
+
+fn date_patterns() -> Vec<String> {
+    let paddings = [("dd", "MM"), ("dd", "M"), ("d", "MM"), ("d", "M")];
+    let mut patterns = vec!["uuuuMMdd".to_string()];
+    for template in NUMERIC_DATES {
+        for separator in ["-", "/", "."] {
+            for (day, month) in paddings {
+                let pattern = template.replace("{s}", separator).replace("{d}", day).replace("{M}", month);
+                if !patterns.contains(&pattern) {
+                    patterns.push(pattern);
+                }
+            }
+        }
+    }
+    for template in TEXT_DATES {
+        patterns.extend(["dd", "d"].map(|day| template.replace("{d}", day)));
+    }
+    patterns
+}
+
+fn time_patterns() -> Vec<String> {
+    let mut patterns = Vec::new();
+    for offset in OFFSETS {
+        for template in TIMES {
+            for (hour24, hour12) in [("HH", "hh"), ("H", "h")] {
+                patterns.push(format!("{}{offset}", template.replace("{H}", hour24).replace("{h}", hour12)));
+            }
+        }
+    }
+    patterns
+}
+
+/// Returns the first pattern that reads the most texts, with that number, if any reads one.
+fn best(patterns: &[String], texts: &[&str]) -> Option<(String, usize)> {
+    let mut best: Option<(String, usize)> = None;
+    for pattern in patterns {
+        let count = matches(pattern, texts);
+        if count > best.as_ref().map_or(0, |(_, best)| *best) {
+            best = Some((pattern.clone(), count));
+        }
+    }
+    best
+}
+
+fn matches(pattern: &str, texts: &[&str]) -> usize {
+    let formatter = DateTimeFormatter::of_pattern(pattern).with_resolver_style(ResolverStyle::Strict);
+    texts.iter().filter(|text| formatter.parse_fields(text).is_ok()).count()
+}
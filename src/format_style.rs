@@ -0,0 +1,19 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The length of a localized date or time format, in the style of
+/// `java.time.format.FormatStyle`. See [`DateTimeFormatter::of_localized_date`].
+///
+/// [`DateTimeFormatter::of_localized_date`]: crate::DateTimeFormatter::of_localized_date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FormatStyle {
+    /// Full text, such as "Tuesday, April 12, 1952 AD" or "3:30:42pm PST".
+    Full,
+    /// Long text, such as "January 12, 1952".
+    Long,
+    /// Medium text, such as "Jan 12, 1952".
+    Medium,
+    /// Short text, typically numeric, such as "12.13.52" or "3:30pm".
+    Short,
+}
@@ -1,4 +1,5 @@
-use crate::{Clock, OffsetDateTime, TemporalInstant, ZoneId, ZoneOffset, ZonedDateTime};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, OffsetDateTime, TemporalInstant, ZoneId, ZoneOffset, ZonedDateTime};
+use crate::local_time::write_fraction;
 use std::fmt;
 use std::ops::{Add, Sub};
 
@@ -79,6 +80,28 @@ impl Instant {
         )
     }
 
+    /// Obtains a `Instant` from ISO-8601 text such as `2024-03-05T13:07:09Z` or `2024-03-05T14:07:09+01:00`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`Instant::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid Instant string")
+    }
+
+    /// Obtains a `Instant` like [`Instant::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(Instant::try_parse("2024-03-05T13:07:09Z"), Ok(Instant::of_epoch_second(1_709_644_029)));
+    /// assert!(Instant::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::iso_instant().parse(text)
+    }
+
     /// Returns the number of seconds from the Unix Epoch.
     ///
     /// Leap seconds are not taken into account.
@@ -151,12 +174,7 @@ impl Instant {
     }
 
     pub fn at_zone(self, zone: ZoneId) -> ZonedDateTime {
-        let utc_datetime = self.0.to_offset(time::UtcOffset::UTC);
-        let offset_seconds = zone.to_offset();
-        let target_offset =
-            time::UtcOffset::from_whole_seconds(offset_seconds).expect("Invalid timezone offset");
-        let target_datetime = utc_datetime.to_offset(target_offset);
-        ZonedDateTime::from(target_datetime)
+        ZonedDateTime::of_instant(self, zone)
     }
 
     /// Determines whether the current instance is before another instance.
@@ -231,9 +249,13 @@ impl Instant {
         self >= other
     }
 
-    // pub(crate) fn inner(self) -> time::OffsetDateTime {
-    //     self.0
-    // }
+    pub(crate) fn from(inner: time::OffsetDateTime) -> Self {
+        Self(inner)
+    }
+
+    pub(crate) fn inner(self) -> time::OffsetDateTime {
+        self.0
+    }
 }
 
 impl TemporalInstant for Instant {
@@ -250,8 +272,31 @@ impl TemporalInstant for Instant {
     }
 }
 
+/// Formats the instant as an ISO-8601 UTC date-time, like `java.time.Instant.toString`: the
+/// seconds are always present and the fraction is in groups of three digits, such as
+/// `2024-01-01T12:00:00Z` or `2024-01-01T12:00:00.500Z`.
 impl fmt::Display for Instant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        let utc = self.at_offset(ZoneOffset::UTC).to_local_date_time();
+        let time = utc.to_local_time();
+        write!(f, "{}T{:02}:{:02}:{:02}", utc.to_local_date(), time.hour(), time.minute(), time.second())?;
+        write_fraction(f, time.nanosecond())?;
+        f.write_str("Z")
+    }
+}
+
+impl std::str::FromStr for Instant {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for Instant {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
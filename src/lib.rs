@@ -7,35 +7,71 @@ pub mod instant;
 pub mod duration;
 pub mod zone_offset;
 pub mod zone_id;
+mod zone_links;
+pub mod zone_resolver;
+pub mod zone_rules;
+pub mod zone_rules_provider;
+mod zone_names;
+pub mod offset_conflict;
+pub mod resolver_style;
 pub mod period;
 pub mod day_of_week;
 pub mod month;
 pub mod year;
 pub mod year_month;
+pub mod year_week;
 pub mod month_day;
 pub mod clock;
 pub mod temporal;
+pub mod date_time_fields;
+pub mod date_time_formatter;
+pub mod date_time_parse_error;
+pub mod text_style;
+pub mod format_style;
+pub mod relative_time_formatter;
+pub mod natural_date_parser;
+pub mod log_timestamp;
+pub mod format_inference;
+pub mod locale;
+mod locale_data;
 pub mod chrono_unit;
 pub mod constants;
 pub mod serde_time;
+mod tzif;
 
 pub use chrono_unit::ChronoUnit;
 pub use clock::{Clock, FixedClock, SystemClock};
+pub use date_time_fields::{DateTimeFields, TemporalAccessor};
+pub use date_time_formatter::DateTimeFormatter;
+pub use date_time_parse_error::DateTimeParseError;
 pub use day_of_week::DayOfWeek;
 pub use duration::Duration;
+pub use format_inference::{Ambiguity, InferredFormat, TemporalKind};
+pub use format_style::FormatStyle;
 pub use instant::Instant;
 pub use local_date::LocalDate;
 pub use local_date_time::LocalDateTime;
 pub use local_time::LocalTime;
+pub use locale::Locale;
+pub use log_timestamp::{LogFormat, LogTimestampParser};
 pub use month::Month;
 pub use month_day::MonthDay;
+pub use natural_date_parser::NaturalDateParser;
+pub use offset_conflict::OffsetConflict;
 pub use offset_date_time::OffsetDateTime;
 pub use period::Period;
+pub use relative_time_formatter::{RelativeTimeFormatter, RoundingMode};
+pub use resolver_style::ResolverStyle;
 pub use temporal::TemporalInstant;
+pub use text_style::TextStyle;
 pub use year::Year;
 pub use year_month::YearMonth;
-pub use zone_id::ZoneId;
+pub use year_week::YearWeek;
+pub use zone_id::{ZoneId, ZoneIdParseError};
 pub use zone_offset::ZoneOffset;
+pub use zone_resolver::ZoneResolver;
+pub use zone_rules::{ZoneOffsetTransition, ZoneRules};
+pub use zone_rules_provider::{TzifZoneRulesProvider, ZoneRulesProvider};
 pub use zoned_date_time::ZonedDateTime;
 
 #[cfg(test)]
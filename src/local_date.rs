@@ -1,6 +1,5 @@
-use crate::{Clock, DayOfWeek, Duration, Instant, LocalDateTime, LocalTime, Month, Year, ZoneId};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, DayOfWeek, Duration, Instant, LocalDateTime, LocalTime, Month, Year, YearWeek, ZoneId};
 use std::fmt;
-use time::UtcOffset;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -14,8 +13,9 @@ pub struct LocalDate(
 );
 
 impl LocalDate {
+    /// Returns the current date in the system default zone, see [`ZoneId::system_default`].
     pub fn now() -> Self {
-        Self(time::OffsetDateTime::now_utc().to_offset(UtcOffset::current_local_offset().unwrap()).date())
+        Self::now_with_zone(ZoneId::system_default())
     }
 
     pub fn now_with_clock(clock: &Clock) -> Self {
@@ -47,6 +47,47 @@ impl LocalDate {
         Self(date)
     }
 
+    /// Obtains the date of an ISO-8601 week date: the day of the week in the week of the
+    /// week-based year, where week 1 is the week with the year's first Thursday.
+    ///
+    /// ### Panics
+    /// Panics if the week is not in the week-based year, which has 52 or 53 weeks.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDate::of_iso_week(2024, 5, DayOfWeek::Wednesday), LocalDate::of(2024, 1, 31));
+    /// assert_eq!(LocalDate::of_iso_week(2020, 53, DayOfWeek::Friday), LocalDate::of(2021, 1, 1));
+    /// ```
+    pub fn of_iso_week(year: i32, week: i32, day_of_week: DayOfWeek) -> Self {
+        Self::try_of_iso_week(year, week, day_of_week).expect("invalid week of week-based year")
+    }
+
+    /// Obtains the date of an ISO-8601 week date like [`LocalDate::of_iso_week`], returning
+    /// an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDate::try_of_iso_week(2020, 53, DayOfWeek::Friday), Ok(LocalDate::of(2021, 1, 1)));
+    /// assert!(LocalDate::try_of_iso_week(2023, 53, DayOfWeek::Monday).is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns an error if the week is not in the week-based year, or if the date is out of
+    /// the range of `LocalDate`.
+    pub fn try_of_iso_week(year: i32, week: i32, day_of_week: DayOfWeek) -> Result<Self, &'static str> {
+        if !(time::Date::MIN.year()..=time::Date::MAX.year()).contains(&year) {
+            return Err("Invalid date: year out of range");
+        }
+        if week == 53 && time::util::weeks_in_year(year) == 52 {
+            return Err("Invalid date: week 53 is only valid in a week-based year of 53 weeks");
+        }
+        let week = u8::try_from(week).ok().filter(|week| (1..=53).contains(week)).ok_or("Invalid value for WeekOfWeekBasedYear")?;
+        // The Monday is always in range, the later days of 9999-W52 are not.
+        let monday = time::Date::from_iso_week_date(year, week, time::Weekday::Monday).map_err(|_| "Invalid date: out of range")?;
+        let days = time::Duration::days(i64::from(day_of_week.value() - 1));
+        monday.checked_add(days).map(Self).ok_or("Invalid date: out of range")
+    }
+
     pub fn last_day_of_month_year(year: i32, month: i32) -> Self {
         let m = Month::of(month);
         let leap = Year::of(year).is_leap();
@@ -57,9 +98,26 @@ impl LocalDate {
         Self(d)
     }
 
-    pub fn parse(s: &str) -> Self {
-        use time::format_description::well_known::Iso8601;
-        Self(time::Date::parse(s, &Iso8601::DEFAULT).expect("invalid date string"))
+    /// Obtains a `LocalDate` from ISO-8601 text such as `2024-03-05`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`LocalDate::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid LocalDate string")
+    }
+
+    /// Obtains a `LocalDate` like [`LocalDate::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDate::try_parse("2024-03-05"), Ok(LocalDate::of(2024, 3, 5)));
+    /// assert!(LocalDate::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::iso_local_date().parse(text)
     }
 
     /// Determines whether the current instance is before another instance.
@@ -238,6 +296,35 @@ impl LocalDate {
         self.0.weekday().into()
     }
 
+    /// Returns the ISO-8601 week-based year of the date, which differs from the year in the
+    /// days around January 1 that belong to a week of the other year.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDate::of(2024, 12, 30).week_based_year(), 2025);
+    /// assert_eq!(LocalDate::of(2021, 1, 1).week_based_year(), 2020);
+    /// ```
+    pub fn week_based_year(self) -> i32 {
+        self.0.to_iso_week_date().0
+    }
+
+    /// Returns the ISO-8601 week of the week-based year of the date, from 1 to 53. Weeks
+    /// start on Monday and week 1 is the week with the year's first Thursday.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDate::of(2024, 1, 31).week_of_week_based_year(), 5);
+    /// assert_eq!(LocalDate::of(2021, 1, 1).week_of_week_based_year(), 53);
+    /// ```
+    pub fn week_of_week_based_year(self) -> i32 {
+        self.0.iso_week() as i32
+    }
+
+    /// Returns the ISO-8601 week of the date as a [`YearWeek`].
+    pub fn year_week(self) -> YearWeek {
+        YearWeek::from(self)
+    }
+
     /// Returns the number of days in the month of the date.
     ///
     /// It evaluates whether the year is a leap year and
@@ -999,8 +1086,32 @@ impl core::ops::Sub for LocalDate {
     }
 }
 
+/// Formats the date as ISO-8601 `uuuu-MM-dd`, such as `2024-01-01` or `-0044-03-15`, like
+/// `java.time.LocalDate.toString`.
 impl fmt::Display for LocalDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        let year = self.year();
+        if year < 0 {
+            write!(f, "-{:04}", -i64::from(year))?;
+        } else {
+            write!(f, "{year:04}")?;
+        }
+        write!(f, "-{:02}-{:02}", self.month_value(), self.day_of_month())
+    }
+}
+
+impl std::str::FromStr for LocalDate {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for LocalDate {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
@@ -1,4 +1,4 @@
-use crate::{Clock, DayOfWeek, Duration, Instant, LocalDate, LocalTime, Month, OffsetDateTime, TemporalInstant, Year, ZoneId, ZoneOffset, ZonedDateTime};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, DayOfWeek, Duration, Instant, LocalDate, LocalTime, Month, OffsetDateTime, TemporalInstant, Year, ZoneId, ZoneOffset, ZoneResolver, ZonedDateTime};
 use std::fmt;
 use time::UtcOffset;
 
@@ -14,9 +14,9 @@ pub struct LocalDateTime(
 );
 
 impl LocalDateTime {
+    /// Returns the current date-time in the system default zone, see [`ZoneId::system_default`].
     pub fn now() -> Self {
-        let odt = time::OffsetDateTime::now_utc();
-        Self(time::PrimitiveDateTime::new(odt.date(), odt.time()))
+        Self::now_with_zone(ZoneId::system_default())
     }
 
     pub fn now_with_clock(clock: &Clock) -> Self {
@@ -41,6 +41,28 @@ impl LocalDateTime {
         Self(time::PrimitiveDateTime::new(date.inner(), time.inner()))
     }
 
+    /// Obtains a `LocalDateTime` from ISO-8601 text such as `2024-03-05T14:07:09`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`LocalDateTime::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid LocalDateTime string")
+    }
+
+    /// Obtains a `LocalDateTime` like [`LocalDateTime::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalDateTime::try_parse("2024-03-05T14:07:09"), Ok(LocalDateTime::of(2024, 3, 5, 14, 7, 9)));
+    /// assert!(LocalDateTime::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::iso_local_date_time().parse(text)
+    }
+
     /// Determines whether the current instance is before another instance.
     ///
     /// ### Arguments
@@ -129,18 +151,43 @@ impl LocalDateTime {
         self >= other
     }
 
+    /// Converts this local date-time to an instant by resolving it in `zone`.
+    ///
+    /// The offset is the one the zone's rules give for this date-time. During an
+    /// overlap the earlier offset is used; during a gap the date-time is shifted
+    /// later by the length of the gap, like `java.time.ZonedDateTime.of`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let instant = LocalDateTime::of(2024, 7, 1, 12, 0, 0).to_instant(new_york);
+    /// assert_eq!(instant, LocalDateTime::of(2024, 7, 1, 16, 0, 0).to_instant_utc());
+    /// ```
     pub fn to_instant(self, zone: ZoneId) -> Instant {
-        Instant::of_epoch_second(self.epoch_seconds()).at_zone(zone).to_instant()
+        self.at_zone(zone).to_instant()
     }
 
     pub fn to_instant_utc(self) -> Instant {
-        Instant::of_epoch_second(self.epoch_seconds()).at_zone(ZoneId::UTC).to_instant()
+        self.at_offset(ZoneOffset::UTC).to_instant()
     }
 
     pub fn at_zone(self, zone: ZoneId) -> ZonedDateTime {
         ZonedDateTime::of(self, zone)
     }
 
+    /// Combines this date-time with a zone, using `resolver` to decide what happens in a
+    /// daylight-saving gap or overlap.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let ldt = LocalDateTime::of(2024, 3, 10, 2, 30, 0);
+    /// assert!(ldt.at_zone_with_resolver(new_york, ZoneResolver::Strict).is_err());
+    /// ```
+    pub fn at_zone_with_resolver(self, zone: ZoneId, resolver: ZoneResolver) -> Result<ZonedDateTime, &'static str> {
+        ZonedDateTime::of_with_resolver(self, zone, resolver)
+    }
+
     pub fn at_offset(self, offset: ZoneOffset) -> OffsetDateTime {
         OffsetDateTime::of(self, offset)
     }
@@ -337,6 +384,17 @@ impl LocalDateTime {
         self.0.weekday().into()
     }
 
+    /// Returns the ISO-8601 week-based year of the date, see [`LocalDate::week_based_year`].
+    pub fn week_based_year(self) -> i32 {
+        self.to_local_date().week_based_year()
+    }
+
+    /// Returns the ISO-8601 week of the week-based year of the date, from 1 to 53, see
+    /// [`LocalDate::week_of_week_based_year`].
+    pub fn week_of_week_based_year(self) -> i32 {
+        self.to_local_date().week_of_week_based_year()
+    }
+
     /// Returns the number of days in the month of the date.
     ///
     /// It evaluates whether the year is a leap year and
@@ -643,8 +701,26 @@ impl TemporalInstant for LocalDateTime {
     }
 }
 
+/// Formats the date-time as ISO-8601, such as `2024-01-01T12:00`, like
+/// `java.time.LocalDateTime.toString`.
 impl fmt::Display for LocalDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}T{}", self.to_local_date(), self.to_local_time())
+    }
+}
+
+impl std::str::FromStr for LocalDateTime {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for LocalDateTime {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
@@ -1,8 +1,7 @@
-use crate::{Clock, Duration, Instant, LocalDate, LocalDateTime, ZoneId};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, Duration, Instant, LocalDate, LocalDateTime, ZoneId};
 use core::ops::Sub;
 use std::fmt;
 use std::ops::Add;
-use time::UtcOffset;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,8 +15,9 @@ pub struct LocalTime(
 );
 
 impl LocalTime {
+    /// Returns the current time in the system default zone, see [`ZoneId::system_default`].
     pub fn now() -> Self {
-        Self(time::OffsetDateTime::now_utc().to_offset(UtcOffset::current_local_offset().unwrap()).time())
+        Self::now_with_zone(ZoneId::system_default())
     }
 
     pub fn now_with_clock(clock: &Clock) -> Self {
@@ -73,9 +73,26 @@ impl LocalTime {
         LocalTime(time::Time::from_hms_nano(hour, minute, second, nano).unwrap())
     }
 
-    pub fn parse(s: &str) -> Self {
-        use time::format_description::well_known::Iso8601;
-        Self(time::Time::parse(s, &Iso8601::DEFAULT).expect("invalid time string"))
+    /// Obtains a `LocalTime` from ISO-8601 text such as `14:07`, `14:07:09` or `14:07:09.123456789`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`LocalTime::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid LocalTime string")
+    }
+
+    /// Obtains a `LocalTime` like [`LocalTime::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(LocalTime::try_parse("14:07:09.5"), Ok(LocalTime::of_hms_nano(14, 7, 9, 500_000_000)));
+    /// assert!(LocalTime::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::iso_local_time().parse(text)
     }
 
     /// Determines whether the current instance is before another instance.
@@ -328,8 +345,46 @@ impl Sub for LocalTime {
     }
 }
 
+/// Formats the time as ISO-8601 like `java.time.LocalTime.toString`: `HH:mm`, with the
+/// seconds only when the seconds or nanoseconds are not zero, and the fraction in groups of
+/// three digits, such as `10:15`, `10:15:30` or `10:15:30.120`.
 impl fmt::Display for LocalTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{:02}:{:02}", self.hour(), self.minute())?;
+        if self.second() > 0 || self.nanosecond() > 0 {
+            write!(f, ":{:02}", self.second())?;
+            write_fraction(f, self.nanosecond())?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a non-zero fraction of a second as `.SSS`, `.SSSSSS` or `.SSSSSSSSS`, using the
+/// fewest groups of three digits that represent it exactly.
+pub(crate) fn write_fraction(f: &mut fmt::Formatter<'_>, nanos: i32) -> fmt::Result {
+    if nanos == 0 {
+        Ok(())
+    } else if nanos % 1_000_000 == 0 {
+        write!(f, ".{:03}", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        write!(f, ".{:06}", nanos / 1_000)
+    } else {
+        write!(f, ".{nanos:09}")
+    }
+}
+
+impl std::str::FromStr for LocalTime {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for LocalTime {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
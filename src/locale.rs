@@ -0,0 +1,175 @@
+use crate::locale_data::{self, LocaleNames, LocalePatterns};
+use crate::DayOfWeek;
+use std::fmt;
+
+/// A language with an optional country or region, in the style of `java.util.Locale`, used
+/// to choose localized text such as month and day names.
+///
+/// Names are available for English, French, German, Spanish, Italian, Portuguese, Dutch,
+/// Japanese, Chinese and Arabic; other languages fall back to English.
+///
+/// ```rust
+/// let locale = Locale::for_language_tag("fr-CA").unwrap();
+/// assert_eq!(locale.language(), "fr");
+/// assert_eq!(locale.country(), "CA");
+/// assert_eq!(Month::September.get_display_name(TextStyle::Full, locale), "septembre");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Locale {
+    language: [u8; 3],
+    country: [u8; 3],
+}
+
+impl Locale {
+    pub const ENGLISH: Locale = Locale::of_static("en", "");
+    pub const US: Locale = Locale::of_static("en", "US");
+    pub const UK: Locale = Locale::of_static("en", "GB");
+    pub const FRENCH: Locale = Locale::of_static("fr", "");
+    pub const FRANCE: Locale = Locale::of_static("fr", "FR");
+    pub const GERMAN: Locale = Locale::of_static("de", "");
+    pub const GERMANY: Locale = Locale::of_static("de", "DE");
+    pub const SPANISH: Locale = Locale::of_static("es", "");
+    pub const SPAIN: Locale = Locale::of_static("es", "ES");
+    pub const ITALIAN: Locale = Locale::of_static("it", "");
+    pub const ITALY: Locale = Locale::of_static("it", "IT");
+    pub const PORTUGUESE: Locale = Locale::of_static("pt", "");
+    pub const BRAZIL: Locale = Locale::of_static("pt", "BR");
+    pub const DUTCH: Locale = Locale::of_static("nl", "");
+    pub const NETHERLANDS: Locale = Locale::of_static("nl", "NL");
+    pub const JAPANESE: Locale = Locale::of_static("ja", "");
+    pub const JAPAN: Locale = Locale::of_static("ja", "JP");
+    pub const CHINESE: Locale = Locale::of_static("zh", "");
+    pub const CHINA: Locale = Locale::of_static("zh", "CN");
+    pub const ARABIC: Locale = Locale::of_static("ar", "");
+
+    /// Creates a locale from a language code such as `"fr"` and a country code such as
+    /// `"FR"`, or `""` for none. The codes are normalized to lower and upper case.
+    ///
+    /// # Panics
+    /// Panics if a code is invalid, see [`Locale::try_of`].
+    pub fn of(language: &str, country: &str) -> Self {
+        Self::try_of(language, country).expect("invalid locale")
+    }
+
+    /// Creates a locale from a language code and a country code.
+    ///
+    /// # Errors
+    /// Returns an error unless the language is 2 or 3 letters and the country is empty,
+    /// 2 letters or 3 digits (a UN M.49 region such as `419`).
+    pub fn try_of(language: &str, country: &str) -> Result<Self, &'static str> {
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err("invalid language code");
+        }
+        let valid_country = country.is_empty()
+            || (country.len() == 2 && country.bytes().all(|b| b.is_ascii_alphabetic()))
+            || (country.len() == 3 && country.bytes().all(|b| b.is_ascii_digit()));
+        if !valid_country {
+            return Err("invalid country code");
+        }
+        let mut locale = Locale { language: [0; 3], country: [0; 3] };
+        for (slot, b) in locale.language.iter_mut().zip(language.bytes()) {
+            *slot = b.to_ascii_lowercase();
+        }
+        for (slot, b) in locale.country.iter_mut().zip(country.bytes()) {
+            *slot = b.to_ascii_uppercase();
+        }
+        Ok(locale)
+    }
+
+    /// Creates a locale from an IETF BCP 47 language tag such as `"en-US"` or
+    /// `"zh-Hans-CN"`, like `java.util.Locale.forLanguageTag`. Underscores are accepted as
+    /// separators; a script and any subtags after the region are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if the tag does not start with a valid language, or if the subtag
+    /// after the language and script is neither a region nor a variant.
+    pub fn for_language_tag(tag: &str) -> Result<Self, &'static str> {
+        let mut subtags = tag.split(['-', '_']).peekable();
+        let language = subtags.next().unwrap_or_default();
+        if subtags.peek().is_some_and(|script| script.len() == 4 && script.bytes().all(|b| b.is_ascii_alphabetic())) {
+            subtags.next();
+        }
+        match subtags.next() {
+            Some(region) if region.len() == 2 || region.len() == 3 => Self::try_of(language, region),
+            Some(variant) if variant.len() >= 4 => Self::try_of(language, ""),
+            Some(_) => Err("invalid language tag"),
+            None => Self::try_of(language, ""),
+        }
+    }
+
+    /// Returns the lowercase language code, such as `"fr"`.
+    pub fn language(&self) -> &str {
+        code(&self.language)
+    }
+
+    /// Returns the uppercase country code, such as `"FR"`, or `""` if there is none.
+    pub fn country(&self) -> &str {
+        code(&self.country)
+    }
+
+    /// Returns the IETF BCP 47 language tag, such as `"fr-FR"` or `"fr"`.
+    pub fn to_language_tag(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the first day of the week in this locale, from the CLDR week data of its
+    /// country, like `java.time.temporal.WeekFields.of(locale).getFirstDayOfWeek()`.
+    ///
+    /// A locale without a country uses the most likely country for its language, so
+    /// `Locale::ENGLISH` starts the week on Sunday like the United States.
+    ///
+    /// ```rust
+    /// assert_eq!(Locale::US.first_day_of_week(), DayOfWeek::Sunday);
+    /// assert_eq!(Locale::FRANCE.first_day_of_week(), DayOfWeek::Monday);
+    /// assert_eq!(Locale::of("ar", "EG").first_day_of_week(), DayOfWeek::Saturday);
+    /// ```
+    pub fn first_day_of_week(&self) -> DayOfWeek {
+        let country = match self.country() {
+            "" => locale_data::likely_country(self.language()),
+            country => country,
+        };
+        locale_data::first_day_of_week(country)
+    }
+
+    /// The month, day and am-pm names of this locale's language.
+    pub(crate) fn names(&self) -> &'static LocaleNames {
+        locale_data::names(self.language())
+    }
+
+    /// The localized date and time patterns of this locale.
+    pub(crate) fn patterns(&self) -> &'static LocalePatterns {
+        locale_data::patterns(self.language(), self.country())
+    }
+
+    const fn of_static(language: &str, country: &str) -> Self {
+        let (language, country) = (language.as_bytes(), country.as_bytes());
+        let mut locale = Locale { language: [0; 3], country: [0; 3] };
+        let mut i = 0;
+        while i < language.len() {
+            locale.language[i] = language[i];
+            i += 1;
+        }
+        let mut i = 0;
+        while i < country.len() {
+            locale.country[i] = country[i];
+            i += 1;
+        }
+        locale
+    }
+}
+
+fn code(bytes: &[u8; 3]) -> &str {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(3);
+    std::str::from_utf8(&bytes[..len]).unwrap_or_default()
+}
+
+/// Formats as the language tag, such as `fr-FR`.
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.language())?;
+        if !self.country().is_empty() {
+            write!(f, "-{}", self.country())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,401 @@
+//! Localized month, day-of-week and am-pm names and the first day of the week from CLDR,
+//! for the languages this crate supports.
+//!
+//! The names and patterns come from CLDR 44, `common/main/<language>.xml` and the week data of
+//! `common/supplemental/supplementalData.xml`.
+
+use crate::zone_id::localized_offset;
+use crate::{DayOfWeek, Month, TextStyle, ZoneOffset};
+
+/// The names of one language. Each array holds the full, short and narrow names; days start
+/// on Monday. The standalone names are only given where they differ from the format names.
+pub(crate) struct LocaleNames {
+    months: [[&'static str; 12]; 3],
+    standalone_months: Option<[[&'static str; 12]; 3]>,
+    days: [[&'static str; 7]; 3],
+    standalone_days: Option<[[&'static str; 7]; 3]>,
+    pub(crate) am_pm: [&'static str; 2],
+    /// The localized GMT format used for zone names, as the text for GMT and the minus sign,
+    /// or `None` for English, which has the names of the metazones.
+    gmt: Option<(&'static str, char)>,
+}
+
+impl LocaleNames {
+    pub(crate) fn month(&self, month: Month, style: TextStyle) -> &'static str {
+        let months = self.standalone_months.as_ref().filter(|_| style.is_standalone()).unwrap_or(&self.months);
+        months[style_index(style)][month.value() as usize - 1]
+    }
+
+    pub(crate) fn day(&self, day: DayOfWeek, style: TextStyle) -> &'static str {
+        let days = self.standalone_days.as_ref().filter(|_| style.is_standalone()).unwrap_or(&self.days);
+        days[style_index(style)][day.value() as usize - 1]
+    }
+
+    /// Returns the zone name of an offset in the localized GMT format, such as `UTC−05:00`
+    /// in French, or `None` if the language uses the English metazone names.
+    pub(crate) fn localized_gmt(&self, offset: ZoneOffset, full: bool) -> Option<String> {
+        let (gmt, minus) = self.gmt?;
+        let english = localized_offset(offset, full);
+        Some(format!("{gmt}{}", english.trim_start_matches("GMT").replace('-', &minus.to_string())))
+    }
+
+    /// Finds the month whose full or short name, normal or standalone, is `text`.
+    pub(crate) fn find_month(&self, text: &str) -> Option<Month> {
+        let names = Some(&self.months).into_iter().chain(&self.standalone_months).flat_map(|months| &months[..2]);
+        find(text, names.flat_map(|styles| styles.iter().zip(1..))).map(Month::of)
+    }
+
+    /// Finds the day whose full or short name, normal or standalone, is `text`.
+    pub(crate) fn find_day(&self, text: &str) -> Option<DayOfWeek> {
+        let names = Some(&self.days).into_iter().chain(&self.standalone_days).flat_map(|days| &days[..2]);
+        find(text, names.flat_map(|styles| styles.iter().zip(1..))).map(DayOfWeek::of)
+    }
+}
+
+/// Returns the value of the name equal to `text`, ignoring case, surrounding whitespace and
+/// a trailing period on either.
+fn find<'a>(text: &str, names: impl Iterator<Item = (&'a &'static str, i32)>) -> Option<i32> {
+    let text = text.trim().trim_end_matches('.').to_lowercase();
+    names.into_iter().find(|(name, _)| name.trim_end_matches('.').to_lowercase() == text).map(|(_, value)| value)
+}
+
+fn style_index(style: TextStyle) -> usize {
+    match style.as_normal() {
+        TextStyle::Full => 0,
+        TextStyle::Short => 1,
+        _ => 2,
+    }
+}
+
+/// Returns the names for a language code, falling back to English.
+pub(crate) fn names(language: &str) -> &'static LocaleNames {
+    match language {
+        "fr" => &FR,
+        "de" => &DE,
+        "es" => &ES,
+        "it" => &IT,
+        "pt" => &PT,
+        "nl" => &NL,
+        "ja" => &JA,
+        "zh" => &ZH,
+        "ar" => &AR,
+        _ => &EN,
+    }
+}
+
+/// The localized date, time and date-time patterns of a locale, each indexed by
+/// `FormatStyle`. The date-time pattern joins the date `{1}` and the time `{0}` and is
+/// chosen by the date style.
+pub(crate) struct LocalePatterns {
+    pub(crate) dates: [&'static str; 4],
+    pub(crate) times: [&'static str; 4],
+    pub(crate) date_times: [&'static str; 4],
+}
+
+/// Returns the patterns for a locale, by language and, where it matters, country, falling
+/// back to English (United States).
+pub(crate) fn patterns(language: &str, country: &str) -> &'static LocalePatterns {
+    match (language, country) {
+        ("en", "GB" | "IE" | "AU" | "NZ" | "IN" | "ZA") => &EN_GB_PATTERNS,
+        ("fr", _) => &FR_PATTERNS,
+        ("de", _) => &DE_PATTERNS,
+        ("es", _) => &ES_PATTERNS,
+        ("it", _) => &IT_PATTERNS,
+        ("pt", "PT") => &PT_PT_PATTERNS,
+        ("pt", _) => &PT_PATTERNS,
+        ("nl", _) => &NL_PATTERNS,
+        ("ja", _) => &JA_PATTERNS,
+        ("zh", _) => &ZH_PATTERNS,
+        ("ar", _) => &AR_PATTERNS,
+        _ => &EN_PATTERNS,
+    }
+}
+
+const TWENTY_FOUR_HOUR_TIMES: [&str; 4] = ["HH:mm:ss zzzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"];
+
+static EN_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    times: ["h:mm:ss a zzzz", "h:mm:ss a z", "h:mm:ss a", "h:mm a"],
+    date_times: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+};
+static EN_GB_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+};
+static FR_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} 'à' {0}", "{1} 'à' {0}", "{1}, {0}", "{1} {0}"],
+};
+static DE_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} 'um' {0}", "{1} 'um' {0}", "{1}, {0}", "{1}, {0}"],
+};
+static ES_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "d MMM y", "d/M/yy"],
+    times: ["H:mm:ss (zzzz)", "H:mm:ss z", "H:mm:ss", "H:mm"],
+    date_times: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+};
+static IT_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} {0}", "{1} {0}", "{1}, {0}", "{1}, {0}"],
+};
+static PT_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "d 'de' MMM 'de' y", "dd/MM/y"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+};
+static PT_PT_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE, d 'de' MMMM 'de' y", "d 'de' MMMM 'de' y", "dd/MM/y", "dd/MM/yy"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} 'às' {0}", "{1} 'às' {0}", "{1}, {0}", "{1}, {0}"],
+};
+static NL_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    times: TWENTY_FOUR_HOUR_TIMES,
+    date_times: ["{1} 'om' {0}", "{1} 'om' {0}", "{1} {0}", "{1} {0}"],
+};
+static JA_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+    times: ["H時mm分ss秒 zzzz", "H:mm:ss z", "H:mm:ss", "H:mm"],
+    date_times: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+};
+static ZH_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["y年M月d日EEEE", "y年M月d日", "y年M月d日", "y/M/d"],
+    times: ["zzzz HH:mm:ss", "z HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    date_times: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+};
+static AR_PATTERNS: LocalePatterns = LocalePatterns {
+    dates: ["EEEE، d MMMM y", "d MMMM y", "dd\u{200f}/MM\u{200f}/y", "d\u{200f}/M\u{200f}/y"],
+    times: ["h:mm:ss a zzzz", "h:mm:ss a z", "h:mm:ss a", "h:mm a"],
+    date_times: ["{1} في {0}", "{1} في {0}", "{1}، {0}", "{1}، {0}"],
+};
+
+/// Returns the most likely country for a language, or the world region `001`.
+pub(crate) fn likely_country(language: &str) -> &'static str {
+    match language {
+        "en" => "US",
+        "fr" => "FR",
+        "de" => "DE",
+        "es" => "ES",
+        "it" => "IT",
+        "pt" => "BR",
+        "nl" => "NL",
+        "ja" => "JP",
+        "zh" => "CN",
+        "ar" => "EG",
+        _ => "001",
+    }
+}
+
+/// Returns the first day of the week in a country, which is Monday unless CLDR says otherwise.
+pub(crate) fn first_day_of_week(country: &str) -> DayOfWeek {
+    if SUNDAY_FIRST.contains(&country) {
+        DayOfWeek::Sunday
+    } else if SATURDAY_FIRST.contains(&country) {
+        DayOfWeek::Saturday
+    } else if country == "MV" {
+        DayOfWeek::Friday
+    } else {
+        DayOfWeek::Monday
+    }
+}
+
+const SUNDAY_FIRST: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT", "GU", "HK", "HN", "ID", "IL",
+    "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR",
+    "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+const SATURDAY_FIRST: &[&str] = &["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+
+const NUMBERED_MONTHS: [&str; 12] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"];
+const JA_MONTHS: [&str; 12] = ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"];
+
+const EN_MONTHS: [[&str; 12]; 3] = [
+    ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+    ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const EN_DAYS: [[&str; 7]; 3] = [
+    ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+    ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    ["M", "T", "W", "T", "F", "S", "S"],
+];
+static EN: LocaleNames = LocaleNames {
+    months: EN_MONTHS,
+    standalone_months: None,
+    days: EN_DAYS,
+    standalone_days: None,
+    am_pm: ["AM", "PM"],
+    gmt: None,
+};
+
+const FR_MONTHS: [[&str; 12]; 3] = [
+    ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+    ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const FR_DAYS: [[&str; 7]; 3] = [
+    ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+    ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    ["L", "M", "M", "J", "V", "S", "D"],
+];
+static FR: LocaleNames = LocaleNames {
+    months: FR_MONTHS,
+    standalone_months: None,
+    days: FR_DAYS,
+    standalone_days: None,
+    am_pm: ["AM", "PM"],
+    gmt: Some(("UTC", '\u{2212}')),
+};
+
+const DE_MONTH_NAMES: [&str; 12] =
+    ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"];
+const DE_NARROW_MONTHS: [&str; 12] = ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+const DE_DAY_NAMES: [&str; 7] = ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"];
+const DE_NARROW_DAYS: [&str; 7] = ["M", "D", "M", "D", "F", "S", "S"];
+static DE: LocaleNames = LocaleNames {
+    months: [
+        DE_MONTH_NAMES,
+        ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+        DE_NARROW_MONTHS,
+    ],
+    standalone_months: Some([
+        DE_MONTH_NAMES,
+        ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"],
+        DE_NARROW_MONTHS,
+    ]),
+    days: [DE_DAY_NAMES, ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."], DE_NARROW_DAYS],
+    standalone_days: Some([DE_DAY_NAMES, ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"], DE_NARROW_DAYS]),
+    am_pm: ["AM", "PM"],
+    gmt: Some(("GMT", '-')),
+};
+
+const ES_MONTHS: [[&str; 12]; 3] = [
+    ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+    ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+    ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const ES_DAYS: [[&str; 7]; 3] = [
+    ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+    ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    ["L", "M", "X", "J", "V", "S", "D"],
+];
+static ES: LocaleNames = LocaleNames {
+    months: ES_MONTHS,
+    standalone_months: None,
+    days: ES_DAYS,
+    standalone_days: None,
+    am_pm: ["a. m.", "p. m."],
+    gmt: Some(("GMT", '-')),
+};
+
+const IT_MONTHS: [[&str; 12]; 3] = [
+    ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"],
+    ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+    ["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"],
+];
+const IT_DAYS: [[&str; 7]; 3] = [
+    ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+    ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    ["L", "M", "M", "G", "V", "S", "D"],
+];
+static IT: LocaleNames = LocaleNames {
+    months: IT_MONTHS,
+    standalone_months: None,
+    days: IT_DAYS,
+    standalone_days: None,
+    am_pm: ["AM", "PM"],
+    gmt: Some(("GMT", '-')),
+};
+
+const PT_MONTHS: [[&str; 12]; 3] = [
+    ["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"],
+    ["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const PT_DAYS: [[&str; 7]; 3] = [
+    ["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"],
+    ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+    ["S", "T", "Q", "Q", "S", "S", "D"],
+];
+static PT: LocaleNames = LocaleNames {
+    months: PT_MONTHS,
+    standalone_months: None,
+    days: PT_DAYS,
+    standalone_days: None,
+    am_pm: ["AM", "PM"],
+    gmt: Some(("GMT", '-')),
+};
+
+const NL_MONTHS: [[&str; 12]; 3] = [
+    ["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"],
+    ["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const NL_DAYS: [[&str; 7]; 3] = [
+    ["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"],
+    ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    ["M", "D", "W", "D", "V", "Z", "Z"],
+];
+static NL: LocaleNames = LocaleNames {
+    months: NL_MONTHS,
+    standalone_months: None,
+    days: NL_DAYS,
+    standalone_days: None,
+    am_pm: ["a.m.", "p.m."],
+    gmt: Some(("GMT", '-')),
+};
+
+const JA_MONTH_STYLES: [[&str; 12]; 3] = [JA_MONTHS, JA_MONTHS, NUMBERED_MONTHS];
+const JA_DAYS: [[&str; 7]; 3] = [
+    ["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"],
+    ["月", "火", "水", "木", "金", "土", "日"],
+    ["月", "火", "水", "木", "金", "土", "日"],
+];
+static JA: LocaleNames = LocaleNames {
+    months: JA_MONTH_STYLES,
+    standalone_months: None,
+    days: JA_DAYS,
+    standalone_days: None,
+    am_pm: ["午前", "午後"],
+    gmt: Some(("GMT", '-')),
+};
+
+const ZH_MONTHS: [[&str; 12]; 3] = [
+    ["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"],
+    JA_MONTHS,
+    NUMBERED_MONTHS,
+];
+const ZH_DAYS: [[&str; 7]; 3] = [
+    ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"],
+    ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    ["一", "二", "三", "四", "五", "六", "日"],
+];
+static ZH: LocaleNames = LocaleNames {
+    months: ZH_MONTHS,
+    standalone_months: None,
+    days: ZH_DAYS,
+    standalone_days: None,
+    am_pm: ["上午", "下午"],
+    gmt: Some(("GMT", '-')),
+};
+
+const AR_MONTH_NAMES: [&str; 12] =
+    ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"];
+const AR_MONTHS: [[&str; 12]; 3] =
+    [AR_MONTH_NAMES, AR_MONTH_NAMES, ["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"]];
+const AR_DAY_NAMES: [&str; 7] = ["الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت", "الأحد"];
+const AR_DAYS: [[&str; 7]; 3] = [AR_DAY_NAMES, AR_DAY_NAMES, ["ن", "ث", "ر", "خ", "ج", "س", "ح"]];
+static AR: LocaleNames = LocaleNames {
+    months: AR_MONTHS,
+    standalone_months: None,
+    days: AR_DAYS,
+    standalone_days: None,
+    am_pm: ["ص", "م"],
+    gmt: Some(("غرينتش", '-')),
+};
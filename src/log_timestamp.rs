@@ -0,0 +1,189 @@
+use crate::{
+    Clock, DateTimeFormatter, DateTimeParseError, Instant, LocalDate, LocalDateTime, LocalTime, Locale, OffsetDateTime,
+    ZoneOffset, ZoneResolver, ZonedDateTime,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The timestamp formats of common log sources, for [`LogTimestampParser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LogFormat {
+    /// BSD syslog, RFC 3164: `Oct 11 22:14:15`, with the day padded by a space as in
+    /// `Oct  1 22:14:15`. It has neither a year nor a zone: the local date-time is in the
+    /// zone of the parser's clock and the year is inferred from it.
+    Rfc3164,
+    /// Syslog, RFC 5424: an RFC 3339 timestamp such as `2003-10-11T22:14:15.003Z` or
+    /// `2003-08-24T05:14:15.000003-07:00`, with seconds and at most six digits of fraction.
+    /// The NILVALUE `-` is rejected.
+    Rfc5424,
+    /// Apache Common and Combined Log Format, and nginx `$time_local`:
+    /// `10/Oct/2000:13:55:36 -0700`, optionally in brackets.
+    CommonLog,
+    /// nginx `$time_iso8601`: `2000-10-10T13:55:36-07:00`.
+    NginxIso8601,
+    /// nginx `$msec`: seconds since the epoch with milliseconds, `1700000000.123`.
+    NginxMsec,
+    /// journald `__REALTIME_TIMESTAMP`: microseconds since the epoch, `1700000000123456`.
+    Journald,
+}
+
+/// A parser for the timestamps of log lines in one [`LogFormat`], producing an `Instant` or
+/// an `OffsetDateTime`.
+///
+/// Formats without a year, RFC 3164, are resolved with a [`Clock`]: the year is the latest
+/// one that does not put the timestamp more than a day after the clock's time. Just after
+/// January 1 a line from December 31 is from the previous year, and a line stamped slightly
+/// ahead of the clock on December 31 is from the next one.
+///
+/// ```rust
+/// let clock = Clock::fixed_at_local(LocalDateTime::of(2025, 1, 1, 0, 5, 0), ZoneId::UTC);
+/// let syslog = LogTimestampParser::new(LogFormat::Rfc3164).with_clock(clock);
+/// let odt = syslog.parse_offset_date_time("Dec 31 23:59:58").unwrap();
+/// assert_eq!(odt.to_local_date_time(), LocalDateTime::of(2024, 12, 31, 23, 59, 58));
+///
+/// let clf = LogTimestampParser::new(LogFormat::CommonLog);
+/// let odt = clf.parse_offset_date_time("[10/Oct/2000:13:55:36 -0700]").unwrap();
+/// assert_eq!(odt.offset(), -7 * 3600);
+///
+/// let journald = LogTimestampParser::new(LogFormat::Journald);
+/// assert_eq!(journald.parse_instant("1700000000123456"), Ok(Instant::of_epoch_second_nano(1_700_000_000, 123_456_000)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogTimestampParser {
+    format: LogFormat,
+    clock: Clock,
+}
+
+impl LogTimestampParser {
+    /// Creates a parser for `format` that infers missing years and zones from the system
+    /// clock in the system default zone.
+    pub fn new(format: LogFormat) -> Self {
+        LogTimestampParser { format, clock: Clock::system_default_zone() }
+    }
+
+    /// Returns a copy of this parser that infers missing years and zones from `clock`.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the format this parser reads.
+    pub fn format(&self) -> LogFormat {
+        self.format
+    }
+
+    /// Returns the clock used for missing years and zones.
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Parses a timestamp into an instant.
+    ///
+    /// # Errors
+    /// Returns an error if the text is not a timestamp in the format of this parser.
+    pub fn parse_instant(&self, text: &str) -> Result<Instant, DateTimeParseError> {
+        match self.format {
+            LogFormat::NginxMsec => parse_epoch(text, 3),
+            LogFormat::Journald => parse_epoch(text, 6),
+            _ => self
+                .parse_offset_date_time(text)?
+                .try_to_instant()
+                .map_err(|message| DateTimeParseError::new(text, 0, message)),
+        }
+    }
+
+    /// Parses a timestamp into a date-time with its offset. Epoch timestamps are in UTC and
+    /// RFC 3164 timestamps have the offset of the clock's zone at that time.
+    ///
+    /// # Errors
+    /// Returns an error if the text is not a timestamp in the format of this parser.
+    pub fn parse_offset_date_time(&self, text: &str) -> Result<OffsetDateTime, DateTimeParseError> {
+        match self.format {
+            LogFormat::Rfc3164 => self.parse_rfc_3164(text),
+            LogFormat::Rfc5424 => {
+                if text == "-" {
+                    return Err(DateTimeParseError::new(text, 0, "The timestamp is the NILVALUE"));
+                }
+                DateTimeFormatter::rfc_5424_timestamp().parse(text)
+            }
+            LogFormat::NginxIso8601 => DateTimeFormatter::iso_offset_date_time().parse(text),
+            LogFormat::CommonLog => {
+                let inner = text.strip_prefix('[').and_then(|rest| rest.strip_suffix(']'));
+                DateTimeFormatter::common_log_format().parse(inner.unwrap_or(text)).map_err(|error| match inner {
+                    Some(_) => DateTimeParseError::new(text, error.error_index() + 1, error.expected()),
+                    None => error,
+                })
+            }
+            LogFormat::NginxMsec | LogFormat::Journald => Ok(self.parse_instant(text)?.at_offset(ZoneOffset::UTC)),
+        }
+    }
+
+    fn parse_rfc_3164(&self, text: &str) -> Result<OffsetDateTime, DateTimeParseError> {
+        let error = |index: usize, expected| DateTimeParseError::new(text, index, expected);
+        let month = text.get(..3).and_then(|name| Locale::US.names().find_month(name)).ok_or(error(0, "Expected a month name such as Oct"))?;
+        let padded = text.get(3..5) == Some("  ");
+        let day_start = if padded { 5 } else { 4 };
+        if text.get(3..4) != Some(" ") {
+            return Err(error(3, "Expected a space"));
+        }
+        let day_len = text[day_start..].bytes().take_while(u8::is_ascii_digit).count();
+        if day_len == 0 || day_len > 2 || (padded && day_len != 1) {
+            return Err(error(day_start, "Expected the day of the month"));
+        }
+        let day: i32 = text[day_start..day_start + day_len].parse().unwrap_or(0);
+        let time_start = day_start + day_len + 1;
+        if text.get(time_start - 1..time_start) != Some(" ") {
+            return Err(error(time_start - 1, "Expected a space"));
+        }
+        if text.len() != time_start + 8 {
+            return Err(error(time_start, "Expected a time such as 22:14:15"));
+        }
+        let time = LocalTime::try_parse(&text[time_start..]).map_err(|e| error(time_start + e.error_index(), e.expected()))?;
+
+        // The latest year that does not put the timestamp more than a day ahead of the clock.
+        // Compared in epoch seconds, since a day after the clock may be past the supported range.
+        let now = self.clock.instant();
+        let latest = now.epoch_seconds() + 86_400;
+        let zone = self.clock.zone();
+        let year = ZonedDateTime::checked_resolve_instant(now.inner(), zone.clone()).map_or(time::Date::MAX.year(), |zdt| zdt.year()) + 1;
+        (year - 8..=year)
+            .rev()
+            .filter_map(|year| time::Date::from_calendar_date(year, month.into(), day as u8).ok())
+            .filter_map(|date| ZoneResolver::ShiftForward.resolve(LocalDateTime::of_date_time(LocalDate::from(date), time), zone.clone()).ok())
+            .find(|zdt| zdt.epoch_seconds() <= latest)
+            .map(|zdt| zdt.to_offset_date_time())
+            .ok_or(error(0, "Invalid date"))
+    }
+}
+
+/// Parses a count of seconds since the epoch with `decimals` digits of fraction, either
+/// written with a decimal point or, when there is none, as one integer in that unit.
+fn parse_epoch(text: &str, decimals: u32) -> Result<Instant, DateTimeParseError> {
+    let (whole, fraction) = match text.split_once('.') {
+        Some((seconds, fraction)) => (seconds, Some(fraction)),
+        None => (text, None),
+    };
+    let digits = |part: &str| !part.is_empty() && part.len() <= 18 && part.bytes().all(|b| b.is_ascii_digit());
+    if !digits(whole) {
+        return Err(DateTimeParseError::new(text, 0, "Expected a number of seconds since the epoch"));
+    }
+    let value: i64 = whole.parse().unwrap_or(0);
+    let (seconds, nanos) = match fraction {
+        Some(fraction) if !digits(fraction) || fraction.len() > 9 => {
+            return Err(DateTimeParseError::new(text, whole.len() + 1, "Expected the fraction of the second"));
+        }
+        Some(fraction) => (value, format!("{fraction:0<9}").parse().unwrap_or(0)),
+        None => {
+            let unit = 10_i64.pow(decimals);
+            (value / unit, (value % unit) * 10_i64.pow(9 - decimals))
+        }
+    };
+    time::OffsetDateTime::UNIX_EPOCH
+        .checked_add(time::Duration::new(seconds, nanos as i32))
+        .filter(|date_time| date_time.year() <= 9999)
+        .map(Instant::from)
+        .ok_or(DateTimeParseError::new(text, 0, "Instant exceeds the supported range"))
+}
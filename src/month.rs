@@ -1,3 +1,5 @@
+use crate::{Locale, TextStyle};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -302,6 +304,53 @@ impl Month {
     /// * The returned month is converted back to one-based indexing (1 for January, 12 for December).
     ///
     pub fn minus(self, months: i64) -> Self { self.plus(-(months)) }
+
+    /// Returns the localized name of the month, like `java.time.Month.getDisplayName`.
+    ///
+    /// The standalone styles give the form used on its own, as in a calendar header, which
+    /// differs from the form inside a date in some languages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use joda_rs::{Locale, Month, TextStyle};
+    ///
+    /// assert_eq!(Month::March.get_display_name(TextStyle::Full, Locale::US), "March");
+    /// assert_eq!(Month::March.get_display_name(TextStyle::Short, Locale::FRANCE), "mars");
+    /// assert_eq!(Month::March.get_display_name(TextStyle::Short, Locale::GERMANY), "März");
+    /// assert_eq!(Month::March.get_display_name(TextStyle::ShortStandalone, Locale::GERMANY), "Mär");
+    /// assert_eq!(Month::March.get_display_name(TextStyle::Narrow, Locale::JAPAN), "3");
+    /// ```
+    pub fn get_display_name(self, style: TextStyle, locale: Locale) -> String {
+        locale.names().month(self, style).to_string()
+    }
+
+    /// Obtains a `Month` from its full or short name in a locale, such as `"Sep"` or
+    /// `"septembre"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text is not a month name, see [`Month::try_parse_display_name`].
+    pub fn parse_display_name(text: &str, locale: Locale) -> Self {
+        Self::try_parse_display_name(text, locale).expect("invalid month name")
+    }
+
+    /// Obtains a `Month` from its full or short name in a locale, in either the normal or
+    /// the standalone form. Case, surrounding whitespace and a trailing period are ignored,
+    /// so `"janv"`, `"Janv."` and `"JANVIER"` are all January in French.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use joda_rs::{Locale, Month};
+    ///
+    /// assert_eq!(Month::try_parse_display_name("septembre", Locale::FRENCH), Ok(Month::September));
+    /// assert_eq!(Month::try_parse_display_name("mrt", Locale::DUTCH), Ok(Month::March));
+    /// assert!(Month::try_parse_display_name("Sept.", Locale::ENGLISH).is_err());
+    /// ```
+    pub fn try_parse_display_name(text: &str, locale: Locale) -> Result<Self, &'static str> {
+        locale.names().find_month(text).ok_or("invalid month name")
+    }
 }
 
 impl core::fmt::Display for Month {
@@ -1,4 +1,4 @@
-use crate::{LocalDate, Month, Year};
+use crate::{DateTimeFormatter, DateTimeParseError, LocalDate, Month, Year};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -30,6 +30,28 @@ impl MonthDay {
         MonthDay { month, day }
     }
 
+    /// Obtains a `MonthDay` from ISO-8601 text such as `--12-25`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`MonthDay::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid MonthDay string")
+    }
+
+    /// Obtains a `MonthDay` like [`MonthDay::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(MonthDay::try_parse("--12-25"), Ok(MonthDay::of(12, 25)));
+    /// assert!(MonthDay::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::of_pattern("--MM-dd").parse(text)
+    }
+
     // Queries
     pub fn month(self) -> Month { self.month }
     pub fn month_value(self) -> i32 { self.month.value() }
@@ -56,3 +78,26 @@ impl MonthDay {
         LocalDate::from(d)
     }
 }
+
+/// Formats as ISO-8601 `--MM-dd`, such as `--12-25`, like `java.time.MonthDay.toString`.
+impl core::fmt::Display for MonthDay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "--{:02}-{:02}", self.month_value(), self.day)
+    }
+}
+
+impl std::str::FromStr for MonthDay {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for MonthDay {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
+}
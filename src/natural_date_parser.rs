@@ -0,0 +1,637 @@
+use crate::{Clock, DateTimeParseError, DayOfWeek, LocalDate, LocalDateTime, LocalTime, Locale, Month, ZonedDateTime};
+
+/// A parser for free-text English date expressions such as "tomorrow", "next Friday at 17:00",
+/// "in 3 weeks", "last day of next month" or "2nd Tuesday of March", resolved relative to the
+/// current time of a [`Clock`].
+///
+/// Expressions are a date, optionally followed by a time ("friday 5pm", "march 14 at noon"),
+/// a time optionally followed by a date ("5:30pm tomorrow"), an amount from now ("in 2 hours",
+/// "3 days ago", "a week from now") or "now". Case, commas and periods are ignored.
+///
+/// Dates:
+/// - "today", "tomorrow", "yesterday", "the day after tomorrow", "the day before yesterday"
+/// - "friday" or "this friday" (today if it is a Friday), "next friday" (the first one after
+///   today, as [`LocalDate::next`]), "last friday"
+/// - "next week", "last month", "next year": one week, month or year from today
+/// - "start of the week", "end of next month", "beginning of 2026"; weeks start on Monday
+/// - "first day of next month", "last day of the year", "15th day of march"
+/// - "2nd tuesday of march", "last friday of the month", "first monday of next month"
+/// - "march 14", "14th of march 2026", "2026-03-14"
+///
+/// Times are "17:00", "17:00:30", "5pm", "5:30 p.m.", "noon", "midnight", or an hour after
+/// "at" such as "at 17".
+///
+/// A month without a year, such as "march" or "march 14", is the next one from today: in the
+/// current year unless it has already passed.
+///
+/// ```rust
+/// let clock = Clock::fixed_at_local(LocalDateTime::of(2025, 1, 9, 12, 0, 0), ZoneId::UTC); // a Thursday
+/// let parser = NaturalDateParser::new(clock);
+///
+/// assert_eq!(parser.parse_date("tomorrow"), LocalDate::of(2025, 1, 10));
+/// assert_eq!(parser.parse_date_time("next friday at 17:00"), LocalDateTime::of(2025, 1, 10, 17, 0, 0));
+/// assert_eq!(parser.parse_date("in 3 weeks"), LocalDate::of(2025, 1, 30));
+/// assert_eq!(parser.parse_date("last day of next month"), LocalDate::of(2025, 2, 28));
+/// assert_eq!(parser.parse_date("2nd Tuesday of March"), LocalDate::of(2025, 3, 11));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NaturalDateParser {
+    clock: Clock,
+}
+
+/// What an expression resolved to. Amounts of hours, minutes and seconds are moved on the
+/// time-line and keep their zoned date-time.
+struct Resolved {
+    date: LocalDate,
+    time: Option<LocalTime>,
+    zoned: Option<ZonedDateTime>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Span {
+    Week,
+    Month,
+    Year,
+}
+
+struct Token {
+    text: String,
+    start: usize,
+}
+
+impl NaturalDateParser {
+    /// Creates a parser that resolves expressions relative to the current time of `clock`,
+    /// in the clock's zone.
+    pub fn new(clock: Clock) -> Self {
+        NaturalDateParser { clock }
+    }
+
+    /// Returns the clock expressions are resolved against.
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Parses an expression into a date, dropping any time of day.
+    ///
+    /// # Panics
+    /// Panics if the expression cannot be parsed, see [`NaturalDateParser::try_parse_date`].
+    pub fn parse_date(&self, text: &str) -> LocalDate {
+        self.try_parse_date(text).expect("invalid date expression")
+    }
+
+    /// Parses an expression into a date, dropping any time of day.
+    ///
+    /// # Errors
+    /// Returns an error pointing at the first word that does not fit an expression, or at
+    /// the start for dates that do not exist, such as "february 30".
+    pub fn try_parse_date(&self, text: &str) -> Result<LocalDate, DateTimeParseError> {
+        Ok(self.resolve(text)?.date)
+    }
+
+    /// Parses an expression into a local date-time. Expressions without a time are at the
+    /// start of the day, except amounts such as "in 3 days" which keep the current time.
+    ///
+    /// # Panics
+    /// Panics if the expression cannot be parsed.
+    pub fn parse_date_time(&self, text: &str) -> LocalDateTime {
+        self.try_parse_date_time(text).expect("invalid date expression")
+    }
+
+    /// Parses an expression into a local date-time.
+    ///
+    /// # Errors
+    /// Returns an error if the expression cannot be parsed.
+    pub fn try_parse_date_time(&self, text: &str) -> Result<LocalDateTime, DateTimeParseError> {
+        Ok(self.resolve(text)?.local_date_time())
+    }
+
+    /// Parses an expression into a date-time in the zone of the clock, like
+    /// [`NaturalDateParser::parse_date_time`]. "in 2 hours" is two elapsed hours from now,
+    /// even across a daylight saving change.
+    ///
+    /// # Panics
+    /// Panics if the expression cannot be parsed.
+    pub fn parse_zoned_date_time(&self, text: &str) -> ZonedDateTime {
+        self.try_parse_zoned_date_time(text).expect("invalid date expression")
+    }
+
+    /// Parses an expression into a date-time in the zone of the clock.
+    ///
+    /// # Errors
+    /// Returns an error if the expression cannot be parsed.
+    pub fn try_parse_zoned_date_time(&self, text: &str) -> Result<ZonedDateTime, DateTimeParseError> {
+        let resolved = self.resolve(text)?;
+        Ok(match resolved.zoned {
+            Some(zoned) => zoned,
+            None => ZonedDateTime::of(resolved.local_date_time(), self.clock.zone()),
+        })
+    }
+
+    fn resolve(&self, text: &str) -> Result<Resolved, DateTimeParseError> {
+        let now = ZonedDateTime::now_with_clock(&self.clock);
+        Parser { input: text, tokens: tokenize(text), pos: 0, today: now.to_local_date(), now }.parse()
+    }
+}
+
+/// Splits the text into lowercase words at whitespace and commas, without periods.
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, c.is_whitespace() || c == ',') {
+            (None, false) => start = Some(index),
+            (Some(from), true) => {
+                let word: String = text[from..index].chars().filter(|&c| c != '.').flat_map(char::to_lowercase).collect();
+                if !word.is_empty() {
+                    tokens.push(Token { text: word, start: from });
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    today: LocalDate,
+    now: ZonedDateTime,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<Resolved, DateTimeParseError> {
+        if self.tokens.is_empty() {
+            return Err(self.error("Expected a date expression"));
+        }
+        let resolved = if self.eat("now") {
+            Resolved { date: self.today, time: Some(self.now.to_local_time()), zoned: Some(self.now.clone()) }
+        } else if let Some(resolved) = self.amount()? {
+            resolved
+        } else if let Some(time) = self.time(false)? {
+            let date = if self.peek().is_some() { self.date()? } else { self.today };
+            Resolved { date, time: Some(time), zoned: None }
+        } else {
+            let date = self.date()?;
+            let time = self.trailing_time()?;
+            Resolved { date, time, zoned: None }
+        };
+        match self.peek() {
+            Some(_) => Err(self.error("Unexpected text")),
+            None => Ok(resolved),
+        }
+    }
+
+    /// Parses "in 3 days", "3 days ago", "3 days from now" or "3 days later", with a time
+    /// after amounts of days or more.
+    fn amount(&mut self) -> Result<Option<Resolved>, DateTimeParseError> {
+        let future = self.peek() == Some("in");
+        let at = self.pos + usize::from(future);
+        let (Some(amount), Some((unit, factor))) = (self.token(at).and_then(number), self.token(at + 1).and_then(unit)) else {
+            return Ok(None);
+        };
+        self.pos = at + 2;
+        let sign = if future {
+            1
+        } else if self.eat("ago") {
+            -1
+        } else if self.eat("later") || (self.eat("from") && self.expect("now", "Expected 'now'")?) {
+            1
+        } else {
+            return Err(self.error("Expected 'ago' or 'from now'"));
+        };
+        let amount = amount * factor * sign;
+        if let Some(seconds) = match unit {
+            Unit::Seconds => Some(amount),
+            Unit::Minutes => Some(amount * 60),
+            Unit::Hours => Some(amount * 3600),
+            _ => None,
+        } {
+            plus(self.today, seconds / 86_400 + sign, Unit::Days).map_err(|expected| self.error_at(0, expected))?;
+            let zoned = self.now.plus_seconds(seconds);
+            return Ok(Some(Resolved { date: zoned.to_local_date(), time: Some(zoned.to_local_time()), zoned: Some(zoned) }));
+        }
+        let date = plus(self.today, amount, unit).map_err(|expected| self.error_at(0, expected))?;
+        let time = self.trailing_time()?.unwrap_or(self.now.to_local_time());
+        Ok(Some(Resolved { date, time: Some(time), zoned: None }))
+    }
+
+    fn date(&mut self) -> Result<LocalDate, DateTimeParseError> {
+        self.eat("the");
+        let start = self.pos;
+        let Some(word) = self.next() else {
+            return Err(self.error("Expected a date"));
+        };
+        match word.as_str() {
+            "today" => return Ok(self.today),
+            "tomorrow" => return self.today_plus_days(1),
+            "yesterday" => return self.today_plus_days(-1),
+            "day" if self.eat("after") => {
+                self.expect("tomorrow", "Expected 'tomorrow'")?;
+                return self.today_plus_days(2);
+            }
+            "day" if self.eat("before") => {
+                self.expect("yesterday", "Expected 'yesterday'")?;
+                return self.today_plus_days(-2);
+            }
+            "start" | "beginning" | "end" => {
+                self.expect("of", "Expected 'of'")?;
+                let (anchor, span) = self.span()?;
+                let date = if word == "end" { end_of(anchor, span) } else { start_of(anchor, span) };
+                return date.map_err(|expected| self.error_at(0, expected));
+            }
+            "this" | "next" | "last" if self.peek().and_then(span_word).is_some() => {
+                let span = self.next().and_then(|word| span_word(&word)).unwrap_or(Span::Week);
+                let amount = match word.as_str() {
+                    "this" => 0,
+                    "next" => 1,
+                    _ => -1,
+                };
+                return plus(self.today, amount, span.unit()).map_err(|expected| self.error_at(0, expected));
+            }
+            "this" | "next" => {
+                let dow = self.day_of_week()?;
+                let ahead = days_until(self.today.day_of_week(), dow);
+                let ahead = if word == "next" && ahead == 0 { 7 } else { ahead };
+                return self.today_plus_days(ahead);
+            }
+            "last" if self.peek().and_then(day_of_week).is_some() && self.token(self.pos + 1) != Some("of") => {
+                let dow = self.day_of_week()?;
+                let back = match days_until(dow, self.today.day_of_week()) {
+                    0 => 7,
+                    back => back,
+                };
+                return self.today_plus_days(-back);
+            }
+            _ => {}
+        }
+        if let Some(dow) = day_of_week(&word) {
+            let ahead = days_until(self.today.day_of_week(), dow);
+            return self.today_plus_days(ahead);
+        }
+        if let Some(n) = ordinal(&word) {
+            if self.eat("day") {
+                self.expect("of", "Expected 'of'")?;
+                return self.nth_day(n, start);
+            }
+            if let Some(dow) = self.peek().and_then(day_of_week) {
+                self.pos += 1;
+                self.expect("of", "Expected 'of'")?;
+                return self.nth_day_of_week(n, dow, start);
+            }
+        }
+        if let Some(day) = day_number(&word) {
+            self.eat("of");
+            let month = self.month()?;
+            return self.month_day(month, day, start);
+        }
+        if let Some(month) = month(&word) {
+            let day = self.next().as_deref().and_then(day_number).ok_or_else(|| self.error_at(self.pos - 1, "Expected a day of the month"))?;
+            return self.month_day(month, day, start);
+        }
+        LocalDate::try_parse(&self.tokens[start].text).map_err(|_| self.error_at(start, "Expected a date"))
+    }
+
+    /// Returns the date some days from today, with an error instead of a panic when it is
+    /// out of range.
+    fn today_plus_days(&self, days: i64) -> Result<LocalDate, DateTimeParseError> {
+        plus(self.today, days, Unit::Days).map_err(|expected| self.error_at(0, expected))
+    }
+
+    /// Parses the week, month or year after "of", such as "next month", "the year",
+    /// "march" or "2026", returning a date in it.
+    fn span(&mut self) -> Result<(LocalDate, Span), DateTimeParseError> {
+        self.eat("the");
+        let amount = if self.eat("this") {
+            Some(0)
+        } else if self.eat("next") {
+            Some(1)
+        } else if self.eat("last") {
+            Some(-1)
+        } else {
+            None
+        };
+        if let Some(span) = self.peek().and_then(span_word) {
+            self.pos += 1;
+            let date = plus(self.today, amount.unwrap_or(0), span.unit()).map_err(|expected| self.error_at(0, expected))?;
+            return Ok((date, span));
+        }
+        if amount.is_none() {
+            if let Some(year) = self.peek().and_then(year) {
+                self.pos += 1;
+                return Ok((LocalDate::of(year, 1, 1), Span::Year));
+            }
+            if self.peek().and_then(month).is_some() {
+                let month = self.month()?;
+                let year = match self.year() {
+                    Some(year) => year,
+                    None if month.value() < self.today.month_value() => self.today.year() + 1,
+                    None => self.today.year(),
+                };
+                let date = of_date(year, month, 1).ok_or_else(|| self.error_at(0, "Invalid date: out of range"))?;
+                return Ok((date, Span::Month));
+            }
+        }
+        Err(self.error("Expected a week, month or year"))
+    }
+
+    /// Resolves "first day of", "last day of" or "15th day of" a span.
+    fn nth_day(&mut self, n: i32, start: usize) -> Result<LocalDate, DateTimeParseError> {
+        let (anchor, span) = self.span()?;
+        let (first, last) = (start_of(anchor, span), end_of(anchor, span));
+        let date = match n {
+            -1 => last.ok(),
+            _ => first.and_then(|first| plus(first, i64::from(n) - 1, Unit::Days)).ok().filter(|date| last.is_ok_and(|last| *date <= last)),
+        };
+        date.ok_or_else(|| self.error_at(start, "Invalid date: there is no such day"))
+    }
+
+    /// Resolves "2nd tuesday of march" or "last friday of the month".
+    fn nth_day_of_week(&mut self, n: i32, dow: DayOfWeek, start: usize) -> Result<LocalDate, DateTimeParseError> {
+        let (anchor, span) = self.span()?;
+        if span != Span::Month {
+            return Err(self.error_at(start, "Expected a month"));
+        }
+        let date = match n {
+            -1 => Some(anchor.last_in_month(dow)),
+            _ => plus(anchor.first_in_month(dow), i64::from(n) - 1, Unit::Weeks).ok().filter(|date| date.month() == anchor.month()),
+        };
+        date.ok_or_else(|| self.error_at(start, "Invalid date: the month has no such day"))
+    }
+
+    /// Resolves a day of a month with an optional year, the next one from today by default.
+    fn month_day(&mut self, month: Month, day: i32, start: usize) -> Result<LocalDate, DateTimeParseError> {
+        if let Some(year) = self.year() {
+            return of_date(year, month, day).ok_or_else(|| self.error_at(start, "Invalid date"));
+        }
+        // February 29 can be up to eight years away.
+        (self.today.year()..=self.today.year() + 8)
+            .filter_map(|year| of_date(year, month, day))
+            .find(|date| *date >= self.today)
+            .ok_or_else(|| self.error_at(start, "Invalid date"))
+    }
+
+    /// Parses a time after an optional "at".
+    fn trailing_time(&mut self) -> Result<Option<LocalTime>, DateTimeParseError> {
+        let at = self.eat("at") || self.eat("@");
+        match self.time(at)? {
+            None if at => Err(self.error("Expected a time")),
+            time => Ok(time),
+        }
+    }
+
+    /// Parses "17:00", "5pm", "5:30 pm", "noon" or "midnight", and a bare hour if `bare_hour`.
+    fn time(&mut self, bare_hour: bool) -> Result<Option<LocalTime>, DateTimeParseError> {
+        let start = self.pos;
+        let Some(word) = self.peek() else {
+            return Ok(None);
+        };
+        let fixed = match word {
+            "noon" | "midday" => Some(LocalTime::of(12, 0, 0)),
+            "midnight" => Some(LocalTime::of(0, 0, 0)),
+            _ => None,
+        };
+        if fixed.is_some() {
+            self.pos += 1;
+            return Ok(fixed);
+        }
+        let (digits, mut meridiem) = match word.strip_suffix("am").or_else(|| word.strip_suffix("pm")) {
+            Some(digits) => (digits.to_string(), Some(word.ends_with("pm"))),
+            None => (word.to_string(), None),
+        };
+        let parts: Vec<&str> = digits.split(':').collect();
+        let valid = parts.len() <= 3
+            && parts.iter().all(|part| !part.is_empty() && part.len() <= 2 && part.bytes().all(|b| b.is_ascii_digit()))
+            && parts[1..].iter().all(|part| part.len() == 2);
+        if !valid {
+            return Ok(None);
+        }
+        if meridiem.is_none() {
+            match self.token(start + 1) {
+                Some("am") => meridiem = Some(false),
+                Some("pm") => meridiem = Some(true),
+                _ if parts.len() == 1 && !bare_hour => return Ok(None),
+                _ => {}
+            }
+            if meridiem.is_some() {
+                self.pos += 1;
+            }
+        }
+        self.pos += 1;
+        let values: Vec<i32> = parts.iter().map(|part| part.parse().unwrap_or_default()).collect();
+        let hour = match meridiem {
+            Some(_) if !(1..=12).contains(&values[0]) => None,
+            Some(pm) => Some(values[0] % 12 + if pm { 12 } else { 0 }),
+            None => Some(values[0]).filter(|hour| *hour < 24),
+        };
+        let minute = values.get(1).copied().unwrap_or(0);
+        let second = values.get(2).copied().unwrap_or(0);
+        match hour {
+            Some(hour) if minute < 60 && second < 60 => Ok(Some(LocalTime::of(hour, minute, second))),
+            _ => Err(self.error_at(start, "Invalid time")),
+        }
+    }
+
+    fn day_of_week(&mut self) -> Result<DayOfWeek, DateTimeParseError> {
+        let dow = self.peek().and_then(day_of_week).ok_or_else(|| self.error("Expected a day of the week"))?;
+        self.pos += 1;
+        Ok(dow)
+    }
+
+    fn month(&mut self) -> Result<Month, DateTimeParseError> {
+        let month = self.peek().and_then(month).ok_or_else(|| self.error("Expected a month"))?;
+        self.pos += 1;
+        Ok(month)
+    }
+
+    fn year(&mut self) -> Option<i32> {
+        let year = self.peek().and_then(year)?;
+        self.pos += 1;
+        Some(year)
+    }
+
+    fn token(&self, index: usize) -> Option<&str> {
+        self.tokens.get(index).map(|token| token.text.as_str())
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.token(self.pos)
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let word = self.peek().map(str::to_string);
+        self.pos += usize::from(word.is_some());
+        word
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        let matches = self.peek() == Some(word);
+        self.pos += usize::from(matches);
+        matches
+    }
+
+    fn expect(&mut self, word: &str, expected: &'static str) -> Result<bool, DateTimeParseError> {
+        if self.eat(word) { Ok(true) } else { Err(self.error(expected)) }
+    }
+
+    /// An error at the current word, or at the end of the text.
+    fn error(&self, expected: &'static str) -> DateTimeParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    fn error_at(&self, token: usize, expected: &'static str) -> DateTimeParseError {
+        let index = self.tokens.get(token).map_or(self.input.len(), |token| token.start);
+        DateTimeParseError::new(self.input, index, expected)
+    }
+}
+
+impl Resolved {
+    fn local_date_time(&self) -> LocalDateTime {
+        self.time.map_or(self.date.at_start_of_day(), |time| self.date.at_time(time))
+    }
+}
+
+impl Span {
+    fn unit(self) -> Unit {
+        match self {
+            Span::Week => Unit::Weeks,
+            Span::Month => Unit::Months,
+            Span::Year => Unit::Years,
+        }
+    }
+}
+
+/// Adds an amount of days or more to a date, with an error instead of a panic when the
+/// result is out of range.
+fn plus(date: LocalDate, amount: i64, unit: Unit) -> Result<LocalDate, &'static str> {
+    const OUT_OF_RANGE: &str = "Invalid date: out of range";
+    match unit {
+        Unit::Months | Unit::Years => {
+            let months = amount.checked_mul(if unit == Unit::Years { 12 } else { 1 }).ok_or(OUT_OF_RANGE)?;
+            let total = i64::from(date.year()) * 12 + i64::from(date.month_value() - 1);
+            let year = total.checked_add(months).ok_or(OUT_OF_RANGE)?.div_euclid(12);
+            if !(-9999..=9999).contains(&year) {
+                return Err(OUT_OF_RANGE);
+            }
+            Ok(date.plus_months(months as i32))
+        }
+        _ => {
+            let days = amount.checked_mul(if unit == Unit::Weeks { 7 } else { 1 }).ok_or(OUT_OF_RANGE)?;
+            let days = days.checked_mul(86_400).map(time::Duration::seconds).ok_or(OUT_OF_RANGE)?;
+            date.inner().checked_add(days).map(LocalDate::from).ok_or(OUT_OF_RANGE)
+        }
+    }
+}
+
+/// Returns the number of days from one day of the week forward to another, 0 to 6.
+fn days_until(from: DayOfWeek, to: DayOfWeek) -> i64 {
+    i64::from(to.value() - from.value()).rem_euclid(7)
+}
+
+fn start_of(date: LocalDate, span: Span) -> Result<LocalDate, &'static str> {
+    match span {
+        Span::Week => plus(date, -days_until(DayOfWeek::Monday, date.day_of_week()), Unit::Days),
+        Span::Month => Ok(date.first_day_of_month()),
+        Span::Year => Ok(date.first_day_of_year()),
+    }
+}
+
+fn end_of(date: LocalDate, span: Span) -> Result<LocalDate, &'static str> {
+    match span {
+        Span::Week => plus(date, days_until(date.day_of_week(), DayOfWeek::Sunday), Unit::Days),
+        Span::Month => Ok(date.last_day_of_month()),
+        Span::Year => Ok(date.last_day_of_year()),
+    }
+}
+
+fn of_date(year: i32, month: Month, day: i32) -> Option<LocalDate> {
+    let month = time::Month::try_from(month.value() as u8).ok()?;
+    time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok().map(LocalDate::from)
+}
+
+fn number(word: &str) -> Option<i64> {
+    const WORDS: [&str; 13] =
+        ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve"];
+    match word {
+        "a" | "an" => Some(1),
+        _ if !word.is_empty() && word.len() <= 9 && word.bytes().all(|b| b.is_ascii_digit()) => word.parse().ok(),
+        _ => WORDS.iter().position(|&name| name == word).map(|value| value as i64),
+    }
+}
+
+/// Reads a unit and how many of it the word stands for, which is 2 weeks for a fortnight.
+fn unit(word: &str) -> Option<(Unit, i64)> {
+    let unit = match word {
+        "fortnight" | "fortnights" => return Some((Unit::Weeks, 2)),
+        "second" | "seconds" | "sec" | "secs" => Unit::Seconds,
+        "minute" | "minutes" | "min" | "mins" => Unit::Minutes,
+        "hour" | "hours" | "hr" | "hrs" => Unit::Hours,
+        "day" | "days" => Unit::Days,
+        "week" | "weeks" => Unit::Weeks,
+        "month" | "months" => Unit::Months,
+        "year" | "years" => Unit::Years,
+        _ => return None,
+    };
+    Some((unit, 1))
+}
+
+fn span_word(word: &str) -> Option<Span> {
+    match word {
+        "week" => Some(Span::Week),
+        "month" => Some(Span::Month),
+        "year" => Some(Span::Year),
+        _ => None,
+    }
+}
+
+/// Reads "first" to "fifth", "1st" to "366th" and "last" (-1).
+fn ordinal(word: &str) -> Option<i32> {
+    match word {
+        "first" => Some(1),
+        "second" => Some(2),
+        "third" => Some(3),
+        "fourth" => Some(4),
+        "fifth" => Some(5),
+        "last" => Some(-1),
+        _ => {
+            let digits = word.strip_suffix("st").or(word.strip_suffix("nd")).or(word.strip_suffix("rd")).or(word.strip_suffix("th"))?;
+            if digits.is_empty() || digits.len() > 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok().filter(|n| (1..=366).contains(n))
+        }
+    }
+}
+
+/// Reads a day of the month such as "14" or "14th".
+fn day_number(word: &str) -> Option<i32> {
+    if word.len() <= 2 && word.bytes().all(|b| b.is_ascii_digit()) {
+        return word.parse().ok().filter(|day| (1..=31).contains(day));
+    }
+    ordinal(word).filter(|&day| (1..=31).contains(&day) && word.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+fn year(word: &str) -> Option<i32> {
+    if word.len() == 4 && word.bytes().all(|b| b.is_ascii_digit()) { word.parse().ok() } else { None }
+}
+
+fn day_of_week(word: &str) -> Option<DayOfWeek> {
+    DayOfWeek::try_parse_display_name(word, Locale::US).ok()
+}
+
+fn month(word: &str) -> Option<Month> {
+    Month::try_parse_display_name(word, Locale::US).ok()
+}
@@ -0,0 +1,63 @@
+use crate::{LocalDateTime, ZoneId, ZoneOffset, ZoneResolver, ZonedDateTime};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Policy for text such as `2024-03-10T03:30-05:00[America/New_York]`, where the offset
+/// and the zone disagree about the local date-time.
+///
+/// This happens when the rules of the zone changed after the text was written, or when the
+/// text was put together by hand. An offset agrees with the zone when it is one of the
+/// zone's valid offsets for the local date-time; in an overlap both offsets agree and the
+/// offset picks between them, whatever the policy.
+///
+/// ```rust
+/// let new_york = ZoneId::try_of("America/New_York").unwrap();
+/// let ldt = LocalDateTime::of(2024, 3, 10, 3, 30, 0);
+///
+/// assert!(OffsetConflict::Reject.resolve(ldt, ZoneOffset::of_hours(-5), new_york.clone()).is_err());
+///
+/// let by_offset = OffsetConflict::PreferOffset.resolve(ldt, ZoneOffset::of_hours(-5), new_york.clone()).unwrap();
+/// assert_eq!(by_offset.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 4, 30, 0));
+///
+/// let by_zone = OffsetConflict::PreferZone.resolve(ldt, ZoneOffset::of_hours(-5), new_york).unwrap();
+/// assert_eq!(by_zone.to_local_date_time(), ldt);
+/// assert_eq!(by_zone.offset(), ZoneOffset::of_hours(-4));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OffsetConflict {
+    /// Returns an error when the offset does not agree with the zone.
+    #[default]
+    Reject,
+    /// Keeps the instant given by the local date-time and offset, and moves the local
+    /// date-time to what it is in the zone at that instant. This is what
+    /// `java.time.ZonedDateTime.parse` does.
+    PreferOffset,
+    /// Keeps the local date-time and resolves it in the zone as `ZonedDateTime::of` does,
+    /// ignoring the offset.
+    PreferZone,
+}
+
+impl OffsetConflict {
+    /// Combines a local date-time, an offset and a zone into a `ZonedDateTime` using this
+    /// policy.
+    ///
+    /// # Errors
+    /// `OffsetConflict::Reject` returns `"Offset does not match the time zone"` when the
+    /// offset is not valid for the local date-time in the zone.
+    ///
+    /// Every policy returns an error near the ends of the supported range:
+    /// `"Instant exceeds the supported range"` when the instant, or the local date-time in the
+    /// zone at that instant, is outside it, and `"Local date-time exceeds the supported range"`
+    /// when `OffsetConflict::PreferZone` shifts out of a gap past the end.
+    pub fn resolve(self, ldt: LocalDateTime, offset: ZoneOffset, zone: ZoneId) -> Result<ZonedDateTime, &'static str> {
+        if self == OffsetConflict::PreferOffset || zone.rules().is_valid_offset(ldt, offset) {
+            return ZonedDateTime::checked_resolve_instant(ldt.at_offset(offset).inner(), zone).ok_or("Instant exceeds the supported range");
+        }
+        match self {
+            OffsetConflict::PreferZone => ZoneResolver::ShiftForward.resolve(ldt, zone),
+            _ => Err("Offset does not match the time zone"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Clock, DayOfWeek, Duration, Instant, LocalDate, LocalDateTime, LocalTime, Month, TemporalInstant, Year, ZoneOffset};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, DayOfWeek, Duration, Instant, LocalDate, LocalDateTime, LocalTime, Month, TemporalInstant, Year, ZoneId, ZoneOffset, ZonedDateTime};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -13,6 +13,18 @@ pub struct OffsetDateTime(
 );
 
 impl OffsetDateTime {
+    /// Returns the current date-time at the offset of the system default zone, see
+    /// [`ZoneId::system_default`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// let current_time = OffsetDateTime::now();
+    /// println!("Current time: {:?}", current_time);
+    /// ```
+    pub fn now() -> Self {
+        ZonedDateTime::now().to_offset_date_time()
+    }
+
     /// Returns the current UTC datetime wrapped in a custom `OffsetDateTime` type.
     ///
     /// # Examples
@@ -131,8 +143,44 @@ impl OffsetDateTime {
         Self(pdt.assume_offset(off))
     }
 
+    /// Obtains a `OffsetDateTime` from ISO-8601 text such as `2024-03-05T14:07:09+01:00`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`OffsetDateTime::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid OffsetDateTime string")
+    }
+
+    /// Obtains a `OffsetDateTime` like [`OffsetDateTime::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(OffsetDateTime::try_parse("2024-03-05T14:07:09+01:00"), Ok(OffsetDateTime::of(LocalDateTime::of(2024, 3, 5, 14, 7, 9), ZoneOffset::of_hours(1))));
+    /// assert!(OffsetDateTime::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::iso_offset_date_time().parse(text)
+    }
+
+    /// Converts this date-time to an instant.
+    ///
+    /// ### Panics
+    /// Panics if the instant is outside the supported range, which only happens within a day
+    /// of either end, see [`OffsetDateTime::try_to_instant`].
     pub fn to_instant(self) -> Instant {
-        Instant::of_epoch_second(self.0.unix_timestamp())
+        self.try_to_instant().expect("Instant exceeds the supported range")
+    }
+
+    /// Converts this date-time to an instant.
+    ///
+    /// ### Errors
+    /// Returns an error if the instant is outside the supported range, such as the instant of
+    /// `9999-12-31T23:00-05:00`, which is in the year 10000 in UTC.
+    pub fn try_to_instant(self) -> Result<Instant, &'static str> {
+        self.0.checked_to_offset(time::UtcOffset::UTC).map(Instant::from).ok_or("Instant exceeds the supported range")
     }
 
     /// Returns the offset in whole seconds from the UTC for the current instance.
@@ -180,6 +228,61 @@ impl OffsetDateTime {
         OffsetDateTime::from(self.0.to_offset(offset.inner()))
     }
 
+    /// Returns a new `OffsetDateTime` with the same local date-time but the specified offset,
+    /// which changes the instant.
+    ///
+    /// # Example
+    /// ```
+    /// let odt = OffsetDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneOffset::UTC);
+    /// let shifted = odt.with_offset_same_local(ZoneOffset::of_hours(2));
+    /// assert_eq!(shifted.to_local_date_time(), odt.to_local_date_time());
+    /// assert_eq!(odt.epoch_seconds() - shifted.epoch_seconds(), 7200);
+    /// ```
+    pub fn with_offset_same_local(self, offset: ZoneOffset) -> OffsetDateTime {
+        OffsetDateTime::from(self.0.replace_offset(offset.inner()))
+    }
+
+    /// Converts this date-time to a `ZonedDateTime` in the given zone at the same instant.
+    ///
+    /// # Example
+    /// ```
+    /// let tokyo = ZoneId::try_of("Asia/Tokyo").unwrap();
+    /// let odt = OffsetDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneOffset::of_hours(-4));
+    /// let zdt = odt.at_zone_same_instant(tokyo);
+    /// assert_eq!(zdt.to_local_date_time(), LocalDateTime::of(2024, 7, 1, 22, 0, 0));
+    /// ```
+    pub fn at_zone_same_instant(self, zone: ZoneId) -> ZonedDateTime {
+        ZonedDateTime::of_instant(self.to_instant(), zone)
+    }
+
+    /// Converts this date-time to a `ZonedDateTime` in the given zone with the same local
+    /// date-time, keeping this offset if the zone allows it.
+    ///
+    /// A local date-time in a gap is moved forward by the length of the gap; in an overlap
+    /// this offset is used if it is one of the two valid offsets, otherwise the earlier one.
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let odt = OffsetDateTime::of(LocalDateTime::of(2024, 11, 3, 1, 30, 0), ZoneOffset::of_hours(-5));
+    /// let zdt = odt.at_zone_similar_local(new_york);
+    /// assert_eq!(zdt.offset(), ZoneOffset::of_hours(-5));
+    /// ```
+    pub fn at_zone_similar_local(self, zone: ZoneId) -> ZonedDateTime {
+        ZonedDateTime::of_local(self.to_local_date_time(), zone, Some(ZoneOffset::from(self.0.offset())))
+    }
+
+    /// Converts this date-time to a `ZonedDateTime` whose zone is this offset.
+    ///
+    /// # Example
+    /// ```
+    /// let odt = OffsetDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneOffset::of_hours(2));
+    /// assert_eq!(odt.to_zoned_date_time().zone().id(), "+02:00");
+    /// ```
+    pub fn to_zoned_date_time(self) -> ZonedDateTime {
+        ZonedDateTime::from_parts(self.0, ZoneId::from(ZoneOffset::from(self.0.offset())))
+    }
+
     /// Determines whether the current instance is before another instance.
     ///
     /// ### Arguments
@@ -750,8 +853,26 @@ impl TemporalInstant for OffsetDateTime {
     }
 }
 
+/// Formats the date-time and offset as ISO-8601, such as `2024-01-01T12:00+01:00` or
+/// `2024-01-01T12:00Z`, like `java.time.OffsetDateTime.toString`.
 impl fmt::Display for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}{}", self.to_local_date_time(), ZoneOffset::from(self.0.offset()))
+    }
+}
+
+impl std::str::FromStr for OffsetDateTime {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for OffsetDateTime {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
@@ -1,3 +1,4 @@
+use crate::DateTimeParseError;
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -42,10 +43,125 @@ impl Period {
     pub const fn with_years(self, years: i32) -> Self { Self { years, ..self } }
     pub const fn with_months(self, months: i32) -> Self { Self { months, ..self } }
     pub const fn with_days(self, days: i32) -> Self { Self { days, ..self } }
+
+    /// Obtains a `Period` from ISO-8601 text such as `P1Y2M3W4D`, like `java.time.Period.parse`.
+    ///
+    /// # Panics
+    /// Panics if the text cannot be parsed, see [`Period::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid Period string")
+    }
+
+    /// Obtains a `Period` from ISO-8601 text `PnYnMnWnD`, like `java.time.Period.parse`.
+    ///
+    /// Letters may be upper or lower case and at least one amount is required. Weeks are
+    /// added to the days as 7 days each. Each amount, and the whole text, may have a sign.
+    ///
+    /// ```rust
+    /// assert_eq!(Period::try_parse("P1Y2M3W4D"), Ok(Period::of(1, 2, 25)));
+    /// assert_eq!(Period::try_parse("P-1Y2M"), Ok(Period::of(-1, 2, 0)));
+    /// assert_eq!(Period::try_parse("-P1Y2M"), Ok(Period::of(-1, -2, 0)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DateTimeParseError`] if the text is not an ISO-8601 period or an amount
+    /// does not fit in an `i32`.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        parse_iso(text).map_err(|(index, expected)| DateTimeParseError::new(text, index, expected))
+    }
+
+    /// Returns the ISO-8601 representation, such as `P1Y2M3D`; the same as `Display`.
+    pub fn to_iso_string(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Parses `[-+]P[nY][nM][nW][nD]`, or returns where it failed and what was expected there.
+fn parse_iso(text: &str) -> Result<Period, (usize, &'static str)> {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+    let negated = match bytes.first() {
+        Some(b'-') => {
+            index += 1;
+            true
+        }
+        Some(b'+') => {
+            index += 1;
+            false
+        }
+        _ => false,
+    };
+    if !bytes.get(index).is_some_and(|b| b.eq_ignore_ascii_case(&b'P')) {
+        return Err((index, "Expected P to start the period"));
+    }
+    index += 1;
+
+    let mut amounts = [0_i32; 4];
+    let mut found = false;
+    for (amount, unit) in amounts.iter_mut().zip([b'Y', b'M', b'W', b'D']) {
+        let start = index + usize::from(matches!(bytes.get(index), Some(b'-' | b'+')));
+        let digits = bytes[start.min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || !bytes.get(start + digits).is_some_and(|b| b.eq_ignore_ascii_case(&unit)) {
+            continue;
+        }
+        let value = text[index..start + digits].trim_start_matches('+').parse::<i32>().map_err(|_| (start, "Period is out of range"))?;
+        *amount = value;
+        index = start + digits + 1;
+        found = true;
+    }
+    if !found {
+        return Err((index, "Expected a year, month, week or day amount"));
+    }
+    if index != bytes.len() {
+        return Err((index, "Unparsed text found after the end of the period"));
+    }
+
+    let [years, months, weeks, days] = amounts;
+    let days = weeks.checked_mul(7).and_then(|weeks| weeks.checked_add(days));
+    let period = match days {
+        Some(days) if !negated => Some(Period::of(years, months, days)),
+        Some(days) => years
+            .checked_neg()
+            .zip(months.checked_neg())
+            .zip(days.checked_neg())
+            .map(|((years, months), days)| Period::of(years, months, days)),
+        None => None,
+    };
+    period.ok_or((0, "Period is out of range"))
+}
+
+impl std::str::FromStr for Period {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for Period {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
 }
 
+/// Formats as ISO-8601, such as `P1Y2M3D`, `P-1M` or `P0D`, like `java.time.Period.toString`.
 impl fmt::Display for Period {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}:{}", self.years, self.months, self.days)
+        if self.is_zero() {
+            return f.write_str("P0D");
+        }
+        f.write_str("P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,300 @@
+use crate::{ChronoUnit, Clock, DateTimeFields, DayOfWeek, Duration, LocalDate, LocalTime, Locale, Period, TemporalAccessor, TextStyle, ZoneId};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A formatter for the time between two points as English text that people read at a
+/// glance, such as "just now", "5 minutes ago", "in 2 weeks", "yesterday" or "last Tuesday",
+/// like the relative times of activity feeds.
+///
+/// The amount is shown in the largest unit that reaches its threshold, see
+/// [`RelativeTimeFormatter::with_threshold`], and rounded with a [`RoundingMode`]. Weeks,
+/// months and years are 7, 30 and 365 days, as in [`ChronoUnit::duration`].
+///
+/// ```rust
+/// let clock = Clock::fixed_at_local(LocalDateTime::of(2025, 1, 9, 12, 0, 0), ZoneId::UTC);
+/// let now = clock.instant();
+/// let formatter = RelativeTimeFormatter::new();
+///
+/// assert_eq!(formatter.format_now(&now.minus_seconds(10), &clock), "just now");
+/// assert_eq!(formatter.format_now(&now.minus_seconds(5 * 60), &clock), "5 minutes ago");
+/// assert_eq!(formatter.format_now(&now.plus_seconds(3 * 3600), &clock), "in 3 hours");
+/// assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 8), &clock), "yesterday");
+/// assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 7), &clock), "last Tuesday");
+/// assert_eq!(formatter.format_duration(Duration::of_days(14)), "in 2 weeks");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeTimeFormatter {
+    just_now: Duration,
+    thresholds: [i64; 6],
+    rounding: RoundingMode,
+    named_days: bool,
+}
+
+/// How a [`RelativeTimeFormatter`] rounds an amount to a whole number of units.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundingMode {
+    /// Rounds to the nearest unit, halves away from zero: 90 minutes is 2 hours.
+    #[default]
+    HalfUp,
+    /// Drops the part of a unit: 90 minutes is 1 hour.
+    Down,
+    /// Counts a started unit as a whole one: 61 minutes is 2 hours.
+    Up,
+}
+
+/// The units that have a threshold, in the order of [`RelativeTimeFormatter::thresholds`].
+const UNITS: [ChronoUnit; 7] =
+    [ChronoUnit::Seconds, ChronoUnit::Minutes, ChronoUnit::Hours, ChronoUnit::Days, ChronoUnit::Weeks, ChronoUnit::Months, ChronoUnit::Years];
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// What separates the value from the reference point.
+enum Elapsed {
+    /// Time, with the calendar days between the two dates when both are known.
+    Time { nanos: i128, dates: Option<(LocalDate, LocalDate)> },
+    /// Whole days between two dates.
+    Days { days: i64, date: LocalDate },
+}
+
+impl Default for RelativeTimeFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeTimeFormatter {
+    /// Creates a formatter with the default thresholds: "just now" under 45 seconds, then
+    /// minutes under 45, hours under 22, days under 7, weeks under 4, months under 11 and
+    /// years. Amounts are rounded half up and calendar days are named.
+    pub fn new() -> Self {
+        RelativeTimeFormatter {
+            just_now: Duration::of_seconds(45),
+            thresholds: [45, 45, 22, 7, 4, 11],
+            rounding: RoundingMode::HalfUp,
+            named_days: true,
+        }
+    }
+
+    /// Returns a copy of this formatter that shows "just now" for anything closer than
+    /// `just_now`. Use `Duration::of_seconds(0)` to always show an amount.
+    pub fn with_just_now(mut self, just_now: Duration) -> Self {
+        self.just_now = just_now.abs();
+        self
+    }
+
+    /// Returns a copy of this formatter that shows amounts of `unit` only while they are
+    /// below `limit`, moving on to the next larger unit from there.
+    ///
+    /// The units are seconds, minutes, hours, days, weeks and months, and the next larger
+    /// unit of each is minutes, hours, days, weeks, months and years. A limit of 0 skips a
+    /// unit entirely.
+    ///
+    /// ```rust
+    /// let formatter = RelativeTimeFormatter::new().with_threshold(ChronoUnit::Hours, 48).with_threshold(ChronoUnit::Days, 30);
+    /// assert_eq!(formatter.format_duration(Duration::of_hours(-36)), "36 hours ago");
+    /// assert_eq!(formatter.format_duration(Duration::of_days(21)), "in 21 days");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `unit` is not one of the units above.
+    pub fn with_threshold(mut self, unit: ChronoUnit, limit: i64) -> Self {
+        let index = UNITS[..6].iter().position(|&candidate| candidate == unit).expect("unsupported unit for a relative time threshold");
+        self.thresholds[index] = limit.max(0);
+        self
+    }
+
+    /// Returns a copy of this formatter that rounds amounts with `rounding`.
+    ///
+    /// ```rust
+    /// let ninety_minutes = Duration::of_minutes(-90);
+    /// assert_eq!(RelativeTimeFormatter::new().format_duration(ninety_minutes), "2 hours ago");
+    /// assert_eq!(RelativeTimeFormatter::new().with_rounding(RoundingMode::Down).format_duration(ninety_minutes), "1 hour ago");
+    /// ```
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns a copy of this formatter that, with `named_days`, says "yesterday",
+    /// "tomorrow", "last Tuesday" and "next Tuesday" for dates less than a week away, or
+    /// otherwise counts the days. It has no effect on durations and periods.
+    pub fn with_named_days(mut self, named_days: bool) -> Self {
+        self.named_days = named_days;
+        self
+    }
+
+    /// Formats `temporal` relative to the current time of `clock`, such as "3 hours ago".
+    ///
+    /// Values with an instant are compared with the clock's instant and their calendar
+    /// days are taken in the clock's zone. Local values are compared with the clock's local
+    /// date and time.
+    ///
+    /// # Panics
+    /// Panics if the value has neither a date nor a time of day, such as a `YearMonth`.
+    pub fn format_now<T: TemporalAccessor>(&self, temporal: &T, clock: &Clock) -> String {
+        self.try_format_now(temporal, clock).expect("unable to format the value relative to the clock")
+    }
+
+    /// Formats `temporal` relative to the current time of `clock`.
+    ///
+    /// # Errors
+    /// Returns an error if the value has neither a date nor a time of day.
+    pub fn try_format_now<T: TemporalAccessor>(&self, temporal: &T, clock: &Clock) -> Result<String, &'static str> {
+        let now = clock.instant().at_zone(clock.zone()).to_fields();
+        let mut fields = temporal.to_fields();
+        if let Some(instant) = fields.instant() {
+            fields = instant.at_zone(clock.zone()).to_fields();
+        }
+        Ok(self.format_elapsed(elapsed(&fields, &now)?))
+    }
+
+    /// Formats `temporal` relative to `reference`, such as "2 days ago" when `temporal` is
+    /// two days before `reference`.
+    ///
+    /// ```rust
+    /// let meeting = LocalDateTime::of(2025, 1, 9, 9, 0, 0);
+    /// let formatter = RelativeTimeFormatter::new();
+    /// assert_eq!(formatter.format_relative_to(&meeting, &meeting.minus_minutes(30)), "in 30 minutes");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the values have neither a date nor a time of day.
+    pub fn format_relative_to<T: TemporalAccessor>(&self, temporal: &T, reference: &T) -> String {
+        self.try_format_relative_to(temporal, reference).expect("unable to format the value relative to the reference")
+    }
+
+    /// Formats `temporal` relative to `reference`. Values with an instant have their
+    /// calendar days taken in the zone or offset of `reference`.
+    ///
+    /// # Errors
+    /// Returns an error if the values have neither a date nor a time of day.
+    pub fn try_format_relative_to<T: TemporalAccessor>(&self, temporal: &T, reference: &T) -> Result<String, &'static str> {
+        let reference = reference.to_fields();
+        let mut fields = temporal.to_fields();
+        if let Some(instant) = fields.instant() {
+            let zone = reference.zone.clone().or(reference.offset.map(ZoneId::from)).unwrap_or(ZoneId::UTC);
+            fields = instant.at_zone(zone).to_fields();
+        }
+        Ok(self.format_elapsed(elapsed(&fields, &reference)?))
+    }
+
+    /// Formats a duration from now: positive durations are in the future ("in 5 minutes")
+    /// and negative ones in the past ("5 minutes ago").
+    pub fn format_duration(&self, duration: Duration) -> String {
+        self.format_elapsed(Elapsed::Time { nanos: duration.to_nanos(), dates: None })
+    }
+
+    /// Formats a period from now, with months of 30 days and years of 365 days, such as
+    /// "in 1 month" for `P1M` or "2 weeks ago" for `P-14D`.
+    pub fn format_period(&self, period: Period) -> String {
+        let days = i128::from(period.years()) * 365 + i128::from(period.months()) * 30 + i128::from(period.days());
+        self.format_elapsed(Elapsed::Time { nanos: days * NANOS_PER_DAY, dates: None })
+    }
+
+    fn format_elapsed(&self, elapsed: Elapsed) -> String {
+        match elapsed {
+            Elapsed::Time { nanos, dates } => {
+                if nanos.abs() < self.just_now.to_nanos() {
+                    return "just now".to_string();
+                }
+                let (unit, count) = self.unit_and_count(nanos.abs(), 0);
+                if let Some((date, reference)) = dates
+                    && self.named_days
+                    && unit >= ChronoUnit::Days
+                    && let Some(name) = day_name(date, days_between(reference, date))
+                {
+                    return name;
+                }
+                phrase(unit, count, nanos < 0)
+            }
+            Elapsed::Days { days: 0, .. } => "today".to_string(),
+            Elapsed::Days { days, date } => match day_name(date, days).filter(|_| self.named_days) {
+                Some(name) => name,
+                None => {
+                    let (unit, count) = self.unit_and_count(i128::from(days.abs()) * NANOS_PER_DAY, 3);
+                    phrase(unit, count, days < 0)
+                }
+            },
+        }
+    }
+
+    /// Chooses the unit for an amount of nanoseconds, starting from `UNITS[first]`.
+    fn unit_and_count(&self, nanos: i128, first: usize) -> (ChronoUnit, i64) {
+        for (index, &unit) in UNITS.iter().enumerate().skip(first) {
+            let count = self.round(nanos, unit.duration().to_nanos());
+            if index == UNITS.len() - 1 || count < i128::from(self.thresholds[index]) {
+                return (unit, count.clamp(1, i128::from(i64::MAX)) as i64);
+            }
+        }
+        unreachable!("the last unit has no threshold")
+    }
+
+    fn round(&self, nanos: i128, unit: i128) -> i128 {
+        match self.rounding {
+            RoundingMode::HalfUp => (nanos + unit / 2) / unit,
+            RoundingMode::Down => nanos / unit,
+            RoundingMode::Up => (nanos + unit - 1) / unit,
+        }
+    }
+}
+
+/// Finds what separates the value from the reference, by instant, local date-time, date or
+/// time of day, whichever both of them have first.
+fn elapsed(fields: &DateTimeFields, reference: &DateTimeFields) -> Result<Elapsed, &'static str> {
+    let dates = fields.date.zip(reference.date);
+    if let (Some(instant), Some(reference)) = (fields.instant(), reference.instant()) {
+        return Ok(Elapsed::Time { nanos: instant.epoch_nanoseconds() - reference.epoch_nanoseconds(), dates });
+    }
+    if let (Some(ldt), Some(reference)) = (fields.local_date_time(), reference.local_date_time()) {
+        let nanos = i128::from(days_between(reference.to_local_date(), ldt.to_local_date())) * NANOS_PER_DAY
+            + nano_of_day(ldt.to_local_time())
+            - nano_of_day(reference.to_local_time());
+        return Ok(Elapsed::Time { nanos, dates });
+    }
+    if let Some((date, reference)) = dates {
+        return Ok(Elapsed::Days { days: days_between(reference, date), date });
+    }
+    if let (Some(time), Some(reference)) = (fields.time, reference.time) {
+        let nanos = nano_of_day(time) - nano_of_day(reference);
+        return Ok(Elapsed::Time { nanos, dates: None });
+    }
+    Err("Unable to compare values without a date or a time of day")
+}
+
+fn days_between(from: LocalDate, to: LocalDate) -> i64 {
+    i64::from(to.inner().to_julian_day() - from.inner().to_julian_day())
+}
+
+fn nano_of_day(time: LocalTime) -> i128 {
+    i128::from(time.hour() * 3600 + time.minute() * 60 + time.second()) * 1_000_000_000 + i128::from(time.nanosecond())
+}
+
+/// Names a date one to six days away: "yesterday", "tomorrow", "last Tuesday" or
+/// "next Tuesday".
+fn day_name(date: LocalDate, days: i64) -> Option<String> {
+    let weekday = |day: DayOfWeek| day.get_display_name(TextStyle::Full, Locale::US);
+    match days {
+        -1 => Some("yesterday".to_string()),
+        1 => Some("tomorrow".to_string()),
+        -6..=-2 => Some(format!("last {}", weekday(date.day_of_week()))),
+        2..=6 => Some(format!("next {}", weekday(date.day_of_week()))),
+        _ => None,
+    }
+}
+
+fn phrase(unit: ChronoUnit, count: i64, past: bool) -> String {
+    let name = match unit {
+        ChronoUnit::Seconds => "second",
+        ChronoUnit::Minutes => "minute",
+        ChronoUnit::Hours => "hour",
+        ChronoUnit::Days => "day",
+        ChronoUnit::Weeks => "week",
+        ChronoUnit::Months => "month",
+        _ => "year",
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if past { format!("{count} {name}{plural} ago") } else { format!("in {count} {name}{plural}") }
+}
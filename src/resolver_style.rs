@@ -0,0 +1,34 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How parsed fields are combined into dates and times, in the style of
+/// `java.time.format.ResolverStyle`. See [`DateTimeFormatter::with_resolver_style`].
+///
+/// ```rust
+/// let formatter = DateTimeFormatter::of_pattern("uuuu-MM-dd");
+///
+/// let strict = formatter.clone().with_resolver_style(ResolverStyle::Strict);
+/// assert!(strict.parse::<LocalDate>("2023-02-30").is_err());
+///
+/// let smart = formatter.clone().with_resolver_style(ResolverStyle::Smart);
+/// assert_eq!(smart.parse::<LocalDate>("2023-02-30"), Ok(LocalDate::of(2023, 2, 28)));
+///
+/// let lenient = formatter.with_resolver_style(ResolverStyle::Lenient);
+/// assert_eq!(lenient.parse::<LocalDate>("2023-02-30"), Ok(LocalDate::of(2023, 3, 2)));
+/// ```
+///
+/// [`DateTimeFormatter::with_resolver_style`]: crate::DateTimeFormatter::with_resolver_style
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResolverStyle {
+    /// Every field must be in range and the date must exist: `2023-02-30` and `24:00` are
+    /// errors.
+    Strict,
+    /// Fields must be in range, but a day-of-month up to 31 past the end of the month is
+    /// moved back to the last day, and `24:00` is midnight at the start of the next day.
+    #[default]
+    Smart,
+    /// Values past the end of their range roll over into the next larger field, so
+    /// `2023-02-30` is March 2, month 13 is January of the next year and `10:75` is 11:15.
+    Lenient,
+}
@@ -78,3 +78,106 @@ pub mod serde_datetime {
         PrimitiveDateTime::parse(&s, &DATETIME_FORMAT).map_err(serde::de::Error::custom)
     }
 }
+
+/// Serializes a `ZonedDateTime` as RFC 9557 text, the format of its `Display`, such as
+/// `2024-03-10T03:30-04:00[America/New_York]`, so that the zone survives a round trip.
+/// Deserializing rejects text whose offset does not agree with the zone.
+///
+/// The default serde representation of `ZonedDateTime` is RFC 3339, which only keeps the
+/// offset; select this one per field:
+///
+/// ```rust
+/// #[derive(Serialize, Deserialize)]
+/// struct Meeting {
+///     #[serde(with = "joda_rs::serde_time::rfc9557")]
+///     start: ZonedDateTime,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod rfc9557 {
+    use crate::ZonedDateTime;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(zdt: &ZonedDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(zdt)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ZonedDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ZonedDateTime::try_parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes a `Duration` as ISO-8601 text, the format of its `Display`, such as
+/// `PT8H6M12.345S`, and deserializes any text accepted by `Duration::try_parse`.
+///
+/// The default serde representation of `Duration` is that of `time::Duration`; select this
+/// one per field:
+///
+/// ```rust
+/// #[derive(Serialize, Deserialize)]
+/// struct Job {
+///     #[serde(with = "joda_rs::serde_time::iso_duration")]
+///     timeout: Duration,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod iso_duration {
+    use crate::Duration;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(duration)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Duration::try_parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes a `Period` as ISO-8601 text, the format of its `Display`, such as `P1Y2M3D`,
+/// and deserializes any text accepted by `Period::try_parse`, such as `P1M` or `P2W`.
+///
+/// The default serde representation of `Period` is a struct of years, months and days;
+/// select this one per field:
+///
+/// ```rust
+/// #[derive(Serialize, Deserialize)]
+/// struct Subscription {
+///     #[serde(with = "joda_rs::serde_time::iso_period")]
+///     term: Period,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod iso_period {
+    use crate::Period;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(period: &Period, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(period)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Period, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Period::try_parse(&s).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The style of a textual representation, such as a time-zone name, in the style of
+/// `java.time.format.TextStyle`.
+///
+/// Each length comes in a normal form, used inside a complete date or time, and a
+/// standalone form, used on its own (as in a calendar header). Most names are the same
+/// in both forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextStyle {
+    /// Full text, such as "Eastern Time".
+    Full,
+    /// Full text for use on its own.
+    FullStandalone,
+    /// Short text, such as "ET".
+    Short,
+    /// Short text for use on its own.
+    ShortStandalone,
+    /// Narrow text, typically a single letter.
+    Narrow,
+    /// Narrow text for use on its own.
+    NarrowStandalone,
+}
+
+impl TextStyle {
+    /// Returns `true` if this is a standalone style.
+    pub fn is_standalone(self) -> bool {
+        matches!(self, TextStyle::FullStandalone | TextStyle::ShortStandalone | TextStyle::NarrowStandalone)
+    }
+
+    /// Returns the standalone style of the same length.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(TextStyle::Short.as_standalone(), TextStyle::ShortStandalone);
+    /// ```
+    pub fn as_standalone(self) -> Self {
+        match self {
+            TextStyle::Full | TextStyle::FullStandalone => TextStyle::FullStandalone,
+            TextStyle::Short | TextStyle::ShortStandalone => TextStyle::ShortStandalone,
+            TextStyle::Narrow | TextStyle::NarrowStandalone => TextStyle::NarrowStandalone,
+        }
+    }
+
+    /// Returns the normal (not standalone) style of the same length.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(TextStyle::FullStandalone.as_normal(), TextStyle::Full);
+    /// ```
+    pub fn as_normal(self) -> Self {
+        match self {
+            TextStyle::Full | TextStyle::FullStandalone => TextStyle::Full,
+            TextStyle::Short | TextStyle::ShortStandalone => TextStyle::Short,
+            TextStyle::Narrow | TextStyle::NarrowStandalone => TextStyle::Narrow,
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Reader for compiled time-zone files in the TZif format (RFC 8536), versions 1 to 4, and for
+//! the POSIX `TZ` rule in their footer that covers instants after the last transition.
+
+/// Instants are clamped to this range (years -10706 to 14645), which covers every `Instant`,
+/// before calendar calculations so that the rule instants of their years cannot overflow.
+const MAX_SECONDS: i64 = 400_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+/// Days from 0000-03-01, the start of the 400-year era used below, to 1970-01-01.
+const DAYS_TO_UNIX_EPOCH: i64 = 719_468;
+/// The largest offset from UTC, 25:59:59, that a `ZoneOffset` can hold.
+const MAX_OFFSET: i64 = 93_599;
+
+const INVALID: &str = "Invalid TZif data";
+
+/// One of the local time types of a zone: an offset, whether it is daylight saving time and
+/// its abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LocalTimeType {
+    pub(crate) offset: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) abbreviation: String,
+}
+
+/// The parsed content of a TZif file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TzifData {
+    transitions: Vec<i64>,
+    type_indices: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    footer: Option<PosixTz>,
+}
+
+impl TzifData {
+    /// Parses a TZif file. For version 2 and later the 64-bit data block and the footer are
+    /// used; the version 1 block is only read from version 1 files.
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = Reader { bytes, position: 0 };
+        let header = reader.header()?;
+        if header.version == 0 {
+            return reader.block(&header, 4);
+        }
+        reader.skip_block(&header, 4)?;
+        let header = reader.header()?;
+        let mut data = reader.block(&header, 8)?;
+        data.footer = reader.footer()?;
+        Ok(data)
+    }
+
+    /// Returns the local time type in effect at the given epoch second.
+    pub(crate) fn local_time_type(&self, epoch_second: i64) -> &LocalTimeType {
+        if let Some(footer) = &self.footer
+            && self.transitions.last().is_none_or(|&last| epoch_second >= last)
+        {
+            return footer.local_time_type(epoch_second);
+        }
+        match self.transitions.partition_point(|&at| at <= epoch_second) {
+            0 => &self.types[0],
+            index => &self.types[self.type_indices[index - 1]],
+        }
+    }
+
+    /// Returns the first second after `epoch_second` at which the offset changes.
+    pub(crate) fn next_change(&self, epoch_second: i64) -> Option<i64> {
+        let start = self.transitions.partition_point(|&at| at <= epoch_second);
+        if let Some(&at) = self.transitions[start..].iter().find(|&&at| self.changes_at(at)) {
+            return Some(at);
+        }
+        let footer = self.footer.as_ref()?;
+        let floor = self.transitions.last().map_or(epoch_second, |&last| last.max(epoch_second));
+        let year = year_of(floor);
+        (year..=year + 1)
+            .flat_map(|year| footer.transitions(year))
+            .find(|&at| at > floor && self.changes_at(at))
+    }
+
+    /// Returns the last second strictly before `bound` at which the offset changes.
+    pub(crate) fn previous_change(&self, bound: i64) -> Option<i64> {
+        if let Some(footer) = &self.footer {
+            let floor = self.transitions.last().copied();
+            if floor.is_none_or(|last| bound > last) {
+                let lowest = (year_of(bound) - 1).max(floor.map_or(i64::MIN, year_of));
+                let found = (lowest..=year_of(bound))
+                    .rev()
+                    .flat_map(|year| footer.transitions(year).into_iter().rev())
+                    .find(|&at| at < bound && floor.is_none_or(|last| at > last) && self.changes_at(at));
+                if found.is_some() {
+                    return found;
+                }
+            }
+        }
+        let end = self.transitions.partition_point(|&at| at < bound);
+        self.transitions[..end].iter().rev().find(|&&at| self.changes_at(at)).copied()
+    }
+
+    fn changes_at(&self, epoch_second: i64) -> bool {
+        self.local_time_type(epoch_second.saturating_sub(1)).offset != self.local_time_type(epoch_second).offset
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], &'static str> {
+        let end = self.position.checked_add(count).ok_or(INVALID)?;
+        let slice = self.bytes.get(self.position..end).ok_or(INVALID)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn count(&mut self) -> Result<usize, &'static str> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    }
+
+    fn header(&mut self) -> Result<Header, &'static str> {
+        if self.take(4)? != b"TZif" {
+            return Err(INVALID);
+        }
+        let version = match self.take(1)?[0] {
+            0 => 0,
+            version @ b'2'..=b'4' => version - b'0',
+            _ => return Err("Unsupported TZif version"),
+        };
+        self.take(15)?;
+        let header = Header {
+            version,
+            isutcnt: self.count()?,
+            isstdcnt: self.count()?,
+            leapcnt: self.count()?,
+            timecnt: self.count()?,
+            typecnt: self.count()?,
+            charcnt: self.count()?,
+        };
+        if header.typecnt == 0 {
+            return Err(INVALID);
+        }
+        Ok(header)
+    }
+
+    fn skip_block(&mut self, header: &Header, time_size: usize) -> Result<(), &'static str> {
+        let length = header.timecnt * (time_size + 1)
+            + header.typecnt * 6
+            + header.charcnt
+            + header.leapcnt * (time_size + 4)
+            + header.isstdcnt
+            + header.isutcnt;
+        self.take(length).map(|_| ())
+    }
+
+    fn block(&mut self, header: &Header, time_size: usize) -> Result<TzifData, &'static str> {
+        let transitions = self
+            .take(header.timecnt * time_size)?
+            .chunks(time_size)
+            .map(|chunk| match *chunk {
+                [a, b, c, d] => i64::from(i32::from_be_bytes([a, b, c, d])),
+                _ => i64::from_be_bytes(chunk.try_into().expect("8-byte transition time")),
+            })
+            .collect::<Vec<_>>();
+        let type_indices = self.take(header.timecnt)?.iter().map(|&index| index as usize).collect::<Vec<_>>();
+        let raw_types = self.take(header.typecnt * 6)?;
+        let designations = self.take(header.charcnt)?;
+        self.take(header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt)?;
+
+        if type_indices.iter().any(|&index| index >= header.typecnt) || !transitions.is_sorted() {
+            return Err(INVALID);
+        }
+        let types = raw_types
+            .chunks(6)
+            .map(|chunk| {
+                let offset = i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                if i64::from(offset).abs() > MAX_OFFSET {
+                    return Err(INVALID);
+                }
+                let start = chunk[5] as usize;
+                let rest = designations.get(start..).ok_or(INVALID)?;
+                let end = rest.iter().position(|&b| b == 0).ok_or(INVALID)?;
+                let abbreviation = String::from_utf8(rest[..end].to_vec()).map_err(|_| INVALID)?;
+                Ok(LocalTimeType { offset, is_dst: chunk[4] != 0, abbreviation })
+            })
+            .collect::<Result<Vec<_>, &'static str>>()?;
+        Ok(TzifData { transitions, type_indices, types, footer: None })
+    }
+
+    fn footer(&mut self) -> Result<Option<PosixTz>, &'static str> {
+        let rest = &self.bytes[self.position..];
+        let Some(rest) = rest.strip_prefix(b"\n") else {
+            return Ok(None);
+        };
+        let end = rest.iter().position(|&b| b == b'\n').ok_or(INVALID)?;
+        let text = std::str::from_utf8(&rest[..end]).map_err(|_| INVALID)?;
+        if text.is_empty() {
+            return Ok(None);
+        }
+        PosixTz::parse(text).map(Some).ok_or(INVALID)
+    }
+}
+
+/// A POSIX `TZ` rule such as `EST5EDT,M3.2.0,M11.1.0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PosixTz {
+    standard: LocalTimeType,
+    daylight: Option<(LocalTimeType, PosixRule, PosixRule)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PosixRule {
+    date: RuleDate,
+    /// Seconds after local midnight, possibly negative or beyond 24 hours (TZif version 3).
+    time: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: day 1 to 365, never counting February 29.
+    Julian(u16),
+    /// `n`: zero-based day of the year, counting February 29.
+    ZeroBased(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last) of month `m`.
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl PosixTz {
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let mut cursor = Cursor { bytes: text.as_bytes(), position: 0 };
+        let standard_name = cursor.name()?;
+        let standard_offset = -cursor.offset()?;
+        let standard = LocalTimeType { offset: standard_offset as i32, is_dst: false, abbreviation: standard_name };
+        if cursor.is_done() {
+            return Some(PosixTz { standard, daylight: None });
+        }
+        let daylight_name = cursor.name()?;
+        let daylight_offset = match cursor.peek() {
+            Some(b',') | None => standard_offset + 3_600,
+            _ => -cursor.offset()?,
+        };
+        if daylight_offset.abs() > MAX_OFFSET {
+            return None;
+        }
+        let (start, end) = if cursor.eat(b',') {
+            let start = cursor.rule()?;
+            cursor.eat(b',').then_some(())?;
+            (start, cursor.rule()?)
+        } else {
+            let start = PosixRule { date: RuleDate::MonthWeekDay(3, 2, 0), time: 7_200 };
+            (start, PosixRule { date: RuleDate::MonthWeekDay(11, 1, 0), time: 7_200 })
+        };
+        if !cursor.is_done() {
+            return None;
+        }
+        let daylight = LocalTimeType { offset: daylight_offset as i32, is_dst: true, abbreviation: daylight_name };
+        Some(PosixTz { standard, daylight: Some((daylight, start, end)) })
+    }
+
+    fn local_time_type(&self, epoch_second: i64) -> &LocalTimeType {
+        let Some((daylight, _, _)) = &self.daylight else {
+            return &self.standard;
+        };
+        let [start, end] = self.year_bounds(year_of(epoch_second.saturating_add(i64::from(self.standard.offset))));
+        let in_daylight = if start < end {
+            start <= epoch_second && epoch_second < end
+        } else {
+            !(end <= epoch_second && epoch_second < start)
+        };
+        if in_daylight { daylight } else { &self.standard }
+    }
+
+    /// Returns the instants at which daylight saving time starts and ends in the given year.
+    fn year_bounds(&self, year: i64) -> [i64; 2] {
+        let Some((daylight, start, end)) = &self.daylight else {
+            return [i64::MIN, i64::MIN];
+        };
+        [
+            start.local_seconds(year) - i64::from(self.standard.offset),
+            end.local_seconds(year) - i64::from(daylight.offset),
+        ]
+    }
+
+    /// Returns the transitions of the given year, in order.
+    fn transitions(&self, year: i64) -> Vec<i64> {
+        if self.daylight.is_none() {
+            return Vec::new();
+        }
+        let mut bounds = self.year_bounds(year);
+        bounds.sort_unstable();
+        bounds.to_vec()
+    }
+}
+
+impl PosixRule {
+    /// Returns the local date-time of the rule in the given year, in seconds since the epoch.
+    fn local_seconds(self, year: i64) -> i64 {
+        let leap = is_leap(year);
+        let days = match self.date {
+            RuleDate::Julian(day) => {
+                let day = i64::from(day);
+                days_from_civil(year, 1, 1) + day - 1 + i64::from(leap && day >= 60)
+            }
+            RuleDate::ZeroBased(day) => days_from_civil(year, 1, 1) + i64::from(day),
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday (weekday 4).
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first + (i64::from(weekday) - first_weekday).rem_euclid(7) + 7 * (i64::from(week) - 1);
+                let next_month = if month == 12 { days_from_civil(year + 1, 1, 1) } else { days_from_civil(year, month + 1, 1) };
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        days * SECONDS_PER_DAY + self.time
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matched = self.peek() == Some(byte);
+        self.position += usize::from(matched);
+        matched
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &str {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position]).unwrap_or_default()
+    }
+
+    fn name(&mut self) -> Option<String> {
+        if self.eat(b'<') {
+            let name = self.take_while(|b| b != b'>').to_string();
+            return self.eat(b'>').then_some(name);
+        }
+        let name = self.take_while(|b| b.is_ascii_alphabetic()).to_string();
+        (name.len() >= 3).then_some(name)
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        self.take_while(|b| b.is_ascii_digit()).parse().ok()
+    }
+
+    /// Parses `[+-]hh[:mm[:ss]]` into seconds.
+    fn duration(&mut self) -> Option<i64> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number()?.checked_mul(3_600)?;
+        if self.eat(b':') {
+            seconds = seconds.checked_add(self.number()?.checked_mul(60)?)?;
+            if self.eat(b':') {
+                seconds = seconds.checked_add(self.number()?)?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    /// Parses a duration that must be a valid offset from UTC, within 25:59:59.
+    fn offset(&mut self) -> Option<i64> {
+        self.duration().filter(|seconds| seconds.abs() <= MAX_OFFSET)
+    }
+
+    fn rule(&mut self) -> Option<PosixRule> {
+        let date = if self.eat(b'J') {
+            RuleDate::Julian(u16::try_from(self.number()?).ok().filter(|day| (1..=365).contains(day))?)
+        } else if self.eat(b'M') {
+            let month = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let week = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday) {
+                return None;
+            }
+            RuleDate::MonthWeekDay(month as u8, week as u8, weekday as u8)
+        } else {
+            RuleDate::ZeroBased(u16::try_from(self.number()?).ok().filter(|day| *day <= 365)?)
+        };
+        // RFC 8536 allows rule times from -167 to 167 hours.
+        let time = if self.eat(b'/') { self.duration().filter(|seconds| seconds.abs() <= 167 * 3_600)? } else { 7_200 };
+        Some(PosixRule { date, time })
+    }
+}
+
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the days since 1970-01-01 of a proleptic Gregorian date, counting in 400-year
+/// eras that start on March 1st so that February 29 is the last day of a year.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - DAYS_TO_UNIX_EPOCH
+}
+
+/// Returns the proleptic Gregorian year containing the given epoch second.
+fn year_of(epoch_second: i64) -> i64 {
+    let days = epoch_second.clamp(-MAX_SECONDS, MAX_SECONDS).div_euclid(SECONDS_PER_DAY) + DAYS_TO_UNIX_EPOCH;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Days from March 1st: January and February belong to the next calendar year.
+    let year = year_of_era + era * 400;
+    if day_of_year >= 306 { year + 1 } else { year }
+}
@@ -1,4 +1,4 @@
-use crate::{Clock, LocalDate, Month, YearMonth, ZoneId};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, LocalDate, Month, YearMonth, ZoneId};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -59,6 +59,28 @@ impl Year {
     /// ```
     pub fn of(value: i32) -> Self { Year(value) }
 
+    /// Obtains a `Year` from text such as `2024` or `-44`.
+    ///
+    /// # Panics
+    /// Panics if the text cannot be parsed, see [`Year::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid Year string")
+    }
+
+    /// Obtains a `Year` like [`Year::parse`], returning an error instead of panicking.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(Year::try_parse("2024"), Ok(Year::of(2024)));
+    /// assert!(Year::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::of_pattern("u").parse(text)
+    }
+
     /// Returns the year `i32` value.
     ///
     /// # Example
@@ -71,7 +93,8 @@ impl Year {
     /// ```
     pub fn value(self) -> i32 { self.0 }
 
-    /// Returns the current year as an instance of `Self` using the system's UTC clock.
+    /// Returns the current year as an instance of `Self` in the system default zone, see
+    /// [`ZoneId::system_default`].
     ///
     /// # Returns
     /// An instance of `Self` representing the current year.
@@ -83,7 +106,7 @@ impl Year {
     /// println!("Current year: {:?}", Year::now().value());
     /// ```
     pub fn now() -> Self {
-        Self::now_with_clock(Clock::system_utc())
+        Self::now_with_clock(Clock::system_default_zone())
     }
 
     /// Retrieves the current datetime in the specified time zone.
@@ -429,3 +452,19 @@ impl Year {
 impl core::fmt::Display for Year {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { write!(f, "{}", self.0) }
 }
+
+impl std::str::FromStr for Year {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for Year {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{LocalDate, Month, Year};
+use crate::{DateTimeFormatter, DateTimeParseError, LocalDate, Month, Year};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -20,11 +20,32 @@ impl YearMonth {
         YearMonth { year, month }
     }
 
+    /// Obtains a `YearMonth` from ISO-8601 text such as `2024-03`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`YearMonth::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid YearMonth string")
+    }
+
+    /// Obtains a `YearMonth` like [`YearMonth::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(YearMonth::try_parse("2024-03"), Ok(YearMonth::of(2024, 3)));
+    /// assert!(YearMonth::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::of_pattern("uuuu-MM").parse(text)
+    }
+
+    /// Returns the current year-month in the system default zone, see [`ZoneId::system_default`](crate::ZoneId::system_default).
     pub fn now() -> Self {
-        let d = time::OffsetDateTime::now_utc().date();
-        let year = d.year();
-        let month: Month = d.month().into();
-        YearMonth { year, month }
+        let date = LocalDate::now();
+        YearMonth { year: date.year(), month: date.month() }
     }
 
     pub fn year(self) -> i32 { self.year }
@@ -84,8 +105,32 @@ impl YearMonth {
     pub fn is_on_or_after(self, other: YearMonth) -> bool { !self.is_before(other) }
 }
 
+/// Formats as ISO-8601 `uuuu-MM`, such as `2024-03`, `-0044-03` or `+10000-01`, like
+/// `java.time.YearMonth.toString`.
 impl core::fmt::Display for YearMonth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:04}-{:02}", self.year, self.month_value())
+        if self.year < 0 {
+            write!(f, "-{:04}-{:02}", -i64::from(self.year), self.month_value())
+        } else if self.year > 9999 {
+            write!(f, "+{}-{:02}", self.year, self.month_value())
+        } else {
+            write!(f, "{:04}-{:02}", self.year, self.month_value())
+        }
+    }
+}
+
+impl std::str::FromStr for YearMonth {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for YearMonth {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
     }
 }
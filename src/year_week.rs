@@ -0,0 +1,203 @@
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, DayOfWeek, LocalDate, ResolverStyle};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A week of an ISO-8601 week-based year, such as `2024-W05`.
+///
+/// Weeks start on Monday and week 1 is the week with the first Thursday of the year, so a
+/// week-based year has 52 or 53 weeks and its first days may be in the previous calendar
+/// year: 2025-W01 starts on Monday 30 December 2024.
+///
+/// ```rust
+/// let week = YearWeek::of(2024, 5);
+/// assert_eq!(week.at_day(DayOfWeek::Wednesday), LocalDate::of(2024, 1, 31));
+/// assert_eq!(week.plus_weeks(48), YearWeek::of(2025, 1));
+/// assert_eq!(YearWeek::of(2020, 1).length_of_year(), 53);
+/// assert_eq!(week.to_string(), "2024-W05");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "YearWeekFields"))]
+pub struct YearWeek {
+    year: i32,
+    week: u8,
+}
+
+/// The serialized fields of a [`YearWeek`], checked by [`YearWeek::try_of`] when
+/// deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct YearWeekFields {
+    year: i32,
+    week: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<YearWeekFields> for YearWeek {
+    type Error = &'static str;
+
+    fn try_from(fields: YearWeekFields) -> Result<Self, Self::Error> {
+        Self::try_of(fields.year, fields.week)
+    }
+}
+
+impl YearWeek {
+    /// Obtains a week of a week-based year.
+    ///
+    /// ### Panics
+    /// Panics if the year or week is invalid, see [`YearWeek::try_of`].
+    pub fn of(year: i32, week: i32) -> Self {
+        Self::try_of(year, week).expect("invalid week for YearWeek")
+    }
+
+    /// Obtains a week of a week-based year like [`YearWeek::of`], returning an error instead
+    /// of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(YearWeek::try_of(2020, 53).map(YearWeek::week), Ok(53));
+    /// assert!(YearWeek::try_of(2023, 53).is_err());
+    /// assert!(YearWeek::try_of(10_000, 1).is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns an error if the year is outside -9999 to 9999, the range of `LocalDate`, or if
+    /// the week is not in the week-based year, which has 52 or 53 weeks.
+    pub fn try_of(year: i32, week: i32) -> Result<Self, &'static str> {
+        if !(time::Date::MIN.year()..=time::Date::MAX.year()).contains(&year) {
+            return Err("Invalid value for WeekBasedYear");
+        }
+        if week < 1 || week > weeks_in_year(year) {
+            return Err("Invalid value for WeekOfWeekBasedYear");
+        }
+        Ok(YearWeek { year, week: week as u8 })
+    }
+
+    /// Obtains a `YearWeek` from ISO-8601 text such as `2024-W05`.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`YearWeek::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid YearWeek string")
+    }
+
+    /// Obtains a `YearWeek` like [`YearWeek::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(YearWeek::try_parse("2024-W05"), Ok(YearWeek::of(2024, 5)));
+    /// assert!(YearWeek::try_parse("2023-W53").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::of_pattern("YYYY-'W'ww").with_resolver_style(ResolverStyle::Strict).parse(text)
+    }
+
+    /// Returns the current week in the system default zone, see [`ZoneId::system_default`](crate::ZoneId::system_default).
+    pub fn now() -> Self {
+        Self::from(LocalDate::now())
+    }
+
+    /// Returns the current week of the clock in its zone.
+    pub fn now_with_clock(clock: &Clock) -> Self {
+        Self::from(LocalDate::now_with_clock(clock))
+    }
+
+    pub fn year(self) -> i32 { self.year }
+    pub fn week(self) -> i32 { i32::from(self.week) }
+
+    /// Returns the number of weeks in the week-based year, 52 or 53.
+    pub fn length_of_year(self) -> i32 { weeks_in_year(self.year) }
+
+    pub fn plus_weeks(self, weeks: i64) -> Self { Self::from(self.at_day(DayOfWeek::Monday).plus_weeks(weeks)) }
+    pub fn minus_weeks(self, weeks: i64) -> Self { self.plus_weeks(-weeks) }
+
+    /// Adds years, keeping the week except for week 53 in a year of 52 weeks, which becomes
+    /// week 52.
+    pub fn plus_years(self, years: i64) -> Self {
+        let ny = self.year as i64 + years;
+        if ny > i32::MAX as i64 || ny < i32::MIN as i64 { panic!("year overflow") }
+        self.with_year(ny as i32)
+    }
+    pub fn minus_years(self, years: i64) -> Self { self.plus_years(-years) }
+
+    /// Returns this week in another week-based year, week 53 becoming week 52 in a year of
+    /// 52 weeks.
+    ///
+    /// ### Panics
+    /// Panics if the year is outside -9999 to 9999.
+    pub fn with_year(self, year: i32) -> Self {
+        YearWeek::of(year, self.week().min(weeks_in_year(year)))
+    }
+    pub fn with_week(self, week: i32) -> Self { YearWeek::of(self.year, week) }
+
+    /// Returns the date of the day of the week in this week.
+    ///
+    /// ### Panics
+    /// Panics if the date is after 9999-12-31, as the last days of 9999-W52 are.
+    pub fn at_day(self, day_of_week: DayOfWeek) -> LocalDate {
+        LocalDate::of_iso_week(self.year, self.week(), day_of_week)
+    }
+
+    /// Returns the number of whole weeks from this week to `end`, negative if `end` is before.
+    pub fn weeks_until(self, end: YearWeek) -> i64 {
+        let days = end.at_day(DayOfWeek::Monday).inner().to_julian_day() - self.at_day(DayOfWeek::Monday).inner().to_julian_day();
+        i64::from(days / 7)
+    }
+
+    /// Returns the weeks from this week up to but not including `end`, like
+    /// `LocalDate.datesUntil` in java.time. It is empty if `end` is not after this week.
+    ///
+    /// ```rust
+    /// let weeks: Vec<YearWeek> = YearWeek::of(2020, 52).iter_until(YearWeek::of(2021, 2)).collect();
+    /// assert_eq!(weeks, [YearWeek::of(2020, 52), YearWeek::of(2020, 53), YearWeek::of(2021, 1)]);
+    /// ```
+    pub fn iter_until(self, end: YearWeek) -> impl Iterator<Item = YearWeek> {
+        (0..self.weeks_until(end).max(0)).map(move |weeks| self.plus_weeks(weeks))
+    }
+
+    pub fn is_before(self, other: YearWeek) -> bool { self < other }
+    pub fn is_after(self, other: YearWeek) -> bool { self > other }
+    pub fn is_on_or_before(self, other: YearWeek) -> bool { !self.is_after(other) }
+    pub fn is_on_or_after(self, other: YearWeek) -> bool { !self.is_before(other) }
+}
+
+fn weeks_in_year(year: i32) -> i32 {
+    i32::from(time::util::weeks_in_year(year))
+}
+
+impl From<LocalDate> for YearWeek {
+    fn from(date: LocalDate) -> Self {
+        YearWeek { year: date.week_based_year(), week: date.week_of_week_based_year() as u8 }
+    }
+}
+
+/// Formats as ISO-8601 `YYYY-'W'ww`, such as `2024-W05` or `-0044-W05`.
+impl core::fmt::Display for YearWeek {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.year < 0 {
+            write!(f, "-{:04}-W{:02}", -i64::from(self.year), self.week)
+        } else {
+            write!(f, "{:04}-W{:02}", self.year, self.week)
+        }
+    }
+}
+
+impl std::str::FromStr for YearWeek {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for YearWeek {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
+}
@@ -1,13 +1,40 @@
+use crate::zone_links::{self, Link};
+use crate::zone_names::{MetazoneNames, ABBREVIATIONS, DAYLIGHT_OVERRIDES, METAZONES, ZONE_METAZONES};
+use crate::zone_rules_provider::{self, ZoneRulesProvider};
+use crate::{Instant, LocalDateTime, TextStyle, ZoneOffset, ZoneRules};
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::path::Path;
 use std::str::FromStr;
-use time::OffsetDateTime;
+use std::sync::{Arc, PoisonError, RwLock};
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 use time_tz::timezones::get_by_name;
-use time_tz::{timezones, OffsetDateTimeExt, TimeZone};
+use time_tz::{timezones, TimeZone};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ZoneId(&'static str);
+/// A time-zone ID, in the style of `java.time.ZoneId`.
+///
+/// There are two kinds of ID:
+/// - Region-based IDs such as `Europe/Paris` or `Etc/GMT+5`, whose rules come from the tz
+///   database.
+/// - Offset-based IDs with fixed rules: a plain offset (`Z`, `+05:30`), or an offset after
+///   one of the prefixes `UTC`, `GMT` or `UT` (`UTC+05:30`, `GMT-3`, `UT`).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZoneId {
+    /// Borrowed for zones of the tz database, owned for IDs built at runtime.
+    id: Cow<'static, str>,
+    /// The offset of a zone with fixed rules, `None` for zones from the tz database.
+    fixed: Option<ZoneOffset>,
+}
+
+/// The system default zone, determined on first use, see [`ZoneId::try_system_default`].
+static SYSTEM_DEFAULT: RwLock<Option<Result<ZoneId, &'static str>>> = RwLock::new(None);
+
+/// The prefixes accepted before an offset, longest first so `UTC` is not read as `UT`.
+const PREFIXES: [&str; 3] = ["UTC", "GMT", "UT"];
 
 impl ZoneId {
     /// A constant representing the UTC (Coordinated Universal Time) time zone.
@@ -20,69 +47,340 @@ impl ZoneId {
     /// ```
     /// println!("Time zone: {}", ZoneId::UTC);
     /// ```
-    pub const UTC: ZoneId = ZoneId("UTC");
+    pub const UTC: ZoneId = ZoneId { id: Cow::Borrowed("UTC"), fixed: Some(ZoneOffset::UTC) };
 
     /// Retrieves the system's default time zone and converts it into a `ZoneId` instance.
     ///
-    /// # Returns
-    /// A `ZoneId` representing the system's current time zone.
-    ///
-    /// # Errors
-    /// This function will panic if the underlying system time zone cannot be determined.
-    /// Ensure that your operating system is properly configured to provide time zone information.
+    /// Every `now()` constructor uses this zone. It never panics: if the host configuration
+    /// does not name a known zone, this falls back to [`ZoneId::UTC`]. Use
+    /// [`ZoneId::try_system_default`] to detect that case.
     ///
-    /// # Panics
-    /// - Panics with the message "Failed to determine system time zone; please check your OS configuration"
-    ///   if the time zone cannot be retrieved.
+    /// The zone is determined once and cached, like `java.util.TimeZone.getDefault`; call
+    /// [`ZoneId::refresh_system_default`] after the host configuration changes.
     ///
     /// # Example
     /// ```rust
     /// let default_zone = ZoneId::system_default();
     /// println!("System default time zone: {:?}", default_zone);
     /// ```
-    ///
-    /// Note: This function relies on the `time_tz` crate to get the system time zone.
     pub fn system_default() -> Self {
-        let tz = time_tz::system::get_timezone()
-            .expect("Failed to determine system time zone; please check your OS configuration");
-        ZoneId::of(tz.name())
+        Self::try_system_default().unwrap_or(ZoneId::UTC)
+    }
+
+    /// Determines the system's default time zone, looking in order at:
+    /// 1. The `TZ` environment variable, like the C library does. An empty value means UTC,
+    ///    a leading `:` is ignored, and a path into a zoneinfo directory names the zone below it.
+    /// 2. The target of the `/etc/localtime` symbolic link, such as
+    ///    `/usr/share/zoneinfo/Europe/Paris`.
+    /// 3. The content of `/etc/timezone`.
+    /// 4. The zone reported by the operating system.
+    ///
+    /// The result is cached on the first call, see [`ZoneId::refresh_system_default`].
+    ///
+    /// # Errors
+    /// Returns an error if `TZ` is set to an unknown zone, or if none of the sources above
+    /// names a known zone.
+    ///
+    /// # Example
+    /// ```rust
+    /// match ZoneId::try_system_default() {
+    ///     Ok(zone) => println!("System default time zone: {}", zone.id()),
+    ///     Err(reason) => eprintln!("Using UTC: {reason}"),
+    /// }
+    /// ```
+    pub fn try_system_default() -> Result<Self, &'static str> {
+        if let Some(cached) = SYSTEM_DEFAULT.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+            return cached.clone();
+        }
+        Self::refresh_system_default()
+    }
+
+    /// Determines the system's default time zone again, like [`ZoneId::try_system_default`],
+    /// and caches the result for [`ZoneId::system_default`] and every `now()` constructor.
+    ///
+    /// Call this after changing the `TZ` environment variable or the host's zone.
+    ///
+    /// # Errors
+    /// The same as [`ZoneId::try_system_default`].
+    ///
+    /// # Example
+    /// ```rust
+    /// unsafe { std::env::set_var("TZ", "Europe/Paris") };
+    /// assert_eq!(ZoneId::refresh_system_default().unwrap().id(), "Europe/Paris");
+    /// ```
+    pub fn refresh_system_default() -> Result<Self, &'static str> {
+        let zone = Self::read_system_default();
+        *SYSTEM_DEFAULT.write().unwrap_or_else(PoisonError::into_inner) = Some(zone.clone());
+        zone
+    }
+
+    fn read_system_default() -> Result<Self, &'static str> {
+        if let Some(tz) = std::env::var_os("TZ") {
+            let tz = tz.to_string_lossy();
+            let tz = tz.strip_prefix(':').unwrap_or(&tz);
+            return match tz {
+                "" => Ok(ZoneId::UTC),
+                path if path.starts_with('/') => zone_from_path(Path::new(path)).ok_or("Unknown time zone in TZ"),
+                id => ZoneId::try_of(id).map_err(|_| "Unknown time zone in TZ"),
+            };
+        }
+        if let Ok(target) = fs::read_link("/etc/localtime")
+            && let Some(zone) = zone_from_path(&target)
+        {
+            return Ok(zone);
+        }
+        if let Ok(content) = fs::read_to_string("/etc/timezone")
+            && let Ok(zone) = ZoneId::try_of(content.trim())
+        {
+            return Ok(zone);
+        }
+        let tz = time_tz::system::get_timezone().map_err(|_| "Failed to determine system time zone")?;
+        ZoneId::try_of(tz.name()).map_err(|_| "Failed to determine system time zone")
     }
 
     /// Attempts to create a `ZoneId` instance from the given time zone identifier.
     ///
+    /// Accepts every ID that `java.time.ZoneId.of` accepts:
+    /// - `Z` or an offset starting with `+` or `-` (`+05:30`, `-3`), giving an offset-based ID
+    ///   normalized like [`ZoneOffset::id`].
+    /// - `UTC`, `GMT` or `UT`, alone or followed by an offset (`UTC+05:30`, `GMT-3`), giving a
+    ///   prefixed ID with fixed rules. A zero offset leaves only the prefix (`UTC+0` is `UTC`).
+    /// - Region IDs from the tz database, such as `America/New_York` or `Etc/GMT+5`.
+    ///
     /// # Parameters
-    /// - `id`: A string slice (`&'static str`) representing the identifier of the time zone.
+    /// - `id`: A string slice representing the identifier of the time zone. It does not need
+    ///   to be `'static`.
     ///
     /// # Returns
     /// - `Ok(ZoneId)`: If a valid time zone corresponding to the provided `id` is found.
     /// - `Err(&'static str)`: If the provided `id` does not correspond to any known time zone.
-    ///   The returned error message will be `"Unknown time zone"`.
     ///
     /// # Example
     /// ```
     /// let zone_id = ZoneId::try_of("America/New_York");
     /// assert!(zone_id.is_ok());
     ///
+    /// let fixed = ZoneId::try_of("GMT-3").unwrap();
+    /// assert_eq!(fixed.id(), "GMT-03:00");
+    ///
     /// let invalid_zone_id = ZoneId::try_of("Invalid/Time_Zone");
     /// assert!(invalid_zone_id.is_err());
     /// ```
     ///
     /// # Errors
-    /// This function will return an error if the given `id` does not match any known time zone
-    /// in the `timezones` module.
-    ///
-    /// # Notes
-    /// The `timezones::get_by_name` function is used internally to verify the validity of the provided `id`.
-    /// Ensure that the `timezones` module is properly configured and contains the expected data
-    /// before calling this function.
-    pub fn try_of(id: &'static str) -> Result<Self, &'static str> {
-        if timezones::get_by_name(id).is_some() {
-            Ok(ZoneId(id))
+    /// - `"Invalid ID for ZoneOffset"` if an offset is malformed or out of range.
+    /// - `"Invalid ID for region-based ZoneId"` if a region ID contains invalid characters.
+    /// - `"Unknown time zone"` if a region ID is not in the tz database.
+    pub fn try_of(id: &str) -> Result<Self, &'static str> {
+        if id == "Z" || id.starts_with('+') || id.starts_with('-') {
+            return ZoneOffset::try_of(id).map(ZoneId::from);
+        }
+        for prefix in PREFIXES {
+            if let Some(rest) = id.strip_prefix(prefix) {
+                if rest.is_empty() {
+                    return Ok(ZoneId::prefixed(prefix, ZoneOffset::UTC));
+                }
+                if rest.starts_with('+') || rest.starts_with('-') {
+                    return ZoneOffset::try_of(rest).map(|offset| ZoneId::prefixed(prefix, offset));
+                }
+            }
+        }
+        if !is_valid_region_id(id) {
+            return Err("Invalid ID for region-based ZoneId");
+        }
+        if zone_rules_provider::provided_rules(id).is_some() {
+            return Ok(ZoneId { id: Cow::Owned(id.to_string()), fixed: None });
+        }
+        match get_by_name(id) {
+            Some(tz) => Ok(ZoneId { id: Cow::Borrowed(tz.name()), fixed: None }),
+            None => Err("Unknown time zone"),
+        }
+    }
+
+    /// Parses a `ZoneId` like [`ZoneId::try_of`], with an error that keeps the input and,
+    /// for an unknown region ID, the closest known IDs.
+    ///
+    /// # Example
+    /// ```
+    /// let err = ZoneId::try_parse("America/New_Yrok").unwrap_err();
+    /// assert_eq!(err.suggestions(), ["America/New_York"]);
+    /// assert_eq!(err.to_string(), "Unknown time zone \"America/New_Yrok\"; did you mean \"America/New_York\"?");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`ZoneIdParseError`] with the reason [`ZoneId::try_of`] gives.
+    pub fn try_parse(text: &str) -> Result<Self, ZoneIdParseError> {
+        ZoneId::try_of(text).map_err(|reason| {
+            let suggestions = if reason == "Unknown time zone" { ZoneId::suggestions(text) } else { Vec::new() };
+            ZoneIdParseError { input: text.to_string(), reason, suggestions }
+        })
+    }
+
+    /// Creates a `ZoneId` with fixed rules from a prefix and an offset, as
+    /// `java.time.ZoneId.ofOffset` does.
+    ///
+    /// # Parameters
+    /// - `prefix`: `"UTC"`, `"GMT"`, `"UT"`, or `""` for a plain offset ID.
+    /// - `offset`: The offset used at every instant.
+    ///
+    /// # Errors
+    /// Returns `"Invalid prefix for ZoneId"` if `prefix` is not one of the values above.
+    ///
+    /// # Example
+    /// ```
+    /// let zone = ZoneId::of_offset("UTC", ZoneOffset::of_hours_minutes(5, 30)).unwrap();
+    /// assert_eq!(zone.id(), "UTC+05:30");
+    /// assert_eq!(ZoneId::of_offset("", ZoneOffset::of_hours(2)).unwrap().id(), "+02:00");
+    /// assert_eq!(ZoneId::of_offset("GMT", ZoneOffset::UTC).unwrap().id(), "GMT");
+    /// assert!(ZoneId::of_offset("CET", ZoneOffset::of_hours(1)).is_err());
+    /// ```
+    pub fn of_offset(prefix: &str, offset: ZoneOffset) -> Result<Self, &'static str> {
+        match PREFIXES.iter().find(|known| **known == prefix) {
+            Some(prefix) => Ok(ZoneId::prefixed(prefix, offset)),
+            None if prefix.is_empty() => Ok(ZoneId::from(offset)),
+            None => Err("Invalid prefix for ZoneId"),
+        }
+    }
+
+    /// Creates the ID of an offset after one of [`PREFIXES`].
+    fn prefixed(prefix: &'static str, offset: ZoneOffset) -> Self {
+        let id = if offset.total_seconds() == 0 {
+            Cow::Borrowed(prefix)
         } else {
-            Err("Unknown time zone")
+            Cow::Owned(format!("{prefix}{}", offset.id()))
+        };
+        ZoneId { id, fixed: Some(offset) }
+    }
+
+    /// Returns an equivalent ID that uses a `ZoneOffset`-style ID when the rules are fixed,
+    /// as `java.time.ZoneId.normalized` does.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(ZoneId::try_of("UTC+05:30").unwrap().normalized().id(), "+05:30");
+    /// assert_eq!(ZoneId::try_of("Etc/GMT+5").unwrap().normalized().id(), "-05:00");
+    /// assert_eq!(ZoneId::UTC.normalized().id(), "Z");
+    ///
+    /// let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    /// assert_eq!(paris.normalized(), paris);
+    /// ```
+    pub fn normalized(&self) -> Self {
+        match self.fixed {
+            Some(offset) => ZoneId::from(offset),
+            None => {
+                let rules = self.rules();
+                if rules.is_fixed_offset() {
+                    ZoneId::from(rules.offset(Instant::of_epoch_second(0)))
+                } else {
+                    self.clone()
+                }
+            }
         }
     }
 
+    /// Returns every region ID known to the tz database, sorted, as
+    /// `java.time.ZoneId.getAvailableZoneIds` does. Aliases such as `US/Eastern` are included;
+    /// use [`ZoneId::canonical_zone_ids`] for a list without them.
+    ///
+    /// # Example
+    /// ```
+    /// let ids = ZoneId::available_zone_ids();
+    /// assert!(ids.iter().any(|id| id == "Europe/Paris"));
+    /// assert!(ids.iter().any(|id| id == "US/Eastern"));
+    /// ```
+    pub fn available_zone_ids() -> Vec<String> {
+        let mut ids: Vec<String> = timezones::iter().map(|tz| tz.name().to_string()).collect();
+        ids.extend(zone_rules_provider::provided_zone_ids().into_iter().filter(|id| is_valid_region_id(id)));
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Returns the region IDs that are not aliases of another zone, sorted. This is the list
+    /// to offer in a time-zone picker.
+    ///
+    /// # Example
+    /// ```
+    /// let ids = ZoneId::canonical_zone_ids();
+    /// assert!(ids.iter().any(|id| id == "America/New_York"));
+    /// assert!(!ids.iter().any(|id| id == "US/Eastern"));
+    /// ```
+    pub fn canonical_zone_ids() -> Vec<String> {
+        let mut ids = Self::available_zone_ids();
+        ids.retain(|id| link(id).is_none());
+        ids
+    }
+
+    /// Returns the zone this ID is an alias of, or the ID itself if it is not an alias.
+    /// Offset-based IDs are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// let eastern = ZoneId::try_of("US/Eastern").unwrap();
+    /// assert_eq!(eastern.canonical().id(), "America/New_York");
+    /// ```
+    pub fn canonical(&self) -> Self {
+        match (self.fixed, link(&self.id)) {
+            (None, Some((_, canonical, _))) => ZoneId::try_of(canonical).expect("link to a known time zone"),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns `true` if this region ID is an alias (a tz database link) of another zone.
+    ///
+    /// # Example
+    /// ```
+    /// assert!(ZoneId::try_of("Europe/Jersey").unwrap().is_alias());
+    /// assert!(!ZoneId::try_of("Europe/London").unwrap().is_alias());
+    /// ```
+    pub fn is_alias(&self) -> bool {
+        self.fixed.is_none() && link(&self.id).is_some()
+    }
+
+    /// Returns `true` if this region ID is a deprecated alias, kept by the tz database only
+    /// for backward compatibility (such as `US/Eastern` or `Asia/Calcutta`).
+    ///
+    /// # Example
+    /// ```
+    /// assert!(ZoneId::try_of("US/Eastern").unwrap().is_deprecated());
+    /// assert!(!ZoneId::try_of("Europe/Jersey").unwrap().is_deprecated());
+    /// ```
+    pub fn is_deprecated(&self) -> bool {
+        self.fixed.is_none() && link(&self.id).is_some_and(|(_, _, deprecated)| deprecated)
+    }
+
+    /// Returns up to three known region IDs that are closest to `id`, canonical IDs first.
+    ///
+    /// Matching ignores case, treats spaces as underscores and also compares against the
+    /// last part of each ID, so `"tokyo"` suggests `Asia/Tokyo`.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(ZoneId::suggestions("US/Estern"), vec!["US/Eastern"]);
+    /// assert_eq!(ZoneId::suggestions("tokyo"), vec!["Asia/Tokyo"]);
+    /// ```
+    pub fn suggestions(id: &str) -> Vec<String> {
+        let wanted = id.trim().to_ascii_lowercase().replace(' ', "_");
+        let limit = (wanted.chars().count() / 3).max(1);
+        let mut scored: Vec<(usize, bool, String)> = Self::available_zone_ids()
+            .into_iter()
+            .filter_map(|candidate| {
+                let lower = candidate.to_ascii_lowercase();
+                let last = lower.rsplit('/').next().unwrap_or(&lower);
+                let distance = edit_distance(&wanted, &lower).min(edit_distance(&wanted, last));
+                (distance <= limit).then(|| (distance, link(&candidate).is_some(), candidate))
+            })
+            .collect();
+        scored.sort_unstable();
+        let best = scored.first().map_or(0, |(distance, _, _)| *distance);
+        scored
+            .into_iter()
+            .take_while(|(distance, _, _)| *distance == best)
+            .take(3)
+            .map(|(_, _, candidate)| candidate)
+            .collect()
+    }
+
     /// Retrieves the identifier associated with the current instance.
     ///
     /// # Returns
@@ -95,43 +393,304 @@ impl ZoneId {
     /// assert_eq!(newYork.unwrap().id(), "America/New_York");
     /// ```
     pub fn id(&self) -> &str {
-        self.0
+        &self.id
     }
 
     /// Converts the current timezone represented by the instance into its corresponding offset in seconds.
     ///
+    /// The offset is the one in effect *now*. Use [`ZoneId::offset_at_instant`] or
+    /// [`ZoneId::valid_offsets_at`] to get the offset for a specific point in time.
+    ///
     /// # Returns
     ///
     /// An `i32` value representing the offset, in seconds, from UTC for the associated timezone.
     ///
     /// # Panics
     ///
-    /// This method will panic if the timezone name stored in `self.0` does not exist
-    /// or if it cannot be resolved to a valid timezone offset using the `timezones::get_by_name` method.
+    /// Does not panic for any `ZoneId` this crate creates: a region ID is checked against the
+    /// registered providers and the compiled tz database when the `ZoneId` is created, see
+    /// [`ZoneId::rules`], and an offset-based ID always has fixed rules.
     ///
     /// # Examples
     ///
     /// ```
     /// let zone_id = ZoneId::of("America/New_York");
     /// let offset = zone_id.to_offset();
-    /// assert_eq!(offset, -5);
+    /// assert!(offset == -5 * 3600 || offset == -4 * 3600);
     /// ```
-    pub fn to_offset(self) -> i32 {
-        let tz = get_by_name(self.0).expect("unknown timezone");
-        let now_utc = OffsetDateTime::now_utc();
-        let now_tz = now_utc.to_timezone(tz);
-        now_tz.offset().whole_seconds()
+    pub fn to_offset(&self) -> i32 {
+        self.offset_at_instant(Instant::now()).total_seconds()
     }
 
-    const fn of(id: &'static str) -> Self {
-        ZoneId(id)
+    /// Returns the offset from UTC in effect in this zone at the given instant.
+    ///
+    /// Every instant has exactly one offset, so this lookup never fails.
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let january = LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant_utc();
+    /// let july = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+    /// assert_eq!(new_york.offset_at_instant(january), ZoneOffset::of_hours(-5));
+    /// assert_eq!(new_york.offset_at_instant(july), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn offset_at_instant(&self, instant: Instant) -> ZoneOffset {
+        ZoneOffset::from(self.offset_at(instant.inner()))
+    }
+
+    /// Returns the offsets that are valid for the given local date-time in this zone.
+    ///
+    /// # Returns
+    /// - One offset for a normal local date-time.
+    /// - Two offsets during an overlap (clocks set back), earlier offset first.
+    /// - No offsets during a gap (clocks set forward), where the local date-time does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let normal = new_york.valid_offsets_at(LocalDateTime::of(2024, 7, 1, 12, 0, 0));
+    /// let gap = new_york.valid_offsets_at(LocalDateTime::of(2024, 3, 10, 2, 30, 0));
+    /// let overlap = new_york.valid_offsets_at(LocalDateTime::of(2024, 11, 3, 1, 30, 0));
+    /// assert_eq!(normal, vec![ZoneOffset::of_hours(-4)]);
+    /// assert!(gap.is_empty());
+    /// assert_eq!(overlap, vec![ZoneOffset::of_hours(-4), ZoneOffset::of_hours(-5)]);
+    /// ```
+    pub fn valid_offsets_at(&self, ldt: LocalDateTime) -> Vec<ZoneOffset> {
+        self.rules().valid_offsets(ldt)
+    }
+
+    /// Returns the rules describing how the offset of this zone changes over time.
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let transitions = new_york.rules().transitions_in(Year::of(2024));
+    /// assert_eq!(transitions.len(), 2);
+    /// ```
+    pub fn rules(&self) -> ZoneRules {
+        match self.fixed {
+            Some(offset) => ZoneRules::of(offset),
+            None => zone_rules_provider::provided_rules(&self.id)
+                .unwrap_or_else(|| ZoneRules::of_tz(get_by_name(&self.id).expect("unknown timezone"))),
+        }
+    }
+
+    /// Registers a provider of zone rules. Its zones become available to [`ZoneId::try_of`],
+    /// `FromStr` and [`ZoneId::available_zone_ids`], and its rules take precedence over the
+    /// tz database compiled into this crate, and over earlier providers, for every conversion.
+    ///
+    /// # Example
+    /// ```
+    /// let provider = TzifZoneRulesProvider::from_directory("/usr/share/zoneinfo").unwrap();
+    /// ZoneId::register_provider(provider);
+    /// println!("Using tzdata {:?}", ZoneId::tzdb_version());
+    /// ```
+    pub fn register_provider(provider: impl ZoneRulesProvider + 'static) {
+        zone_rules_provider::register(Arc::new(provider));
+    }
+
+    /// Returns the version of the tz database in use, as reported by the most recently
+    /// registered provider that knows it. `time-tz` does not record the version of the tz
+    /// database compiled into this crate, so this is `None` until such a provider is
+    /// registered.
+    ///
+    /// # Example
+    /// ```
+    /// if let Some(version) = ZoneId::tzdb_version() {
+    ///     println!("tzdata {version}");
+    /// }
+    /// ```
+    pub fn tzdb_version() -> Option<String> {
+        zone_rules_provider::provided_version()
+    }
+
+    /// Returns the abbreviation of this zone at the given instant, such as `EST` in winter and
+    /// `EDT` in summer for `America/New_York`. Offset-based IDs return the ID itself.
+    ///
+    /// Some zones have no customary abbreviation, and the tz database uses their offset
+    /// instead (`+03` for `Europe/Istanbul`).
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let january = LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant_utc();
+    /// let july = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+    /// assert_eq!(new_york.abbreviation_at(january), "EST");
+    /// assert_eq!(new_york.abbreviation_at(july), "EDT");
+    /// ```
+    pub fn abbreviation_at(&self, instant: Instant) -> String {
+        match self.fixed {
+            Some(_) => self.id.to_string(),
+            None => self.rules().abbreviation(instant),
+        }
+    }
+
+    /// Returns the generic English name of this zone, which does not depend on daylight
+    /// saving time, in the style of `java.time.ZoneId.getDisplayName`.
+    ///
+    /// - Full styles give the CLDR name, such as "Eastern Time" or "Central European Time".
+    ///   Zones without one use their city ("Ulaanbaatar Time"), and offset-based IDs use
+    ///   the GMT format ("GMT+05:30").
+    /// - Short and narrow styles give the short name, such as "ET", or else the abbreviation
+    ///   of the standard time ("CET"), or else the short GMT format ("GMT+3").
+    ///
+    /// The offsets and abbreviations of the fallbacks are those in effect now, see
+    /// [`ZoneId::generic_display_name_at`] to choose the instant. Use
+    /// [`ZoneId::display_name_at`] for the name of the time in effect at an instant.
+    ///
+    /// # Example
+    /// ```
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// assert_eq!(new_york.display_name(TextStyle::Full), "Eastern Time");
+    /// assert_eq!(new_york.display_name(TextStyle::Short), "ET");
+    /// ```
+    pub fn display_name(&self, style: TextStyle) -> String {
+        self.generic_display_name_at(Instant::now(), style)
+    }
+
+    /// Returns the generic English name of this zone like [`ZoneId::display_name`], taking
+    /// the standard offset and abbreviation of the fallbacks at the given instant.
+    ///
+    /// # Example
+    /// ```
+    /// let istanbul = ZoneId::try_of("Europe/Istanbul").unwrap();
+    /// let july_2024 = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+    /// let july_2015 = LocalDateTime::of(2015, 7, 15, 12, 0, 0).to_instant_utc();
+    /// assert_eq!(istanbul.generic_display_name_at(july_2024, TextStyle::Short), "GMT+3");
+    /// assert_eq!(istanbul.generic_display_name_at(july_2015, TextStyle::Short), "EET");
+    /// ```
+    pub fn generic_display_name_at(&self, instant: Instant, style: TextStyle) -> String {
+        if let Some(offset) = self.fixed {
+            return self.fixed_display_name(offset, style);
+        }
+        let names = zone_names(self.canonical().id());
+        if style.as_normal() == TextStyle::Full {
+            return match names {
+                Some(names) => names.long.generic.to_string(),
+                None if self.id.contains('/') && !self.id.starts_with("Etc/") => {
+                    let city = self.id.rsplit('/').next().unwrap_or(&self.id);
+                    format!("{} Time", city.replace('_', " "))
+                }
+                None => localized_offset(self.rules().standard_offset(instant), true),
+            };
+        }
+        if let Some(short) = names.and_then(|names| names.short) {
+            return short.generic.to_string();
+        }
+        let rules = self.rules();
+        let mut instant = instant;
+        if rules.is_daylight_savings(instant)
+            && let Some(transition) = rules.previous_transition(instant)
+        {
+            instant = transition.instant().minus_seconds(1);
+        }
+        short_name(rules.abbreviation(instant), rules.offset(instant))
+    }
+
+    /// Returns the English name of the time in effect in this zone at the given instant,
+    /// which tells standard and daylight saving time apart.
+    ///
+    /// - Full styles give the CLDR name, such as "Eastern Standard Time" or "Central European
+    ///   Summer Time", or else the GMT format ("GMT+03:00").
+    /// - Short and narrow styles give the abbreviation, such as "EDT", or else the short GMT
+    ///   format ("GMT+3").
+    ///
+    /// Names are those of the zone's current metazone, which may differ from the names used
+    /// in the past. Offset-based IDs give the same name as [`ZoneId::display_name`].
+    ///
+    /// # Example
+    /// ```
+    /// let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    /// let july = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+    /// assert_eq!(paris.display_name_at(july, TextStyle::Full), "Central European Summer Time");
+    /// assert_eq!(paris.display_name_at(july, TextStyle::Short), "CEST");
+    /// ```
+    pub fn display_name_at(&self, instant: Instant, style: TextStyle) -> String {
+        if let Some(offset) = self.fixed {
+            return self.fixed_display_name(offset, style);
+        }
+        let rules = self.rules();
+        if style.as_normal() != TextStyle::Full {
+            return short_name(rules.abbreviation(instant), rules.offset(instant));
+        }
+        let canonical = self.canonical();
+        let canonical = canonical.id();
+        match zone_names(canonical) {
+            Some(names) if rules.is_daylight_savings(instant) => {
+                DAYLIGHT_OVERRIDES
+                    .iter()
+                    .find(|(zone, _)| *zone == canonical)
+                    .map_or(names.long.daylight, |(_, name)| name)
+                    .to_string()
+            }
+            Some(names) => names.long.standard.to_string(),
+            None => localized_offset(rules.offset(instant), true),
+        }
+    }
+
+    fn fixed_display_name(&self, offset: ZoneOffset, style: TextStyle) -> String {
+        let full = style.as_normal() == TextStyle::Full;
+        match (self.id(), full) {
+            ("GMT", true) => "Greenwich Mean Time".to_string(),
+            ("UTC" | "UT" | "Z", true) => "Coordinated Universal Time".to_string(),
+            ("Z", false) => "UTC".to_string(),
+            ("GMT" | "UTC" | "UT", false) => self.id.to_string(),
+            _ => localized_offset(offset, full),
+        }
+    }
+
+    /// Returns the offset in effect in this zone at the given instant.
+    pub(crate) fn offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        self.rules().offset_at(instant)
+    }
+
+    /// Converts a local date-time in this zone to an offset date-time, the way
+    /// `java.time.ZonedDateTime.ofLocal` does.
+    ///
+    /// - If the local date-time has exactly one valid offset, that offset is used.
+    /// - In an overlap (clocks set back) the `preferred` offset is kept if it is one of
+    ///   the two valid offsets, otherwise the earlier offset is used.
+    /// - In a gap (clocks set forward) the local date-time is shifted later by the
+    ///   length of the gap and the offset after the transition is used.
+    ///
+    /// Panics if shifting out of a gap passes the end of the supported range; see
+    /// [`ZoneResolver::resolve`](crate::ZoneResolver::resolve) for an error instead.
+    pub(crate) fn resolve_local(&self, local: PrimitiveDateTime, preferred: Option<UtcOffset>) -> OffsetDateTime {
+        match self.valid_offsets_at(LocalDateTime::from(local)).as_slice() {
+            [offset] => local.assume_offset(offset.inner()),
+            [earlier, later] => {
+                let offset = match preferred {
+                    Some(p) if p == later.inner() => later,
+                    _ => earlier,
+                };
+                local.assume_offset(offset.inner())
+            }
+            _ => {
+                let transition = self
+                    .rules()
+                    .transition(LocalDateTime::from(local))
+                    .expect("a local date-time without valid offsets lies in a gap");
+                local
+                    .assume_offset(transition.offset_before().inner())
+                    .checked_to_offset(transition.offset_after().inner())
+                    .expect("Local date-time exceeds the supported range")
+            }
+        }
+    }
+}
+
+/// Formats the zone as its ID, such as `Europe/Paris` or `+05:30`.
+impl fmt::Display for ZoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id)
     }
 }
 
 impl FromStr for ZoneId {
     type Err = &'static str;
 
-    /// Parse a string slice into a `ZoneId`.
+    /// Parse a string slice into a `ZoneId`, accepting the same IDs as [`ZoneId::try_of`].
+    /// Use [`ZoneId::try_parse`] for an error that suggests close matches.
     ///
     /// # Example
     /// ```rust
@@ -141,12 +700,162 @@ impl FromStr for ZoneId {
     /// assert_eq!(z.id(), "America/New_York");
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(tz) = get_by_name(s) {
-            Ok(ZoneId(tz.name()))
-        } else {
-            Err("Unknown time zone")
+        ZoneId::try_of(s)
+    }
+}
+
+/// The error returned by [`ZoneId::try_parse`].
+///
+/// Besides the reason, it keeps the rejected input and, for unknown region IDs, the closest
+/// known IDs so callers can offer "did you mean" hints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneIdParseError {
+    input: String,
+    reason: &'static str,
+    suggestions: Vec<String>,
+}
+
+impl ZoneIdParseError {
+    /// Returns the text that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns why parsing failed, with the same message [`ZoneId::try_of`] returns.
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+
+    /// Returns known region IDs close to the input, best match first. Empty unless the
+    /// input looked like a region ID that does not exist.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+impl fmt::Display for ZoneIdParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} \"{}\"", self.reason, self.input)?;
+        if let Some((last, rest)) = self.suggestions.split_last() {
+            write!(f, "; did you mean ")?;
+            for suggestion in rest {
+                write!(f, "\"{suggestion}\", ")?;
+            }
+            if !rest.is_empty() {
+                write!(f, "or ")?;
+            }
+            write!(f, "\"{last}\"?")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ZoneIdParseError {}
+
+impl From<ZoneOffset> for ZoneId {
+    /// Converts an offset into an offset-based `ZoneId` with fixed rules, such as `+05:30`.
+    fn from(offset: ZoneOffset) -> Self {
+        ZoneId { id: Cow::Owned(offset.id()), fixed: Some(offset) }
+    }
+}
+
+/// Checks the region ID syntax of `java.time`: `[A-Za-z][A-Za-z0-9~/._+-]+`.
+fn is_valid_region_id(id: &str) -> bool {
+    let mut bytes = id.bytes();
+    id.len() >= 2
+        && bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b"~/._+-".contains(&b))
+}
+
+/// Looks up the tz database link whose alias is `id`.
+fn link(id: &str) -> Option<Link> {
+    let links = zone_links::links();
+    links.binary_search_by(|(alias, _, _)| (*alias).cmp(id)).ok().map(|index| links[index])
+}
+
+/// Returns the zone named by a path into a zoneinfo directory, such as
+/// `/usr/share/zoneinfo/posix/Europe/Paris`.
+fn zone_from_path(path: &Path) -> Option<ZoneId> {
+    let path = path.to_str()?;
+    let (_, id) = path.rsplit_once("zoneinfo/")?;
+    let id = id.strip_prefix("posix/").or_else(|| id.strip_prefix("right/")).unwrap_or(id);
+    ZoneId::try_of(id).ok()
+}
+
+/// Looks up the CLDR metazone of a canonical region ID and returns its names.
+fn zone_names(id: &str) -> Option<MetazoneNames> {
+    let index = ZONE_METAZONES.binary_search_by(|(zone, _)| (*zone).cmp(id)).ok()?;
+    let metazone = ZONE_METAZONES[index].1;
+    METAZONES.binary_search_by(|names| names.metazone.cmp(metazone)).ok().map(|index| METAZONES[index])
+}
+
+/// Formats an offset in the CLDR localized GMT format: `GMT+05:30` when `full`, `GMT+5:30`
+/// otherwise, and `GMT` for a zero offset.
+pub(crate) fn localized_offset(offset: ZoneOffset, full: bool) -> String {
+    let total = offset.total_seconds();
+    if total == 0 {
+        return "GMT".to_string();
+    }
+    let sign = if total < 0 { '-' } else { '+' };
+    let (hours, minutes, seconds) = (total.abs() / 3600, total.abs() / 60 % 60, total.abs() % 60);
+    let mut text = if full { format!("GMT{sign}{hours:02}:{minutes:02}") } else { format!("GMT{sign}{hours}") };
+    if !full && (minutes != 0 || seconds != 0) {
+        text.push_str(&format!(":{minutes:02}"));
+    }
+    if seconds != 0 {
+        text.push_str(&format!(":{seconds:02}"));
+    }
+    text
+}
+
+/// Matches the start of `text` against the English zone names and abbreviations, ignoring
+/// case, and returns the longest match: its zone, whether the name was the daylight
+/// (`Some(true)`), standard (`Some(false)`) or generic (`None`) name, and its length.
+pub(crate) fn parse_zone_name(text: &str) -> Option<(ZoneId, Option<bool>, usize)> {
+    let mut candidates: Vec<(&str, &str, Option<bool>)> = Vec::new();
+    for names in METAZONES {
+        for width in std::iter::once(names.long).chain(names.short) {
+            candidates.extend([(width.generic, names.zone, None), (width.standard, names.zone, Some(false)), (width.daylight, names.zone, Some(true))]);
+        }
+    }
+    candidates.extend(DAYLIGHT_OVERRIDES.iter().map(|&(zone, daylight)| (daylight, zone, Some(true))));
+    candidates.extend(ABBREVIATIONS.iter().map(|&(abbreviation, zone, daylight)| (abbreviation, zone, Some(daylight))));
+    candidates
+        .into_iter()
+        .filter(|(name, _, _)| text.get(..name.len()).is_some_and(|start| start.eq_ignore_ascii_case(name)))
+        .max_by_key(|(name, _, _)| name.len())
+        .and_then(|(name, zone, daylight)| ZoneId::try_of(zone).ok().map(|zone| (zone, daylight, name.len())))
+}
+
+/// Returns the abbreviation if it is a name such as `EST`, or the short GMT format if the
+/// tz database only has a numeric abbreviation such as `+03`.
+fn short_name(abbreviation: String, offset: ZoneOffset) -> String {
+    if abbreviation.chars().all(|c| c.is_ascii_alphabetic()) && !abbreviation.is_empty() {
+        abbreviation
+    } else {
+        localized_offset(offset, false)
+    }
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and swaps of
+/// adjacent characters each cost one.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let width = b.len() + 1;
+    let mut d: Vec<usize> = (0..(a.len() + 1) * width).map(|i| if i < width { i } else { 0 }).collect();
+    for i in 1..=a.len() {
+        d[i * width] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (d[(i - 1) * width + j] + 1).min(d[i * width + j - 1] + 1).min(d[(i - 1) * width + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(d[(i - 2) * width + j - 2] + 1);
+            }
+            d[i * width + j] = best;
         }
     }
+    d[a.len() * width + b.len()]
 }
 
 #[cfg(feature = "serde")]
@@ -155,7 +864,7 @@ impl Serialize for ZoneId {
     where
         S: Serializer,
     {
-        serializer.serialize_str(self.0)
+        serializer.serialize_str(&self.id)
     }
 }
 
@@ -0,0 +1,261 @@
+//! The `Link` lines of the tz database, which the zone data generated by `time-tz` does not
+//! keep: a link becomes a zone of its own, with a copy of the data of its target.
+//!
+//! Each entry is `(alias, canonical, deprecated)`, sorted by alias. Links from the
+//! `backward` file only exist for backward compatibility and are flagged as deprecated.
+//! Entries are checked against the compiled data before use, see [`links`].
+
+use std::sync::OnceLock;
+use time::OffsetDateTime;
+use time_tz::timezones::get_by_name;
+use time_tz::{Offset, TimeZone};
+
+/// An alias, the zone it links to and whether it is deprecated.
+pub(crate) type Link = (&'static str, &'static str, bool);
+
+const LINKS: &[Link] = &[
+    ("Africa/Accra", "Africa/Abidjan", false),
+    ("Africa/Addis_Ababa", "Africa/Nairobi", false),
+    ("Africa/Asmara", "Africa/Nairobi", false),
+    ("Africa/Asmera", "Africa/Nairobi", true),
+    ("Africa/Bamako", "Africa/Abidjan", false),
+    ("Africa/Bangui", "Africa/Lagos", false),
+    ("Africa/Banjul", "Africa/Abidjan", false),
+    ("Africa/Blantyre", "Africa/Maputo", false),
+    ("Africa/Brazzaville", "Africa/Lagos", false),
+    ("Africa/Bujumbura", "Africa/Maputo", false),
+    ("Africa/Conakry", "Africa/Abidjan", false),
+    ("Africa/Dakar", "Africa/Abidjan", false),
+    ("Africa/Dar_es_Salaam", "Africa/Nairobi", false),
+    ("Africa/Djibouti", "Africa/Nairobi", false),
+    ("Africa/Douala", "Africa/Lagos", false),
+    ("Africa/Freetown", "Africa/Abidjan", false),
+    ("Africa/Gaborone", "Africa/Maputo", false),
+    ("Africa/Harare", "Africa/Maputo", false),
+    ("Africa/Kampala", "Africa/Nairobi", false),
+    ("Africa/Kigali", "Africa/Maputo", false),
+    ("Africa/Kinshasa", "Africa/Lagos", false),
+    ("Africa/Libreville", "Africa/Lagos", false),
+    ("Africa/Lome", "Africa/Abidjan", false),
+    ("Africa/Luanda", "Africa/Lagos", false),
+    ("Africa/Lubumbashi", "Africa/Maputo", false),
+    ("Africa/Lusaka", "Africa/Maputo", false),
+    ("Africa/Malabo", "Africa/Lagos", false),
+    ("Africa/Maseru", "Africa/Johannesburg", false),
+    ("Africa/Mbabane", "Africa/Johannesburg", false),
+    ("Africa/Mogadishu", "Africa/Nairobi", false),
+    ("Africa/Niamey", "Africa/Lagos", false),
+    ("Africa/Nouakchott", "Africa/Abidjan", false),
+    ("Africa/Ouagadougou", "Africa/Abidjan", false),
+    ("Africa/Porto-Novo", "Africa/Lagos", false),
+    ("Africa/Timbuktu", "Africa/Abidjan", true),
+    ("America/Anguilla", "America/Puerto_Rico", false),
+    ("America/Antigua", "America/Puerto_Rico", false),
+    ("America/Argentina/ComodRivadavia", "America/Argentina/Catamarca", true),
+    ("America/Aruba", "America/Puerto_Rico", false),
+    ("America/Atikokan", "America/Panama", false),
+    ("America/Atka", "America/Adak", true),
+    ("America/Blanc-Sablon", "America/Puerto_Rico", false),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires", true),
+    ("America/Catamarca", "America/Argentina/Catamarca", true),
+    ("America/Cayman", "America/Panama", false),
+    ("America/Coral_Harbour", "America/Panama", true),
+    ("America/Cordoba", "America/Argentina/Cordoba", true),
+    ("America/Creston", "America/Phoenix", false),
+    ("America/Curacao", "America/Puerto_Rico", false),
+    ("America/Dominica", "America/Puerto_Rico", false),
+    ("America/Ensenada", "America/Tijuana", true),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis", true),
+    ("America/Godthab", "America/Nuuk", true),
+    ("America/Grenada", "America/Puerto_Rico", false),
+    ("America/Guadeloupe", "America/Puerto_Rico", false),
+    ("America/Indianapolis", "America/Indiana/Indianapolis", true),
+    ("America/Jujuy", "America/Argentina/Jujuy", true),
+    ("America/Knox_IN", "America/Indiana/Knox", true),
+    ("America/Kralendijk", "America/Puerto_Rico", false),
+    ("America/Louisville", "America/Kentucky/Louisville", true),
+    ("America/Lower_Princes", "America/Puerto_Rico", false),
+    ("America/Marigot", "America/Puerto_Rico", false),
+    ("America/Mendoza", "America/Argentina/Mendoza", true),
+    ("America/Montreal", "America/Toronto", true),
+    ("America/Montserrat", "America/Puerto_Rico", false),
+    ("America/Nassau", "America/Toronto", false),
+    ("America/Port_of_Spain", "America/Puerto_Rico", false),
+    ("America/Porto_Acre", "America/Rio_Branco", true),
+    ("America/Rosario", "America/Argentina/Cordoba", true),
+    ("America/Santa_Isabel", "America/Tijuana", true),
+    ("America/Shiprock", "America/Denver", true),
+    ("America/St_Barthelemy", "America/Puerto_Rico", false),
+    ("America/St_Kitts", "America/Puerto_Rico", false),
+    ("America/St_Lucia", "America/Puerto_Rico", false),
+    ("America/St_Thomas", "America/Puerto_Rico", false),
+    ("America/St_Vincent", "America/Puerto_Rico", false),
+    ("America/Tortola", "America/Puerto_Rico", false),
+    ("America/Virgin", "America/Puerto_Rico", true),
+    ("Antarctica/DumontDUrville", "Pacific/Port_Moresby", false),
+    ("Antarctica/McMurdo", "Pacific/Auckland", false),
+    ("Antarctica/South_Pole", "Pacific/Auckland", true),
+    ("Antarctica/Syowa", "Asia/Riyadh", false),
+    ("Arctic/Longyearbyen", "Europe/Oslo", false),
+    ("Asia/Aden", "Asia/Riyadh", false),
+    ("Asia/Ashkhabad", "Asia/Ashgabat", true),
+    ("Asia/Bahrain", "Asia/Qatar", false),
+    ("Asia/Calcutta", "Asia/Kolkata", true),
+    ("Asia/Chongqing", "Asia/Shanghai", true),
+    ("Asia/Chungking", "Asia/Shanghai", true),
+    ("Asia/Dacca", "Asia/Dhaka", true),
+    ("Asia/Harbin", "Asia/Shanghai", true),
+    ("Asia/Istanbul", "Europe/Istanbul", false),
+    ("Asia/Kashgar", "Asia/Urumqi", true),
+    ("Asia/Katmandu", "Asia/Kathmandu", true),
+    ("Asia/Kuwait", "Asia/Riyadh", false),
+    ("Asia/Macao", "Asia/Macau", true),
+    ("Asia/Muscat", "Asia/Dubai", false),
+    ("Asia/Phnom_Penh", "Asia/Bangkok", false),
+    ("Asia/Rangoon", "Asia/Yangon", true),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh", true),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem", true),
+    ("Asia/Thimbu", "Asia/Thimphu", true),
+    ("Asia/Ujung_Pandang", "Asia/Makassar", true),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar", true),
+    ("Asia/Vientiane", "Asia/Bangkok", false),
+    ("Atlantic/Faeroe", "Atlantic/Faroe", true),
+    ("Atlantic/Jan_Mayen", "Europe/Oslo", true),
+    ("Atlantic/St_Helena", "Africa/Abidjan", false),
+    ("Australia/ACT", "Australia/Sydney", true),
+    ("Australia/Canberra", "Australia/Sydney", true),
+    ("Australia/Currie", "Australia/Hobart", true),
+    ("Australia/LHI", "Australia/Lord_Howe", true),
+    ("Australia/NSW", "Australia/Sydney", true),
+    ("Australia/North", "Australia/Darwin", true),
+    ("Australia/Queensland", "Australia/Brisbane", true),
+    ("Australia/South", "Australia/Adelaide", true),
+    ("Australia/Tasmania", "Australia/Hobart", true),
+    ("Australia/Victoria", "Australia/Melbourne", true),
+    ("Australia/West", "Australia/Perth", true),
+    ("Australia/Yancowinna", "Australia/Broken_Hill", true),
+    ("Brazil/Acre", "America/Rio_Branco", true),
+    ("Brazil/DeNoronha", "America/Noronha", true),
+    ("Brazil/East", "America/Sao_Paulo", true),
+    ("Brazil/West", "America/Manaus", true),
+    ("Canada/Atlantic", "America/Halifax", true),
+    ("Canada/Central", "America/Winnipeg", true),
+    ("Canada/Eastern", "America/Toronto", true),
+    ("Canada/Mountain", "America/Edmonton", true),
+    ("Canada/Newfoundland", "America/St_Johns", true),
+    ("Canada/Pacific", "America/Vancouver", true),
+    ("Canada/Saskatchewan", "America/Regina", true),
+    ("Canada/Yukon", "America/Whitehorse", true),
+    ("Chile/Continental", "America/Santiago", true),
+    ("Chile/EasterIsland", "Pacific/Easter", true),
+    ("Cuba", "America/Havana", true),
+    ("Egypt", "Africa/Cairo", true),
+    ("Eire", "Europe/Dublin", true),
+    ("Etc/GMT+0", "Etc/GMT", false),
+    ("Etc/GMT-0", "Etc/GMT", false),
+    ("Etc/GMT0", "Etc/GMT", false),
+    ("Etc/Greenwich", "Etc/GMT", false),
+    ("Etc/UCT", "Etc/UTC", true),
+    ("Etc/Universal", "Etc/UTC", false),
+    ("Etc/Zulu", "Etc/UTC", false),
+    ("Europe/Belfast", "Europe/London", true),
+    ("Europe/Bratislava", "Europe/Prague", false),
+    ("Europe/Busingen", "Europe/Zurich", false),
+    ("Europe/Guernsey", "Europe/London", false),
+    ("Europe/Isle_of_Man", "Europe/London", false),
+    ("Europe/Jersey", "Europe/London", false),
+    ("Europe/Kiev", "Europe/Kyiv", true),
+    ("Europe/Ljubljana", "Europe/Belgrade", false),
+    ("Europe/Mariehamn", "Europe/Helsinki", false),
+    ("Europe/Nicosia", "Asia/Nicosia", false),
+    ("Europe/Podgorica", "Europe/Belgrade", false),
+    ("Europe/San_Marino", "Europe/Rome", false),
+    ("Europe/Sarajevo", "Europe/Belgrade", false),
+    ("Europe/Skopje", "Europe/Belgrade", false),
+    ("Europe/Tiraspol", "Europe/Chisinau", true),
+    ("Europe/Vaduz", "Europe/Zurich", false),
+    ("Europe/Vatican", "Europe/Rome", false),
+    ("Europe/Zagreb", "Europe/Belgrade", false),
+    ("GB", "Europe/London", true),
+    ("GB-Eire", "Europe/London", true),
+    ("GMT", "Etc/GMT", false),
+    ("GMT+0", "Etc/GMT", true),
+    ("GMT-0", "Etc/GMT", true),
+    ("GMT0", "Etc/GMT", true),
+    ("Greenwich", "Etc/GMT", true),
+    ("Hongkong", "Asia/Hong_Kong", true),
+    ("Iceland", "Atlantic/Reykjavik", true),
+    ("Indian/Antananarivo", "Africa/Nairobi", false),
+    ("Indian/Comoro", "Africa/Nairobi", false),
+    ("Indian/Mayotte", "Africa/Nairobi", false),
+    ("Iran", "Asia/Tehran", true),
+    ("Israel", "Asia/Jerusalem", true),
+    ("Jamaica", "America/Jamaica", true),
+    ("Japan", "Asia/Tokyo", true),
+    ("Kwajalein", "Pacific/Kwajalein", true),
+    ("Libya", "Africa/Tripoli", true),
+    ("Mexico/BajaNorte", "America/Tijuana", true),
+    ("Mexico/BajaSur", "America/Mazatlan", true),
+    ("Mexico/General", "America/Mexico_City", true),
+    ("NZ", "Pacific/Auckland", true),
+    ("NZ-CHAT", "Pacific/Chatham", true),
+    ("Navajo", "America/Denver", true),
+    ("PRC", "Asia/Shanghai", true),
+    ("Pacific/Enderbury", "Pacific/Kanton", true),
+    ("Pacific/Johnston", "Pacific/Honolulu", true),
+    ("Pacific/Midway", "Pacific/Pago_Pago", false),
+    ("Pacific/Ponape", "Pacific/Pohnpei", true),
+    ("Pacific/Saipan", "Pacific/Guam", false),
+    ("Pacific/Samoa", "Pacific/Pago_Pago", true),
+    ("Pacific/Truk", "Pacific/Chuuk", true),
+    ("Pacific/Yap", "Pacific/Chuuk", true),
+    ("Poland", "Europe/Warsaw", true),
+    ("Portugal", "Europe/Lisbon", true),
+    ("ROC", "Asia/Taipei", true),
+    ("ROK", "Asia/Seoul", true),
+    ("Singapore", "Asia/Singapore", true),
+    ("Turkey", "Europe/Istanbul", true),
+    ("UCT", "Etc/UTC", true),
+    ("US/Alaska", "America/Anchorage", true),
+    ("US/Aleutian", "America/Adak", true),
+    ("US/Arizona", "America/Phoenix", true),
+    ("US/Central", "America/Chicago", true),
+    ("US/East-Indiana", "America/Indiana/Indianapolis", true),
+    ("US/Eastern", "America/New_York", true),
+    ("US/Hawaii", "Pacific/Honolulu", true),
+    ("US/Indiana-Starke", "America/Indiana/Knox", true),
+    ("US/Michigan", "America/Detroit", true),
+    ("US/Mountain", "America/Denver", true),
+    ("US/Pacific", "America/Los_Angeles", true),
+    ("US/Samoa", "Pacific/Pago_Pago", true),
+    ("UTC", "Etc/UTC", true),
+    ("Universal", "Etc/UTC", true),
+    ("W-SU", "Europe/Moscow", true),
+    ("Zulu", "Etc/UTC", true),
+];
+
+/// Returns the entries of [`LINKS`] whose alias and target have the same offsets in the tz
+/// database compiled into `time-tz`, sorted by alias. A link that a later tz release turns
+/// into a zone of its own is left out instead of being reported as an alias.
+pub(crate) fn links() -> &'static [Link] {
+    static CHECKED: OnceLock<Vec<Link>> = OnceLock::new();
+    CHECKED.get_or_init(|| LINKS.iter().copied().filter(|(alias, canonical, _)| same_data(alias, canonical)).collect())
+}
+
+/// Returns `true` if the alias and the canonical zone have the same offset, abbreviation and
+/// daylight saving time at instants thirty days apart from 1800 to 2100, which spans the
+/// transitions of the compiled data.
+fn same_data(alias: &str, canonical: &str) -> bool {
+    /// 1800-01-01T00:00Z and 2100-01-01T00:00Z.
+    const START: i64 = -5_364_662_400;
+    const END: i64 = 4_102_444_800;
+    const STEP: usize = 30 * 86_400;
+    let (Some(alias), Some(canonical)) = (get_by_name(alias), get_by_name(canonical)) else {
+        return false;
+    };
+    (START..END).step_by(STEP).all(|epoch_second| {
+        let instant = OffsetDateTime::from_unix_timestamp(epoch_second).expect("within the supported range");
+        let (alias, canonical) = (alias.get_offset_utc(&instant), canonical.get_offset_utc(&instant));
+        (alias.to_utc(), alias.name(), alias.is_dst()) == (canonical.to_utc(), canonical.name(), canonical.is_dst())
+    })
+}
@@ -0,0 +1,698 @@
+//! English time-zone names from CLDR: the metazone each zone belongs to and the generic,
+//! standard and daylight names of each metazone.
+//!
+//! The names come from CLDR 44, `common/main/en.xml`, and the metazones from its
+//! `common/supplemental/metaZones.xml`, keeping only the metazones in use today. The tables
+//! cover the metazones of the most used zones; other zones fall back to their city or GMT
+//! offset.
+
+/// The names of a metazone in one width, as in the `<long>` and `<short>` elements of CLDR.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Names {
+    pub(crate) generic: &'static str,
+    pub(crate) standard: &'static str,
+    pub(crate) daylight: &'static str,
+}
+
+/// The English names of a CLDR metazone.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MetazoneNames {
+    pub(crate) metazone: &'static str,
+    /// The zone a parsed name resolves to.
+    pub(crate) zone: &'static str,
+    pub(crate) long: Names,
+    /// CLDR only has short English names for a few metazones, such as `ET`, `EST` and `EDT`.
+    pub(crate) short: Option<Names>,
+}
+
+/// The names of each metazone, sorted by metazone.
+pub(crate) const METAZONES: &[MetazoneNames] = &[
+    MetazoneNames {
+        metazone: "Acre",
+        zone: "America/Rio_Branco",
+        long: Names { generic: "Acre Time", standard: "Acre Standard Time", daylight: "Acre Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Africa_Central",
+        zone: "Africa/Maputo",
+        long: Names { generic: "Central Africa Time", standard: "Central Africa Time", daylight: "Central Africa Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Africa_Eastern",
+        zone: "Africa/Nairobi",
+        long: Names { generic: "East Africa Time", standard: "East Africa Time", daylight: "East Africa Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Africa_Southern",
+        zone: "Africa/Johannesburg",
+        long: Names { generic: "South Africa Standard Time", standard: "South Africa Standard Time", daylight: "South Africa Standard Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Africa_Western",
+        zone: "Africa/Lagos",
+        long: Names { generic: "West Africa Time", standard: "West Africa Standard Time", daylight: "West Africa Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Alaska",
+        zone: "America/Anchorage",
+        long: Names { generic: "Alaska Time", standard: "Alaska Standard Time", daylight: "Alaska Daylight Time" },
+        short: Some(Names { generic: "AKT", standard: "AKST", daylight: "AKDT" }),
+    },
+    MetazoneNames {
+        metazone: "Amazon",
+        zone: "America/Manaus",
+        long: Names { generic: "Amazon Time", standard: "Amazon Standard Time", daylight: "Amazon Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "America_Central",
+        zone: "America/Chicago",
+        long: Names { generic: "Central Time", standard: "Central Standard Time", daylight: "Central Daylight Time" },
+        short: Some(Names { generic: "CT", standard: "CST", daylight: "CDT" }),
+    },
+    MetazoneNames {
+        metazone: "America_Eastern",
+        zone: "America/New_York",
+        long: Names { generic: "Eastern Time", standard: "Eastern Standard Time", daylight: "Eastern Daylight Time" },
+        short: Some(Names { generic: "ET", standard: "EST", daylight: "EDT" }),
+    },
+    MetazoneNames {
+        metazone: "America_Mountain",
+        zone: "America/Denver",
+        long: Names { generic: "Mountain Time", standard: "Mountain Standard Time", daylight: "Mountain Daylight Time" },
+        short: Some(Names { generic: "MT", standard: "MST", daylight: "MDT" }),
+    },
+    MetazoneNames {
+        metazone: "America_Pacific",
+        zone: "America/Los_Angeles",
+        long: Names { generic: "Pacific Time", standard: "Pacific Standard Time", daylight: "Pacific Daylight Time" },
+        short: Some(Names { generic: "PT", standard: "PST", daylight: "PDT" }),
+    },
+    MetazoneNames {
+        metazone: "Arabian",
+        zone: "Asia/Riyadh",
+        long: Names { generic: "Arabian Time", standard: "Arabian Standard Time", daylight: "Arabian Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Argentina",
+        zone: "America/Argentina/Buenos_Aires",
+        long: Names { generic: "Argentina Time", standard: "Argentina Standard Time", daylight: "Argentina Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Atlantic",
+        zone: "America/Halifax",
+        long: Names { generic: "Atlantic Time", standard: "Atlantic Standard Time", daylight: "Atlantic Daylight Time" },
+        short: Some(Names { generic: "AT", standard: "AST", daylight: "ADT" }),
+    },
+    MetazoneNames {
+        metazone: "Australia_Central",
+        zone: "Australia/Adelaide",
+        long: Names { generic: "Central Australia Time", standard: "Australian Central Standard Time", daylight: "Australian Central Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Australia_Eastern",
+        zone: "Australia/Sydney",
+        long: Names { generic: "Eastern Australia Time", standard: "Australian Eastern Standard Time", daylight: "Australian Eastern Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Australia_Western",
+        zone: "Australia/Perth",
+        long: Names { generic: "Western Australia Time", standard: "Australian Western Standard Time", daylight: "Australian Western Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Bangladesh",
+        zone: "Asia/Dhaka",
+        long: Names { generic: "Bangladesh Time", standard: "Bangladesh Standard Time", daylight: "Bangladesh Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Brasilia",
+        zone: "America/Sao_Paulo",
+        long: Names { generic: "Brasilia Time", standard: "Brasilia Standard Time", daylight: "Brasilia Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Chile",
+        zone: "America/Santiago",
+        long: Names { generic: "Chile Time", standard: "Chile Standard Time", daylight: "Chile Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "China",
+        zone: "Asia/Shanghai",
+        long: Names { generic: "China Time", standard: "China Standard Time", daylight: "China Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Colombia",
+        zone: "America/Bogota",
+        long: Names { generic: "Colombia Time", standard: "Colombia Standard Time", daylight: "Colombia Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Cuba",
+        zone: "America/Havana",
+        long: Names { generic: "Cuba Time", standard: "Cuba Standard Time", daylight: "Cuba Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Europe_Central",
+        zone: "Europe/Paris",
+        long: Names { generic: "Central European Time", standard: "Central European Standard Time", daylight: "Central European Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Europe_Eastern",
+        zone: "Europe/Bucharest",
+        long: Names { generic: "Eastern European Time", standard: "Eastern European Standard Time", daylight: "Eastern European Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Europe_Western",
+        zone: "Europe/Lisbon",
+        long: Names { generic: "Western European Time", standard: "Western European Standard Time", daylight: "Western European Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "GMT",
+        zone: "Europe/London",
+        long: Names { generic: "Greenwich Mean Time", standard: "Greenwich Mean Time", daylight: "Greenwich Mean Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Gulf",
+        zone: "Asia/Dubai",
+        long: Names { generic: "Gulf Standard Time", standard: "Gulf Standard Time", daylight: "Gulf Standard Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Hawaii_Aleutian",
+        zone: "Pacific/Honolulu",
+        long: Names { generic: "Hawaii-Aleutian Time", standard: "Hawaii-Aleutian Standard Time", daylight: "Hawaii-Aleutian Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Hong_Kong",
+        zone: "Asia/Hong_Kong",
+        long: Names { generic: "Hong Kong Time", standard: "Hong Kong Standard Time", daylight: "Hong Kong Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "India",
+        zone: "Asia/Kolkata",
+        long: Names { generic: "India Standard Time", standard: "India Standard Time", daylight: "India Standard Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Indochina",
+        zone: "Asia/Bangkok",
+        long: Names { generic: "Indochina Time", standard: "Indochina Time", daylight: "Indochina Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Indonesia_Central",
+        zone: "Asia/Makassar",
+        long: Names { generic: "Central Indonesia Time", standard: "Central Indonesia Time", daylight: "Central Indonesia Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Indonesia_Eastern",
+        zone: "Asia/Jayapura",
+        long: Names { generic: "Eastern Indonesia Time", standard: "Eastern Indonesia Time", daylight: "Eastern Indonesia Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Indonesia_Western",
+        zone: "Asia/Jakarta",
+        long: Names { generic: "Western Indonesia Time", standard: "Western Indonesia Time", daylight: "Western Indonesia Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Iran",
+        zone: "Asia/Tehran",
+        long: Names { generic: "Iran Time", standard: "Iran Standard Time", daylight: "Iran Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Israel",
+        zone: "Asia/Jerusalem",
+        long: Names { generic: "Israel Time", standard: "Israel Standard Time", daylight: "Israel Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Japan",
+        zone: "Asia/Tokyo",
+        long: Names { generic: "Japan Time", standard: "Japan Standard Time", daylight: "Japan Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Korea",
+        zone: "Asia/Seoul",
+        long: Names { generic: "Korean Time", standard: "Korean Standard Time", daylight: "Korean Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Lord_Howe",
+        zone: "Australia/Lord_Howe",
+        long: Names { generic: "Lord Howe Time", standard: "Lord Howe Standard Time", daylight: "Lord Howe Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Malaysia",
+        zone: "Asia/Kuala_Lumpur",
+        long: Names { generic: "Malaysia Time", standard: "Malaysia Time", daylight: "Malaysia Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Mexico_Pacific",
+        zone: "America/Mazatlan",
+        long: Names { generic: "Mexican Pacific Time", standard: "Mexican Pacific Standard Time", daylight: "Mexican Pacific Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Moscow",
+        zone: "Europe/Moscow",
+        long: Names { generic: "Moscow Time", standard: "Moscow Standard Time", daylight: "Moscow Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Nepal",
+        zone: "Asia/Kathmandu",
+        long: Names { generic: "Nepal Time", standard: "Nepal Time", daylight: "Nepal Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "New_Zealand",
+        zone: "Pacific/Auckland",
+        long: Names { generic: "New Zealand Time", standard: "New Zealand Standard Time", daylight: "New Zealand Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Newfoundland",
+        zone: "America/St_Johns",
+        long: Names { generic: "Newfoundland Time", standard: "Newfoundland Standard Time", daylight: "Newfoundland Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Pakistan",
+        zone: "Asia/Karachi",
+        long: Names { generic: "Pakistan Time", standard: "Pakistan Standard Time", daylight: "Pakistan Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Peru",
+        zone: "America/Lima",
+        long: Names { generic: "Peru Time", standard: "Peru Standard Time", daylight: "Peru Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Philippines",
+        zone: "Asia/Manila",
+        long: Names { generic: "Philippine Time", standard: "Philippine Standard Time", daylight: "Philippine Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Singapore",
+        zone: "Asia/Singapore",
+        long: Names { generic: "Singapore Standard Time", standard: "Singapore Standard Time", daylight: "Singapore Standard Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Taipei",
+        zone: "Asia/Taipei",
+        long: Names { generic: "Taipei Time", standard: "Taipei Standard Time", daylight: "Taipei Daylight Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Turkey",
+        zone: "Europe/Istanbul",
+        long: Names { generic: "Turkey Time", standard: "Turkey Standard Time", daylight: "Turkey Summer Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "UTC",
+        zone: "Etc/UTC",
+        long: Names { generic: "Coordinated Universal Time", standard: "Coordinated Universal Time", daylight: "Coordinated Universal Time" },
+        short: None,
+    },
+    MetazoneNames {
+        metazone: "Venezuela",
+        zone: "America/Caracas",
+        long: Names { generic: "Venezuela Time", standard: "Venezuela Time", daylight: "Venezuela Time" },
+        short: None,
+    },
+];
+
+/// `(zone, metazone)`, sorted by zone.
+pub(crate) const ZONE_METAZONES: &[(&str, &str)] = &[
+    ("Africa/Abidjan", "GMT"),
+    ("Africa/Accra", "GMT"),
+    ("Africa/Addis_Ababa", "Africa_Eastern"),
+    ("Africa/Algiers", "Europe_Central"),
+    ("Africa/Asmara", "Africa_Eastern"),
+    ("Africa/Bamako", "GMT"),
+    ("Africa/Bangui", "Africa_Western"),
+    ("Africa/Banjul", "GMT"),
+    ("Africa/Bissau", "GMT"),
+    ("Africa/Blantyre", "Africa_Central"),
+    ("Africa/Brazzaville", "Africa_Western"),
+    ("Africa/Bujumbura", "Africa_Central"),
+    ("Africa/Cairo", "Europe_Eastern"),
+    ("Africa/Ceuta", "Europe_Central"),
+    ("Africa/Conakry", "GMT"),
+    ("Africa/Dakar", "GMT"),
+    ("Africa/Dar_es_Salaam", "Africa_Eastern"),
+    ("Africa/Djibouti", "Africa_Eastern"),
+    ("Africa/Douala", "Africa_Western"),
+    ("Africa/Freetown", "GMT"),
+    ("Africa/Gaborone", "Africa_Central"),
+    ("Africa/Harare", "Africa_Central"),
+    ("Africa/Johannesburg", "Africa_Southern"),
+    ("Africa/Juba", "Africa_Central"),
+    ("Africa/Kampala", "Africa_Eastern"),
+    ("Africa/Khartoum", "Africa_Central"),
+    ("Africa/Kigali", "Africa_Central"),
+    ("Africa/Kinshasa", "Africa_Western"),
+    ("Africa/Lagos", "Africa_Western"),
+    ("Africa/Libreville", "Africa_Western"),
+    ("Africa/Lome", "GMT"),
+    ("Africa/Luanda", "Africa_Western"),
+    ("Africa/Lubumbashi", "Africa_Central"),
+    ("Africa/Lusaka", "Africa_Central"),
+    ("Africa/Malabo", "Africa_Western"),
+    ("Africa/Maputo", "Africa_Central"),
+    ("Africa/Maseru", "Africa_Southern"),
+    ("Africa/Mbabane", "Africa_Southern"),
+    ("Africa/Mogadishu", "Africa_Eastern"),
+    ("Africa/Monrovia", "GMT"),
+    ("Africa/Nairobi", "Africa_Eastern"),
+    ("Africa/Ndjamena", "Africa_Western"),
+    ("Africa/Niamey", "Africa_Western"),
+    ("Africa/Nouakchott", "GMT"),
+    ("Africa/Ouagadougou", "GMT"),
+    ("Africa/Porto-Novo", "Africa_Western"),
+    ("Africa/Sao_Tome", "GMT"),
+    ("Africa/Tripoli", "Europe_Eastern"),
+    ("Africa/Tunis", "Europe_Central"),
+    ("Africa/Windhoek", "Africa_Central"),
+    ("America/Adak", "Hawaii_Aleutian"),
+    ("America/Anchorage", "Alaska"),
+    ("America/Araguaina", "Brasilia"),
+    ("America/Argentina/Buenos_Aires", "Argentina"),
+    ("America/Argentina/Catamarca", "Argentina"),
+    ("America/Argentina/Cordoba", "Argentina"),
+    ("America/Argentina/Jujuy", "Argentina"),
+    ("America/Argentina/La_Rioja", "Argentina"),
+    ("America/Argentina/Mendoza", "Argentina"),
+    ("America/Argentina/Rio_Gallegos", "Argentina"),
+    ("America/Argentina/Salta", "Argentina"),
+    ("America/Argentina/San_Juan", "Argentina"),
+    ("America/Argentina/Tucuman", "Argentina"),
+    ("America/Argentina/Ushuaia", "Argentina"),
+    ("America/Bahia", "Brasilia"),
+    ("America/Bahia_Banderas", "America_Central"),
+    ("America/Barbados", "Atlantic"),
+    ("America/Belem", "Brasilia"),
+    ("America/Belize", "America_Central"),
+    ("America/Boa_Vista", "Amazon"),
+    ("America/Bogota", "Colombia"),
+    ("America/Boise", "America_Mountain"),
+    ("America/Buenos_Aires", "Argentina"),
+    ("America/Cambridge_Bay", "America_Mountain"),
+    ("America/Campo_Grande", "Amazon"),
+    ("America/Cancun", "America_Eastern"),
+    ("America/Caracas", "Venezuela"),
+    ("America/Cayman", "America_Eastern"),
+    ("America/Chicago", "America_Central"),
+    ("America/Chihuahua", "Mexico_Pacific"),
+    ("America/Costa_Rica", "America_Central"),
+    ("America/Creston", "America_Mountain"),
+    ("America/Cuiaba", "Amazon"),
+    ("America/Danmarkshavn", "GMT"),
+    ("America/Dawson_Creek", "America_Mountain"),
+    ("America/Denver", "America_Mountain"),
+    ("America/Detroit", "America_Eastern"),
+    ("America/Edmonton", "America_Mountain"),
+    ("America/Eirunepe", "Acre"),
+    ("America/El_Salvador", "America_Central"),
+    ("America/Fort_Nelson", "America_Mountain"),
+    ("America/Fortaleza", "Brasilia"),
+    ("America/Glace_Bay", "Atlantic"),
+    ("America/Goose_Bay", "Atlantic"),
+    ("America/Grand_Turk", "America_Eastern"),
+    ("America/Guatemala", "America_Central"),
+    ("America/Halifax", "Atlantic"),
+    ("America/Havana", "Cuba"),
+    ("America/Hermosillo", "Mexico_Pacific"),
+    ("America/Indiana/Indianapolis", "America_Eastern"),
+    ("America/Indiana/Knox", "America_Central"),
+    ("America/Indiana/Marengo", "America_Eastern"),
+    ("America/Indiana/Petersburg", "America_Eastern"),
+    ("America/Indiana/Tell_City", "America_Central"),
+    ("America/Indiana/Vevay", "America_Eastern"),
+    ("America/Indiana/Vincennes", "America_Eastern"),
+    ("America/Indiana/Winamac", "America_Eastern"),
+    ("America/Inuvik", "America_Mountain"),
+    ("America/Iqaluit", "America_Eastern"),
+    ("America/Jamaica", "America_Eastern"),
+    ("America/Juneau", "Alaska"),
+    ("America/Kentucky/Louisville", "America_Eastern"),
+    ("America/Kentucky/Monticello", "America_Eastern"),
+    ("America/Lima", "Peru"),
+    ("America/Los_Angeles", "America_Pacific"),
+    ("America/Maceio", "Brasilia"),
+    ("America/Managua", "America_Central"),
+    ("America/Manaus", "Amazon"),
+    ("America/Martinique", "Atlantic"),
+    ("America/Matamoros", "America_Central"),
+    ("America/Mazatlan", "Mexico_Pacific"),
+    ("America/Menominee", "America_Central"),
+    ("America/Merida", "America_Central"),
+    ("America/Metlakatla", "Alaska"),
+    ("America/Mexico_City", "America_Central"),
+    ("America/Moncton", "Atlantic"),
+    ("America/Monterrey", "America_Central"),
+    ("America/Nassau", "America_Eastern"),
+    ("America/New_York", "America_Eastern"),
+    ("America/Nipigon", "America_Eastern"),
+    ("America/Nome", "Alaska"),
+    ("America/North_Dakota/Beulah", "America_Central"),
+    ("America/North_Dakota/Center", "America_Central"),
+    ("America/North_Dakota/New_Salem", "America_Central"),
+    ("America/Ojinaga", "America_Mountain"),
+    ("America/Panama", "America_Eastern"),
+    ("America/Pangnirtung", "America_Eastern"),
+    ("America/Phoenix", "America_Mountain"),
+    ("America/Port-au-Prince", "America_Eastern"),
+    ("America/Porto_Velho", "Amazon"),
+    ("America/Puerto_Rico", "Atlantic"),
+    ("America/Rainy_River", "America_Central"),
+    ("America/Rankin_Inlet", "America_Central"),
+    ("America/Recife", "Brasilia"),
+    ("America/Regina", "America_Central"),
+    ("America/Resolute", "America_Central"),
+    ("America/Rio_Branco", "Acre"),
+    ("America/Santarem", "Brasilia"),
+    ("America/Santiago", "Chile"),
+    ("America/Santo_Domingo", "Atlantic"),
+    ("America/Sao_Paulo", "Brasilia"),
+    ("America/Sitka", "Alaska"),
+    ("America/St_Johns", "Newfoundland"),
+    ("America/Swift_Current", "America_Central"),
+    ("America/Tegucigalpa", "America_Central"),
+    ("America/Thule", "Atlantic"),
+    ("America/Thunder_Bay", "America_Eastern"),
+    ("America/Tijuana", "America_Pacific"),
+    ("America/Toronto", "America_Eastern"),
+    ("America/Vancouver", "America_Pacific"),
+    ("America/Winnipeg", "America_Central"),
+    ("America/Yakutat", "Alaska"),
+    ("America/Yellowknife", "America_Mountain"),
+    ("Antarctica/McMurdo", "New_Zealand"),
+    ("Arctic/Longyearbyen", "Europe_Central"),
+    ("Asia/Aden", "Arabian"),
+    ("Asia/Baghdad", "Arabian"),
+    ("Asia/Bahrain", "Arabian"),
+    ("Asia/Bangkok", "Indochina"),
+    ("Asia/Beirut", "Europe_Eastern"),
+    ("Asia/Calcutta", "India"),
+    ("Asia/Dhaka", "Bangladesh"),
+    ("Asia/Dubai", "Gulf"),
+    ("Asia/Famagusta", "Europe_Eastern"),
+    ("Asia/Gaza", "Europe_Eastern"),
+    ("Asia/Hebron", "Europe_Eastern"),
+    ("Asia/Ho_Chi_Minh", "Indochina"),
+    ("Asia/Hong_Kong", "Hong_Kong"),
+    ("Asia/Jakarta", "Indonesia_Western"),
+    ("Asia/Jayapura", "Indonesia_Eastern"),
+    ("Asia/Jerusalem", "Israel"),
+    ("Asia/Karachi", "Pakistan"),
+    ("Asia/Kathmandu", "Nepal"),
+    ("Asia/Katmandu", "Nepal"),
+    ("Asia/Kolkata", "India"),
+    ("Asia/Kuala_Lumpur", "Malaysia"),
+    ("Asia/Kuching", "Malaysia"),
+    ("Asia/Kuwait", "Arabian"),
+    ("Asia/Macau", "China"),
+    ("Asia/Makassar", "Indonesia_Central"),
+    ("Asia/Manila", "Philippines"),
+    ("Asia/Muscat", "Gulf"),
+    ("Asia/Nicosia", "Europe_Eastern"),
+    ("Asia/Phnom_Penh", "Indochina"),
+    ("Asia/Pontianak", "Indonesia_Western"),
+    ("Asia/Qatar", "Arabian"),
+    ("Asia/Riyadh", "Arabian"),
+    ("Asia/Saigon", "Indochina"),
+    ("Asia/Seoul", "Korea"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Singapore", "Singapore"),
+    ("Asia/Taipei", "Taipei"),
+    ("Asia/Tehran", "Iran"),
+    ("Asia/Tokyo", "Japan"),
+    ("Asia/Vientiane", "Indochina"),
+    ("Atlantic/Bermuda", "Atlantic"),
+    ("Atlantic/Canary", "Europe_Western"),
+    ("Atlantic/Faroe", "Europe_Western"),
+    ("Atlantic/Madeira", "Europe_Western"),
+    ("Atlantic/Reykjavik", "GMT"),
+    ("Atlantic/St_Helena", "GMT"),
+    ("Australia/Adelaide", "Australia_Central"),
+    ("Australia/Brisbane", "Australia_Eastern"),
+    ("Australia/Broken_Hill", "Australia_Central"),
+    ("Australia/Darwin", "Australia_Central"),
+    ("Australia/Hobart", "Australia_Eastern"),
+    ("Australia/Lindeman", "Australia_Eastern"),
+    ("Australia/Lord_Howe", "Lord_Howe"),
+    ("Australia/Melbourne", "Australia_Eastern"),
+    ("Australia/Perth", "Australia_Western"),
+    ("Australia/Sydney", "Australia_Eastern"),
+    ("CET", "Europe_Central"),
+    ("CST6CDT", "America_Central"),
+    ("EET", "Europe_Eastern"),
+    ("EST", "America_Eastern"),
+    ("EST5EDT", "America_Eastern"),
+    ("Etc/GMT", "GMT"),
+    ("Etc/UCT", "UTC"),
+    ("Etc/UTC", "UTC"),
+    ("Etc/Universal", "UTC"),
+    ("Etc/Zulu", "UTC"),
+    ("Europe/Amsterdam", "Europe_Central"),
+    ("Europe/Andorra", "Europe_Central"),
+    ("Europe/Athens", "Europe_Eastern"),
+    ("Europe/Belgrade", "Europe_Central"),
+    ("Europe/Berlin", "Europe_Central"),
+    ("Europe/Bratislava", "Europe_Central"),
+    ("Europe/Brussels", "Europe_Central"),
+    ("Europe/Bucharest", "Europe_Eastern"),
+    ("Europe/Budapest", "Europe_Central"),
+    ("Europe/Busingen", "Europe_Central"),
+    ("Europe/Chisinau", "Europe_Eastern"),
+    ("Europe/Copenhagen", "Europe_Central"),
+    ("Europe/Dublin", "GMT"),
+    ("Europe/Gibraltar", "Europe_Central"),
+    ("Europe/Guernsey", "GMT"),
+    ("Europe/Helsinki", "Europe_Eastern"),
+    ("Europe/Isle_of_Man", "GMT"),
+    ("Europe/Istanbul", "Turkey"),
+    ("Europe/Jersey", "GMT"),
+    ("Europe/Kaliningrad", "Europe_Eastern"),
+    ("Europe/Kiev", "Europe_Eastern"),
+    ("Europe/Kirov", "Moscow"),
+    ("Europe/Kyiv", "Europe_Eastern"),
+    ("Europe/Lisbon", "Europe_Western"),
+    ("Europe/Ljubljana", "Europe_Central"),
+    ("Europe/London", "GMT"),
+    ("Europe/Luxembourg", "Europe_Central"),
+    ("Europe/Madrid", "Europe_Central"),
+    ("Europe/Malta", "Europe_Central"),
+    ("Europe/Mariehamn", "Europe_Eastern"),
+    ("Europe/Minsk", "Moscow"),
+    ("Europe/Monaco", "Europe_Central"),
+    ("Europe/Moscow", "Moscow"),
+    ("Europe/Oslo", "Europe_Central"),
+    ("Europe/Paris", "Europe_Central"),
+    ("Europe/Podgorica", "Europe_Central"),
+    ("Europe/Prague", "Europe_Central"),
+    ("Europe/Riga", "Europe_Eastern"),
+    ("Europe/Rome", "Europe_Central"),
+    ("Europe/San_Marino", "Europe_Central"),
+    ("Europe/Sarajevo", "Europe_Central"),
+    ("Europe/Simferopol", "Moscow"),
+    ("Europe/Skopje", "Europe_Central"),
+    ("Europe/Sofia", "Europe_Eastern"),
+    ("Europe/Stockholm", "Europe_Central"),
+    ("Europe/Tallinn", "Europe_Eastern"),
+    ("Europe/Tirane", "Europe_Central"),
+    ("Europe/Uzhgorod", "Europe_Eastern"),
+    ("Europe/Vaduz", "Europe_Central"),
+    ("Europe/Vatican", "Europe_Central"),
+    ("Europe/Vienna", "Europe_Central"),
+    ("Europe/Vilnius", "Europe_Eastern"),
+    ("Europe/Volgograd", "Moscow"),
+    ("Europe/Warsaw", "Europe_Central"),
+    ("Europe/Zagreb", "Europe_Central"),
+    ("Europe/Zaporozhye", "Europe_Eastern"),
+    ("Europe/Zurich", "Europe_Central"),
+    ("HST", "Hawaii_Aleutian"),
+    ("Indian/Antananarivo", "Africa_Eastern"),
+    ("Indian/Comoro", "Africa_Eastern"),
+    ("Indian/Mayotte", "Africa_Eastern"),
+    ("MET", "Europe_Central"),
+    ("MST", "America_Mountain"),
+    ("MST7MDT", "America_Mountain"),
+    ("PST8PDT", "America_Pacific"),
+    ("Pacific/Auckland", "New_Zealand"),
+    ("Pacific/Honolulu", "Hawaii_Aleutian"),
+    ("WET", "Europe_Western"),
+];
+
+/// Zones whose daylight name differs from their metazone's: `(zone, daylight)`.
+pub(crate) const DAYLIGHT_OVERRIDES: &[(&str, &str)] = &[
+    ("Europe/Dublin", "Irish Standard Time"),
+    ("Europe/London", "British Summer Time"),
+];
+
+/// Abbreviations recognised when parsing zone names, besides the short names of
+/// [`METAZONES`]: `(abbreviation, zone, daylight)`.
+pub(crate) const ABBREVIATIONS: &[(&str, &str, bool)] = &[
+    ("ACDT", "Australia/Adelaide", true),
+    ("ACST", "Australia/Adelaide", false),
+    ("AEDT", "Australia/Sydney", true),
+    ("AEST", "Australia/Sydney", false),
+    ("AWST", "Australia/Perth", false),
+    ("BST", "Europe/London", true),
+    ("CAT", "Africa/Maputo", false),
+    ("CEST", "Europe/Paris", true),
+    ("CET", "Europe/Paris", false),
+    ("EAT", "Africa/Nairobi", false),
+    ("EEST", "Europe/Bucharest", true),
+    ("EET", "Europe/Bucharest", false),
+    ("HDT", "America/Adak", true),
+    ("HKT", "Asia/Hong_Kong", false),
+    ("HST", "Pacific/Honolulu", false),
+    ("IDT", "Asia/Jerusalem", true),
+    ("IST", "Asia/Kolkata", false),
+    ("JST", "Asia/Tokyo", false),
+    ("KST", "Asia/Seoul", false),
+    ("MSK", "Europe/Moscow", false),
+    ("NDT", "America/St_Johns", true),
+    ("NST", "America/St_Johns", false),
+    ("NZDT", "Pacific/Auckland", true),
+    ("NZST", "Pacific/Auckland", false),
+    ("PKT", "Asia/Karachi", false),
+    ("SAST", "Africa/Johannesburg", false),
+    ("WAT", "Africa/Lagos", false),
+    ("WEST", "Europe/Lisbon", true),
+    ("WET", "Europe/Lisbon", false),
+    ("WIB", "Asia/Jakarta", false),
+];
@@ -1,3 +1,6 @@
+use std::fmt;
+use std::str::FromStr;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -6,6 +9,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct ZoneOffset(time::UtcOffset);
 
+/// The largest offset supported, as in `java.time`: 18 hours.
+const MAX_SECONDS: i32 = 18 * 3600;
+
 impl ZoneOffset {
     /// A constant representing the UTC (Coordinated Universal Time) zone offset.
     ///
@@ -97,6 +103,103 @@ impl ZoneOffset {
         (h as i32) * 3600 + (m as i32) * 60 + (s as i32)
     }
 
+    /// Creates a `ZoneOffset` from a total number of seconds.
+    ///
+    /// # Panics
+    /// Panics if the offset is outside the range `-18:00` to `+18:00`.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(ZoneOffset::of_total_seconds(19_800), ZoneOffset::of_hours_minutes(5, 30));
+    /// ```
+    pub fn of_total_seconds(total_seconds: i32) -> Self {
+        if total_seconds.abs() > MAX_SECONDS {
+            panic!("Zone offset not in valid range: -18:00 to +18:00");
+        }
+        ZoneOffset(time::UtcOffset::from_whole_seconds(total_seconds).expect("invalid offset"))
+    }
+
+    /// Parses an offset ID the way `java.time.ZoneOffset.of` does.
+    ///
+    /// Accepted formats are `Z`, `+h`, `+hh`, `+hh:mm`, `+hhmm`, `+hh:mm:ss` and `+hhmmss`,
+    /// with either sign, up to `+18:00` / `-18:00`.
+    ///
+    /// # Errors
+    /// Returns `"Invalid ID for ZoneOffset"` if the text is not in one of the formats above or
+    /// the offset is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(ZoneOffset::try_of("+05:30").unwrap(), ZoneOffset::of_hours_minutes(5, 30));
+    /// assert_eq!(ZoneOffset::try_of("-3").unwrap(), ZoneOffset::of_hours(-3));
+    /// assert_eq!(ZoneOffset::try_of("Z").unwrap(), ZoneOffset::UTC);
+    /// ```
+    pub fn try_of(id: &str) -> Result<Self, &'static str> {
+        const INVALID: &str = "Invalid ID for ZoneOffset";
+        if id == "Z" {
+            return Ok(ZoneOffset::UTC);
+        }
+        let sign = match id.as_bytes().first() {
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return Err(INVALID),
+        };
+        let digits = |range: std::ops::Range<usize>| -> Result<i32, &'static str> {
+            let text = id.get(range).ok_or(INVALID)?;
+            if text.bytes().all(|b| b.is_ascii_digit()) {
+                text.parse().map_err(|_| INVALID)
+            } else {
+                Err(INVALID)
+            }
+        };
+        let colon = |at: usize| if id.as_bytes()[at] == b':' { Ok(()) } else { Err(INVALID) };
+        let (hours, minutes, seconds) = match id.len() {
+            2 => (digits(1..2)?, 0, 0),
+            3 => (digits(1..3)?, 0, 0),
+            5 => (digits(1..3)?, digits(3..5)?, 0),
+            6 => {
+                colon(3)?;
+                (digits(1..3)?, digits(4..6)?, 0)
+            }
+            7 => (digits(1..3)?, digits(3..5)?, digits(5..7)?),
+            9 => {
+                colon(3)?;
+                colon(6)?;
+                (digits(1..3)?, digits(4..6)?, digits(7..9)?)
+            }
+            _ => return Err(INVALID),
+        };
+        let total = hours * 3600 + minutes * 60 + seconds;
+        if minutes > 59 || seconds > 59 || total > MAX_SECONDS {
+            return Err(INVALID);
+        }
+        Ok(ZoneOffset::of_total_seconds(sign * total))
+    }
+
+    /// Returns the normalized ID of this offset, as `java.time.ZoneOffset.getId` does:
+    /// `Z` for UTC, otherwise `+hh:mm`, or `+hh:mm:ss` when there are seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// assert_eq!(ZoneOffset::of_hours_minutes(5, 30).id(), "+05:30");
+    /// assert_eq!(ZoneOffset::of_hours(-3).id(), "-03:00");
+    /// assert_eq!(ZoneOffset::UTC.id(), "Z");
+    /// ```
+    pub fn id(self) -> String {
+        let total = self.total_seconds();
+        if total == 0 {
+            return "Z".to_string();
+        }
+        let sign = if total < 0 { '-' } else { '+' };
+        let abs = total.abs();
+        let (hours, minutes, seconds) = (abs / 3600, abs / 60 % 60, abs % 60);
+        if seconds == 0 {
+            format!("{sign}{hours:02}:{minutes:02}")
+        } else {
+            format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+        }
+    }
+
     pub(crate) fn inner(self) -> time::UtcOffset {
         self.0
     }
@@ -113,3 +216,19 @@ impl From<ZoneOffset> for time::UtcOffset {
         value.0
     }
 }
+
+/// Formats the offset as its ID, such as `Z` or `+05:30`, like `java.time.ZoneOffset.toString`.
+impl fmt::Display for ZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id())
+    }
+}
+
+impl FromStr for ZoneOffset {
+    type Err = &'static str;
+
+    /// Parse an offset ID such as `+05:30` or `Z`. See [`ZoneOffset::try_of`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ZoneOffset::try_of(s)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{LocalDateTime, ZoneId, ZonedDateTime};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Strategy for converting a `LocalDateTime` to a `ZonedDateTime` when the local
+/// date-time falls in a daylight-saving gap or overlap.
+///
+/// - In a *gap* (clocks set forward) the local date-time does not exist in the zone.
+/// - In an *overlap* (clocks set back) the local date-time exists twice, once with the
+///   offset before the transition (the earlier offset) and once with the offset after it
+///   (the later offset).
+///
+/// Local date-times that are neither in a gap nor an overlap have exactly one offset and
+/// resolve the same way with every strategy.
+///
+/// ```rust
+/// let new_york = ZoneId::try_of("America/New_York").unwrap();
+/// let in_gap = LocalDateTime::of(2024, 3, 10, 2, 30, 0);
+///
+/// let shifted = ZoneResolver::ShiftForward.resolve(in_gap, new_york.clone()).unwrap();
+/// assert_eq!(shifted.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 3, 30, 0));
+///
+/// assert!(ZoneResolver::Strict.resolve(in_gap, new_york).is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ZoneResolver {
+    /// Overlap: uses the earlier offset. Gap: moves the local date-time forward by the
+    /// length of the gap. This is the behavior of `java.time.ZonedDateTime.of` and of
+    /// `ZonedDateTime::of`.
+    #[default]
+    ShiftForward,
+    /// Overlap: uses the earlier offset. Gap: moves the local date-time forward by the
+    /// length of the gap, like `ShiftForward`, as `java.time` always does in a gap.
+    EarlierOffset,
+    /// Overlap: uses the later offset, like `ZonedDateTime.withLaterOffsetAtOverlap` in
+    /// `java.time`. Gap: moves the local date-time forward by the length of the gap.
+    LaterOffset,
+    /// Returns an error for local date-times in a gap or an overlap.
+    Strict,
+}
+
+impl ZoneResolver {
+    /// Resolves `ldt` in `zone` using this strategy.
+    ///
+    /// # Errors
+    /// `ZoneResolver::Strict` returns an error for a gap or an overlap:
+    /// - `"Local date-time falls in a gap in the time zone"` for a gap.
+    /// - `"Local date-time is ambiguous in the time zone"` for an overlap.
+    ///
+    /// Every strategy returns `"Local date-time exceeds the supported range"` when shifting
+    /// out of a gap would pass the end of the supported range.
+    ///
+    /// # Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let in_overlap = LocalDateTime::of(2024, 11, 3, 1, 30, 0);
+    ///
+    /// let earlier = ZoneResolver::EarlierOffset.resolve(in_overlap, new_york.clone()).unwrap();
+    /// let later = ZoneResolver::LaterOffset.resolve(in_overlap, new_york).unwrap();
+    /// assert_eq!(earlier.offset(), ZoneOffset::of_hours(-4));
+    /// assert_eq!(later.offset(), ZoneOffset::of_hours(-5));
+    /// ```
+    pub fn resolve(self, ldt: LocalDateTime, zone: ZoneId) -> Result<ZonedDateTime, &'static str> {
+        let local = ldt.inner();
+        let date_time = match (zone.valid_offsets_at(ldt).as_slice(), self) {
+            ([offset], _) => local.assume_offset(offset.inner()),
+            ([_, _], ZoneResolver::Strict) => return Err("Local date-time is ambiguous in the time zone"),
+            ([_, later], ZoneResolver::LaterOffset) => local.assume_offset(later.inner()),
+            ([earlier, _], _) => local.assume_offset(earlier.inner()),
+            (_, ZoneResolver::Strict) => return Err("Local date-time falls in a gap in the time zone"),
+            (_, _) => {
+                let transition = zone
+                    .rules()
+                    .transition(ldt)
+                    .expect("a local date-time without valid offsets lies in a gap");
+                // Moving forward by the gap keeps the instant that the offset before the
+                // transition gives.
+                local
+                    .assume_offset(transition.offset_before().inner())
+                    .checked_to_offset(transition.offset_after().inner())
+                    .ok_or("Local date-time exceeds the supported range")?
+            }
+        };
+        Ok(ZonedDateTime::from_parts(date_time, zone))
+    }
+}
@@ -0,0 +1,482 @@
+use crate::tzif::TzifData;
+use crate::{Duration, Instant, LocalDateTime, Year, ZoneOffset};
+use std::collections::BTreeMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+use time_tz::{Offset, OffsetResult, TimeZone, Tz};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The rules describing how the offset of a time zone changes over time, in the style of
+/// `java.time.zone.ZoneRules`.
+///
+/// Obtained from [`ZoneId::rules`](crate::ZoneId::rules).
+///
+/// The tz database compiled into this crate lists transitions up to the end of 2099. Later
+/// instants keep the offset in effect after the last of them, so for those zones
+/// [`ZoneRules::next_transition`] returns `None` from 2100 on. Rules read from TZif files
+/// follow the POSIX `TZ` rule in their footer instead, without an end.
+///
+/// ```rust
+/// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+/// let july = LocalDateTime::of(2024, 7, 1, 12, 0, 0).to_instant_utc();
+///
+/// assert_eq!(rules.offset(july), ZoneOffset::of_hours(-4));
+/// assert_eq!(rules.standard_offset(july), ZoneOffset::of_hours(-5));
+/// assert!(rules.is_daylight_savings(july));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneRules {
+    source: Source,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    /// A zone from the tz database compiled into this crate.
+    Tz(&'static Tz),
+    /// A zone whose offset never changes.
+    Fixed(UtcOffset),
+    /// A zone read from a TZif file at runtime.
+    Tzif(Arc<TzifData>),
+}
+
+impl ZoneRules {
+    /// Returns rules that always use the given offset.
+    ///
+    /// ```rust
+    /// let rules = ZoneRules::of(ZoneOffset::of_hours(3));
+    /// assert!(rules.is_fixed_offset());
+    /// ```
+    pub fn of(offset: ZoneOffset) -> Self {
+        ZoneRules { source: Source::Fixed(offset.inner()) }
+    }
+
+    pub(crate) fn of_tz(tz: &'static Tz) -> Self {
+        ZoneRules { source: Source::Tz(tz) }
+    }
+
+    /// Reads rules from the content of a compiled TZif file (RFC 8536), versions 1 to 4, such
+    /// as the files under `/usr/share/zoneinfo`. Instants after the last transition in the
+    /// file follow the POSIX `TZ` rule in its footer.
+    ///
+    /// # Errors
+    /// - `"Invalid TZif data"` if the content is truncated or malformed.
+    /// - `"Unsupported TZif version"` for an unknown version byte.
+    ///
+    /// ```rust
+    /// let bytes = std::fs::read("/usr/share/zoneinfo/Europe/Paris").unwrap();
+    /// let rules = ZoneRules::from_tzif(&bytes).unwrap();
+    /// ```
+    pub fn from_tzif(bytes: &[u8]) -> Result<Self, &'static str> {
+        TzifData::parse(bytes).map(|data| ZoneRules { source: Source::Tzif(Arc::new(data)) })
+    }
+
+    /// Returns `true` if the offset never changes.
+    ///
+    /// ```rust
+    /// assert!(ZoneId::try_of("Etc/GMT+5").unwrap().rules().is_fixed_offset());
+    /// assert!(!ZoneId::try_of("Europe/Paris").unwrap().rules().is_fixed_offset());
+    /// ```
+    pub fn is_fixed_offset(&self) -> bool {
+        match &self.source {
+            Source::Fixed(_) => true,
+            Source::Tz(tz) => tz_transitions(tz).is_empty(),
+            Source::Tzif(data) => data.next_change(i64::MIN).is_none(),
+        }
+    }
+
+    /// Returns the offset in effect at the given instant.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("Europe/Paris").unwrap().rules();
+    /// let january = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+    /// assert_eq!(rules.offset(january), ZoneOffset::of_hours(1));
+    /// ```
+    pub fn offset(&self, instant: Instant) -> ZoneOffset {
+        ZoneOffset::from(self.offset_at(instant.inner()))
+    }
+
+    /// Returns the standard offset (the offset without daylight saving time) in effect at
+    /// the given instant.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("Europe/Paris").unwrap().rules();
+    /// let july = LocalDateTime::of(2024, 7, 1, 0, 0, 0).to_instant_utc();
+    /// assert_eq!(rules.standard_offset(july), ZoneOffset::of_hours(1));
+    /// ```
+    pub fn standard_offset(&self, instant: Instant) -> ZoneOffset {
+        if !self.is_daylight_savings(instant) {
+            return self.offset(instant);
+        }
+        // The tz data only says whether an offset includes daylight saving time, so the
+        // standard offset is taken from the nearest period outside daylight saving time.
+        let mut cursor = instant;
+        while let Some(transition) = self.previous_transition(cursor) {
+            let just_before = transition.instant().minus_seconds(1);
+            if !self.is_daylight_savings(just_before) {
+                return transition.offset_before();
+            }
+            cursor = transition.instant();
+        }
+        let mut cursor = instant;
+        while let Some(transition) = self.next_transition(cursor) {
+            if !self.is_daylight_savings(transition.instant()) {
+                return transition.offset_after();
+            }
+            cursor = transition.instant();
+        }
+        self.offset(instant)
+    }
+
+    /// Returns `true` if daylight saving time is in effect at the given instant.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let january = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+    /// assert!(!rules.is_daylight_savings(january));
+    /// ```
+    pub fn is_daylight_savings(&self, instant: Instant) -> bool {
+        match &self.source {
+            Source::Tz(tz) => tz.get_offset_utc(&instant.inner()).is_dst(),
+            Source::Fixed(_) => false,
+            Source::Tzif(data) => data.local_time_type(instant.epoch_seconds()).is_dst,
+        }
+    }
+
+    /// Returns the amount of daylight saving time in effect at the given instant, which is
+    /// the difference between the actual offset and the standard offset.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let july = LocalDateTime::of(2024, 7, 1, 0, 0, 0).to_instant_utc();
+    /// assert_eq!(rules.daylight_savings(july), Duration::of_hours(1));
+    /// ```
+    pub fn daylight_savings(&self, instant: Instant) -> Duration {
+        let seconds = self.offset(instant).total_seconds() - self.standard_offset(instant).total_seconds();
+        Duration::of_seconds(seconds as i64)
+    }
+
+    /// Returns the abbreviation the tz data uses for the given instant, such as `EST` or
+    /// `CEST`. Zones without a customary abbreviation use their offset (`+03`), and fixed
+    /// rules use the ID of their offset.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let july = LocalDateTime::of(2024, 7, 1, 0, 0, 0).to_instant_utc();
+    /// assert_eq!(rules.abbreviation(july), "EDT");
+    /// ```
+    pub fn abbreviation(&self, instant: Instant) -> String {
+        match &self.source {
+            Source::Tz(tz) => tz.get_offset_utc(&instant.inner()).name().to_string(),
+            Source::Fixed(offset) => ZoneOffset::from(*offset).id(),
+            Source::Tzif(data) => data.local_time_type(instant.epoch_seconds()).abbreviation.clone(),
+        }
+    }
+
+    /// Returns `true` if `offset` is valid for the local date-time in this zone.
+    pub fn is_valid_offset(&self, ldt: LocalDateTime, offset: ZoneOffset) -> bool {
+        self.valid_offsets(ldt).contains(&offset)
+    }
+
+    /// Returns the offsets valid for the local date-time: one normally, two (earlier offset
+    /// first) in an overlap and none in a gap.
+    pub fn valid_offsets(&self, ldt: LocalDateTime) -> Vec<ZoneOffset> {
+        let tz = match &self.source {
+            Source::Tz(tz) => tz,
+            Source::Fixed(offset) => return vec![ZoneOffset::from(*offset)],
+            Source::Tzif(_) => return self.search_valid_offsets(ldt),
+        };
+        match tz.get_offset_local(&ldt.inner().assume_utc()) {
+            OffsetResult::Some(offset) => vec![ZoneOffset::from(offset.to_utc())],
+            OffsetResult::Ambiguous(earlier, later) => {
+                vec![ZoneOffset::from(earlier.to_utc()), ZoneOffset::from(later.to_utc())]
+            }
+            OffsetResult::None => Vec::new(),
+        }
+    }
+
+    /// Returns the transition that makes the local date-time fall in a gap or an overlap,
+    /// or `None` if the local date-time has exactly one valid offset.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let gap = rules.transition(LocalDateTime::of(2024, 3, 10, 2, 30, 0)).unwrap();
+    /// assert!(gap.is_gap());
+    /// assert_eq!(gap.date_time_before(), LocalDateTime::of(2024, 3, 10, 2, 0, 0));
+    /// ```
+    pub fn transition(&self, ldt: LocalDateTime) -> Option<ZoneOffsetTransition> {
+        if self.valid_offsets(ldt).len() == 1 {
+            return None;
+        }
+        // The transition instant lies within 18 hours (the largest possible offset) of the
+        // local date-time read as UTC.
+        let as_utc = ldt.inner().assume_utc().unix_timestamp();
+        let transition = self.next_transition(Instant::from(clamped(as_utc - 18 * 3_600 - 1)))?;
+        // Compared as local epoch seconds, which stay valid where the local date-time after
+        // a transition late in 9999 does not.
+        let local = ldt.epoch_seconds();
+        let at = transition.instant().epoch_seconds();
+        let before = at + i64::from(transition.offset_before().total_seconds());
+        let after = at + i64::from(transition.offset_after().total_seconds());
+        (before.min(after) <= local && local < before.max(after)).then_some(transition)
+    }
+
+    /// Returns the first transition strictly after the given instant, or `None` if the
+    /// offset does not change again. For the compiled tz database that includes every
+    /// instant after 2099, see [`ZoneRules`].
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let start = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+    /// let next = rules.next_transition(start).unwrap();
+    /// assert_eq!(next.instant(), LocalDateTime::of(2024, 3, 10, 7, 0, 0).to_instant_utc());
+    /// assert_eq!(next.offset_before(), ZoneOffset::of_hours(-5));
+    /// assert_eq!(next.offset_after(), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn next_transition(&self, instant: Instant) -> Option<ZoneOffsetTransition> {
+        let epoch_second = instant.epoch_seconds();
+        let at = match &self.source {
+            Source::Fixed(_) => None,
+            Source::Tz(tz) => {
+                let transitions = tz_transitions(tz);
+                let start = transitions.partition_point(|&at| at <= epoch_second);
+                transitions.get(start).copied()
+            }
+            Source::Tzif(data) => data.next_change(epoch_second),
+        };
+        at.filter(|&at| in_range(at)).map(|at| self.transition_at(at))
+    }
+
+    /// Returns the last transition strictly before the given instant, or `None` if the
+    /// offset has never changed before it.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("America/New_York").unwrap().rules();
+    /// let start = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+    /// let previous = rules.previous_transition(start).unwrap();
+    /// assert!(previous.is_overlap());
+    /// assert_eq!(previous.instant(), LocalDateTime::of(2023, 11, 5, 6, 0, 0).to_instant_utc());
+    /// ```
+    pub fn previous_transition(&self, instant: Instant) -> Option<ZoneOffsetTransition> {
+        let nanos = instant.epoch_nanoseconds();
+        let ceiling = nanos.div_euclid(1_000_000_000) as i64 + i64::from(nanos.rem_euclid(1_000_000_000) > 0);
+        let at = match &self.source {
+            Source::Fixed(_) => None,
+            Source::Tz(tz) => {
+                let transitions = tz_transitions(tz);
+                let end = transitions.partition_point(|&at| at < ceiling);
+                end.checked_sub(1).map(|last| transitions[last])
+            }
+            Source::Tzif(data) => data.previous_change(ceiling),
+        };
+        at.filter(|&at| in_range(at - 1)).map(|at| self.transition_at(at))
+    }
+
+    /// Returns the transitions whose instant falls in the given year, in UTC.
+    ///
+    /// ```rust
+    /// let rules = ZoneId::try_of("Europe/London").unwrap().rules();
+    /// let transitions = rules.transitions_in(Year::of(2024));
+    /// assert_eq!(transitions.len(), 2);
+    /// assert!(transitions[0].is_gap());
+    /// assert!(transitions[1].is_overlap());
+    /// ```
+    pub fn transitions_in(&self, year: Year) -> Vec<ZoneOffsetTransition> {
+        let start = year.at_day(1).at_start_of_day().to_instant_utc();
+        let end = year.plus(1).at_day(1).at_start_of_day().to_instant_utc();
+        let mut transitions = Vec::new();
+        let mut cursor = start.minus_seconds(1);
+        while let Some(transition) = self.next_transition(cursor) {
+            if transition.instant().is_on_or_after(end) {
+                break;
+            }
+            cursor = transition.instant();
+            transitions.push(transition);
+        }
+        transitions
+    }
+
+    pub(crate) fn offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        match &self.source {
+            Source::Tz(tz) => tz.get_offset_utc(&instant).to_utc(),
+            Source::Fixed(offset) => *offset,
+            Source::Tzif(data) => {
+                let seconds = data.local_time_type(instant.unix_timestamp()).offset;
+                UtcOffset::from_whole_seconds(seconds).expect("TZif offsets are checked when parsed")
+            }
+        }
+    }
+
+    /// Finds the valid offsets of a local date-time from the transitions around it: an offset
+    /// is valid if it is the one in effect at the instant it gives the local date-time.
+    fn search_valid_offsets(&self, ldt: LocalDateTime) -> Vec<ZoneOffset> {
+        let local = ldt.epoch_seconds();
+        let window = 18 * 3_600;
+        let mut candidates = vec![ZoneOffset::from(self.offset_at_second(local - window))];
+        let mut cursor = Instant::from(clamped(local - window));
+        while let Some(transition) = self.next_transition(cursor) {
+            if transition.instant().epoch_seconds() > local + window {
+                break;
+            }
+            candidates.push(transition.offset_after());
+            cursor = transition.instant();
+        }
+        let mut valid: Vec<ZoneOffset> = candidates
+            .into_iter()
+            .filter(|offset| ZoneOffset::from(self.offset_at_second(local - i64::from(offset.total_seconds()))) == *offset)
+            .collect();
+        valid.sort_by_key(|offset| -offset.total_seconds());
+        valid.dedup();
+        valid
+    }
+
+    fn transition_at(&self, epoch_second: i64) -> ZoneOffsetTransition {
+        ZoneOffsetTransition::new(epoch_second, self.offset_at_second(epoch_second - 1), self.offset_at_second(epoch_second))
+    }
+
+    fn offset_at_second(&self, epoch_second: i64) -> UtcOffset {
+        self.offset_at(clamped(epoch_second))
+    }
+}
+
+/// Returns `true` if an `Instant` can represent the epoch second.
+fn in_range(epoch_second: i64) -> bool {
+    OffsetDateTime::from_unix_timestamp(epoch_second).is_ok()
+}
+
+/// Returns the epoch second as a date-time, moved to the nearest end of the supported range
+/// if it lies outside. TZif footer rules give transitions past the year 9999.
+fn clamped(epoch_second: i64) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(epoch_second).unwrap_or(if epoch_second < 0 {
+        PrimitiveDateTime::MIN.assume_utc()
+    } else {
+        PrimitiveDateTime::MAX.assume_utc()
+    })
+}
+
+/// Returns the instants at which a zone of the compiled tz database changes its offset, in
+/// order, found once per zone through the public offset lookup of `time-tz`, which does not
+/// expose its spans. The offset, abbreviation and daylight saving time from 1800 to the end of
+/// the data in 2099 are compared one day apart and each change is narrowed down to its second
+/// by bisection. A span shorter than a day that returns to all three of the previous values
+/// would be missed; the shortest such span in the tz database lasts about a week.
+fn tz_transitions(tz: &'static Tz) -> Arc<[i64]> {
+    /// 1800-01-01T00:00Z, before the first transition of any zone.
+    const START: i64 = -5_364_662_400;
+    /// 2100-01-01T00:00Z, after the last transition of any zone.
+    const END: i64 = 4_102_444_800;
+    const STEP: i64 = 86_400;
+    static TRANSITIONS: RwLock<BTreeMap<&'static str, Arc<[i64]>>> = RwLock::new(BTreeMap::new());
+    if let Some(transitions) = TRANSITIONS.read().unwrap_or_else(PoisonError::into_inner).get(tz.name()) {
+        return transitions.clone();
+    }
+    let span = |epoch_second: i64| {
+        let offset = tz.get_offset_utc(&clamped(epoch_second));
+        (offset.to_utc(), offset.name().to_owned(), offset.is_dst())
+    };
+    let mut transitions = Vec::new();
+    let (mut from, mut before) = (START, span(START));
+    while from < END {
+        let to = (from + STEP).min(END);
+        if span(to) == before {
+            from = to;
+            continue;
+        }
+        let (mut low, mut high) = (from, to);
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if span(middle) == before {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let after = span(high);
+        if after.0 != before.0 {
+            transitions.push(high);
+        }
+        (from, before) = (high, after);
+    }
+    let transitions: Arc<[i64]> = transitions.into();
+    TRANSITIONS.write().unwrap_or_else(PoisonError::into_inner).insert(tz.name(), transitions.clone());
+    transitions
+}
+
+/// A change of offset in a time zone, in the style of
+/// `java.time.zone.ZoneOffsetTransition`.
+///
+/// A transition is a *gap* when the offset increases (clocks set forward, some local
+/// date-times do not exist) and an *overlap* when the offset decreases (clocks set back,
+/// some local date-times occur twice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZoneOffsetTransition {
+    instant: Instant,
+    offset_before: ZoneOffset,
+    offset_after: ZoneOffset,
+}
+
+impl ZoneOffsetTransition {
+    /// Creates a transition at `instant` from `offset_before` to `offset_after`.
+    ///
+    /// # Panics
+    /// Panics if the two offsets are equal.
+    pub fn of(instant: Instant, offset_before: ZoneOffset, offset_after: ZoneOffset) -> Self {
+        if offset_before == offset_after {
+            panic!("offsets must differ for a transition");
+        }
+        ZoneOffsetTransition { instant, offset_before, offset_after }
+    }
+
+    fn new(epoch_second: i64, before: UtcOffset, after: UtcOffset) -> Self {
+        Self::of(Instant::of_epoch_second(epoch_second), ZoneOffset::from(before), ZoneOffset::from(after))
+    }
+
+    /// Returns the instant at which the new offset takes effect.
+    pub fn instant(self) -> Instant {
+        self.instant
+    }
+
+    /// Returns the offset in effect before the transition.
+    pub fn offset_before(self) -> ZoneOffset {
+        self.offset_before
+    }
+
+    /// Returns the offset in effect from the transition onwards.
+    pub fn offset_after(self) -> ZoneOffset {
+        self.offset_after
+    }
+
+    /// Returns the local date-time at which the transition happens, using the offset before.
+    pub fn date_time_before(self) -> LocalDateTime {
+        self.instant.at_offset(self.offset_before).to_local_date_time()
+    }
+
+    /// Returns the local date-time just after the transition, using the offset after.
+    pub fn date_time_after(self) -> LocalDateTime {
+        self.instant.at_offset(self.offset_after).to_local_date_time()
+    }
+
+    /// Returns the size of the transition: positive for a gap, negative for an overlap.
+    pub fn duration(self) -> Duration {
+        let seconds = self.offset_after.total_seconds() - self.offset_before.total_seconds();
+        Duration::of_seconds(seconds as i64)
+    }
+
+    /// Returns `true` if clocks are set forward, leaving a gap in the local time-line.
+    pub fn is_gap(self) -> bool {
+        self.offset_after.total_seconds() > self.offset_before.total_seconds()
+    }
+
+    /// Returns `true` if clocks are set back, so some local date-times occur twice.
+    pub fn is_overlap(self) -> bool {
+        self.offset_after.total_seconds() < self.offset_before.total_seconds()
+    }
+
+    /// Returns `true` if `offset` is valid for local date-times during this transition:
+    /// never for a gap, both offsets for an overlap.
+    pub fn is_valid_offset(self, offset: ZoneOffset) -> bool {
+        self.is_overlap() && (offset == self.offset_before || offset == self.offset_after)
+    }
+}
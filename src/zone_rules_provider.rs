@@ -0,0 +1,196 @@
+use crate::ZoneRules;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+static PROVIDERS: RwLock<Vec<Arc<dyn ZoneRulesProvider>>> = RwLock::new(Vec::new());
+/// The rules of every provided zone, from the most recently registered provider that has
+/// them, read once when a provider is registered.
+static PROVIDED_RULES: RwLock<BTreeMap<String, ZoneRules>> = RwLock::new(BTreeMap::new());
+/// Whether a provider has been registered, so that lookups skip the locks until then.
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// A source of time-zone rules, in the style of `java.time.zone.ZoneRulesProvider`.
+///
+/// Providers registered with [`ZoneId::register_provider`](crate::ZoneId::register_provider)
+/// are consulted before the tz database compiled into this crate, so parsing a `ZoneId` and
+/// every conversion use the most recently registered rules for each ID.
+pub trait ZoneRulesProvider: Send + Sync {
+    /// Returns the region IDs this provider has rules for.
+    fn zone_ids(&self) -> Vec<String>;
+
+    /// Returns the rules for the region ID, or `None` if this provider does not know it.
+    ///
+    /// This is called once for each of the [`zone_ids`](ZoneRulesProvider::zone_ids) when
+    /// the provider is registered, and the rules are kept from then on.
+    fn rules(&self, zone_id: &str) -> Option<ZoneRules>;
+
+    /// Returns the version of the tz database the rules come from, such as `2024a`, if known.
+    fn version(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A [`ZoneRulesProvider`] serving rules read from compiled TZif files.
+///
+/// ```rust
+/// let provider = TzifZoneRulesProvider::system().unwrap();
+/// println!("tzdata {:?}", provider.version());
+/// ZoneId::register_provider(provider);
+///
+/// let paris = ZoneId::try_of("Europe/Paris").unwrap(); // now uses the system tzdata
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TzifZoneRulesProvider {
+    zones: HashMap<String, ZoneRules>,
+    version: Option<String>,
+}
+
+impl TzifZoneRulesProvider {
+    /// Creates a provider without any zones. Add zones with [`TzifZoneRulesProvider::with_zone`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the system zoneinfo directory: `$TZDIR` if set, otherwise `/usr/share/zoneinfo`.
+    ///
+    /// # Errors
+    /// Returns the I/O error if the directory cannot be read.
+    pub fn system() -> io::Result<Self> {
+        let directory = std::env::var_os("TZDIR").map_or_else(|| PathBuf::from("/usr/share/zoneinfo"), PathBuf::from);
+        Self::from_directory(directory)
+    }
+
+    /// Reads every TZif file below `directory`, using the relative path as the zone ID
+    /// (`America/New_York`). Files that are not TZif files are skipped, as are the `posix` and
+    /// `right` copies of the database, symbolic links back to a directory being read, and
+    /// entries that cannot be read for lack of permission or because they do not exist, such
+    /// as dangling symbolic links.
+    ///
+    /// The tzdb version is taken from a `+VERSION` file or from the header of `tzdata.zi`.
+    ///
+    /// # Errors
+    /// Returns the I/O error if `directory` itself cannot be read or another I/O error occurs,
+    /// or `InvalidData` if a file starts like a TZif file but cannot be parsed.
+    pub fn from_directory(directory: impl AsRef<Path>) -> io::Result<Self> {
+        let directory = directory.as_ref();
+        let mut provider = Self::new();
+        provider.load_directory(directory, directory, &mut vec![fs::canonicalize(directory)?])?;
+        provider.version = read_version(directory);
+        Ok(provider)
+    }
+
+    /// Adds the zone `zone_id` with rules read from the content of a TZif file.
+    ///
+    /// # Errors
+    /// Returns the error of [`ZoneRules::from_tzif`] if the content cannot be parsed.
+    pub fn with_zone(mut self, zone_id: &str, tzif: &[u8]) -> Result<Self, &'static str> {
+        self.zones.insert(zone_id.to_string(), ZoneRules::from_tzif(tzif)?);
+        Ok(self)
+    }
+
+    /// Sets the tzdb version reported by this provider.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Reads the files below `directory`. `ancestors` holds the canonical paths of the
+    /// directories being read, to skip symbolic links that would loop back to one of them.
+    fn load_directory(&mut self, root: &Path, directory: &Path, ancestors: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(directory)? {
+            let Some(entry) = skip_unreadable(entry)? else { continue };
+            let path = entry.path();
+            if path.is_dir() {
+                if directory == root && path.file_name().is_some_and(|name| name == "posix" || name == "right") {
+                    continue;
+                }
+                let Some(canonical) = skip_unreadable(fs::canonicalize(&path))? else { continue };
+                if ancestors.contains(&canonical) {
+                    continue;
+                }
+                ancestors.push(canonical);
+                let loaded = self.load_directory(root, &path, ancestors);
+                ancestors.pop();
+                skip_unreadable(loaded)?;
+                continue;
+            }
+            let Some(bytes) = skip_unreadable(fs::read(&path))? else { continue };
+            if !bytes.starts_with(b"TZif") {
+                continue;
+            }
+            let rules = ZoneRules::from_tzif(&bytes)
+                .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {message}", path.display())))?;
+            let id = path
+                .strip_prefix(root)
+                .expect("path below the root directory")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            self.zones.insert(id, rules);
+        }
+        Ok(())
+    }
+}
+
+impl ZoneRulesProvider for TzifZoneRulesProvider {
+    fn zone_ids(&self) -> Vec<String> {
+        self.zones.keys().cloned().collect()
+    }
+
+    fn rules(&self, zone_id: &str) -> Option<ZoneRules> {
+        self.zones.get(zone_id).cloned()
+    }
+
+    fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+}
+
+/// Turns the errors of entries a zoneinfo tree may hold but cannot be read, for lack of
+/// permission or because they do not exist, into `None`.
+fn skip_unreadable<T>(result: io::Result<T>) -> io::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if matches!(error.kind(), io::ErrorKind::PermissionDenied | io::ErrorKind::NotFound) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn read_version(directory: &Path) -> Option<String> {
+    if let Ok(text) = fs::read_to_string(directory.join("+VERSION")) {
+        return Some(text.trim().to_string());
+    }
+    let text = fs::read_to_string(directory.join("tzdata.zi")).ok()?;
+    let version = text.lines().next()?.strip_prefix("# version ")?;
+    Some(version.trim().to_string())
+}
+
+pub(crate) fn register(provider: Arc<dyn ZoneRulesProvider>) {
+    let rules = provider.zone_ids().into_iter().filter_map(|id| provider.rules(&id).map(|rules| (id, rules)));
+    PROVIDED_RULES.write().unwrap_or_else(PoisonError::into_inner).extend(rules);
+    PROVIDERS.write().unwrap_or_else(PoisonError::into_inner).push(provider);
+    REGISTERED.store(true, Ordering::Release);
+}
+
+/// Returns the rules for `zone_id` from the most recently registered provider that has them.
+pub(crate) fn provided_rules(zone_id: &str) -> Option<ZoneRules> {
+    if !REGISTERED.load(Ordering::Acquire) {
+        return None;
+    }
+    PROVIDED_RULES.read().unwrap_or_else(PoisonError::into_inner).get(zone_id).cloned()
+}
+
+pub(crate) fn provided_zone_ids() -> Vec<String> {
+    PROVIDED_RULES.read().unwrap_or_else(PoisonError::into_inner).keys().cloned().collect()
+}
+
+/// Returns the version of the most recently registered provider that reports one.
+pub(crate) fn provided_version() -> Option<String> {
+    let providers = PROVIDERS.read().unwrap_or_else(PoisonError::into_inner);
+    providers.iter().rev().find_map(|provider| provider.version())
+}
@@ -1,19 +1,34 @@
-use crate::{Clock, DayOfWeek, Duration, Instant, LocalDate, LocalDateTime, LocalTime, Month, TemporalInstant, Year, ZoneId};
+use crate::{Clock, DateTimeFormatter, DateTimeParseError, DayOfWeek, Duration, Instant, LocalDate, LocalDateTime, LocalTime, Month, OffsetConflict, OffsetDateTime, TemporalInstant, TextStyle, Year, ZoneId, ZoneOffset, ZoneResolver};
 use std::fmt;
-use time::UtcOffset;
+use time::{PrimitiveDateTime, UtcOffset};
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub struct ZonedDateTime(
-    #[cfg_attr(feature = "serde", serde(with = "time::serde::rfc3339"))]
-    time::OffsetDateTime
-);
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A date-time with a time zone, in the style of `java.time.ZonedDateTime`.
+///
+/// A `ZonedDateTime` keeps the `ZoneId` it was created with together with the offset
+/// that the zone's rules give for the local date-time. Date-based arithmetic (years,
+/// months, weeks, days) and the `with_*` methods work on the local date-time and then
+/// re-resolve the offset in the zone, so adding a week across a daylight-saving change
+/// keeps the same wall-clock time. Time-based arithmetic (hours and smaller) works on
+/// the instant.
+///
+/// Values are ordered like `java.time.ZonedDateTime.compareTo`: by instant, then by local
+/// date-time, then by zone ID. Two values at the same instant in different zones are not
+/// equal; use [`ZonedDateTime::is_before`] and the related methods to compare instants only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonedDateTime {
+    date_time: time::OffsetDateTime,
+    zone: ZoneId,
+}
 
 impl ZonedDateTime {
+    /// Returns the current date-time in the system default zone, see [`ZoneId::system_default`].
+    pub fn now() -> Self {
+        Instant::now().at_zone(ZoneId::system_default())
+    }
+
     pub fn now_utc() -> Self {
         Instant::now().at_zone(ZoneId::UTC)
     }
@@ -23,32 +38,157 @@ impl ZonedDateTime {
     }
 
     pub fn now_with_zone(zone: ZoneId) -> Self {
-        Self(time::OffsetDateTime::now_utc().to_offset(UtcOffset::from_whole_seconds(zone.to_offset()).expect("invalid zone id")))
+        Instant::now().at_zone(zone)
     }
 
     pub fn new(ldt: LocalDateTime, zone: ZoneId) -> Self {
-        let pdt: time::PrimitiveDateTime = ldt.inner();
-        let offset = UtcOffset::from_whole_seconds(zone.to_offset())
-            .expect("invalid UTC offset");
-        Self(pdt.assume_offset(offset))
+        Self::resolve_local(ldt.inner(), zone, None)
     }
 
     pub fn new_utc(ldt: LocalDateTime) -> Self {
-        let pdt: time::PrimitiveDateTime = ldt.inner();
-        let offset = UtcOffset::from_whole_seconds(ZoneId::UTC.to_offset())
-            .expect("invalid UTC offset");
-        Self(pdt.assume_offset(offset))
+        Self::resolve_local(ldt.inner(), ZoneId::UTC, None)
     }
 
     pub fn of(ldt: LocalDateTime, zone: ZoneId) -> Self {
-        let pdt: time::PrimitiveDateTime = ldt.inner();
-        let offset = UtcOffset::from_whole_seconds(zone.to_offset())
-            .expect("invalid UTC offset");
-        Self(pdt.assume_offset(offset))
+        Self::resolve_local(ldt.inner(), zone, None)
+    }
+
+    /// Obtains a `ZonedDateTime` from a local date-time, choosing what happens when the
+    /// local date-time falls in a daylight-saving gap or overlap.
+    ///
+    /// `ZonedDateTime::of` is equivalent to passing `ZoneResolver::ShiftForward`.
+    ///
+    /// ### Errors
+    /// Returns an error only for `ZoneResolver::Strict` when the local date-time is in a
+    /// gap or an overlap.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let ldt = LocalDateTime::of(2024, 11, 3, 1, 30, 0);
+    /// let zdt = ZonedDateTime::of_with_resolver(ldt, new_york, ZoneResolver::LaterOffset).unwrap();
+    /// assert_eq!(zdt.offset(), ZoneOffset::of_hours(-5));
+    /// ```
+    pub fn of_with_resolver(ldt: LocalDateTime, zone: ZoneId, resolver: ZoneResolver) -> Result<Self, &'static str> {
+        resolver.resolve(ldt, zone)
+    }
+
+    /// Obtains a `ZonedDateTime` from an instant and a zone.
+    ///
+    /// The offset is the one the zone's rules give for the instant, so the result is
+    /// always valid and unambiguous.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let zdt = ZonedDateTime::of_instant(Instant::of_epoch_second(1_720_000_000), new_york);
+    /// assert_eq!(zdt.offset(), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn of_instant(instant: Instant, zone: ZoneId) -> Self {
+        Self::resolve_instant(instant.inner(), zone)
+    }
+
+    /// Obtains a `ZonedDateTime` from RFC 9557 text such as `2024-03-05T14:07:09+01:00[Europe/Paris]`,
+    /// the format of its `Display`: an ISO-8601 date-time with an offset followed by an
+    /// optional zone ID in brackets. Without a zone ID the zone is the offset.
+    ///
+    /// ### Panics
+    /// Panics if the text cannot be parsed, see [`ZonedDateTime::try_parse`].
+    pub fn parse(text: &str) -> Self {
+        Self::try_parse(text).expect("invalid ZonedDateTime string")
+    }
+
+    /// Obtains a `ZonedDateTime` like [`ZonedDateTime::parse`], returning an error instead of panicking.
+    ///
+    /// ### Example
+    /// ```rust
+    /// assert_eq!(ZonedDateTime::try_parse("2024-03-05T14:07:09+01:00[Europe/Paris]"), Ok(ZonedDateTime::of(LocalDateTime::of(2024, 3, 5, 14, 7, 9), ZoneId::try_of("Europe/Paris").unwrap())));
+    /// assert!(ZonedDateTime::try_parse("not a value").is_err());
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure,
+    /// including when the offset does not agree with the zone, see
+    /// [`ZonedDateTime::try_parse_with_offset_conflict`].
+    pub fn try_parse(text: &str) -> Result<Self, DateTimeParseError> {
+        Self::try_parse_with_offset_conflict(text, OffsetConflict::Reject)
+    }
+
+    /// Obtains a `ZonedDateTime` from RFC 9557 text, choosing with `offset_conflict` what
+    /// to do when the offset does not agree with the zone.
+    ///
+    /// ### Example
+    /// ```rust
+    /// // Clocks in New York went forward at 02:00 on 2024-03-10, so -05:00 no longer applies.
+    /// let text = "2024-03-10T03:30-05:00[America/New_York]";
+    ///
+    /// assert!(ZonedDateTime::try_parse(text).is_err());
+    /// let kept_instant = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferOffset).unwrap();
+    /// assert_eq!(kept_instant.to_string(), "2024-03-10T04:30-04:00[America/New_York]");
+    /// let kept_local = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferZone).unwrap();
+    /// assert_eq!(kept_local.to_string(), "2024-03-10T03:30-04:00[America/New_York]");
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns a [`DateTimeParseError`] with the position and reason of the failure.
+    pub fn try_parse_with_offset_conflict(text: &str, offset_conflict: OffsetConflict) -> Result<Self, DateTimeParseError> {
+        DateTimeFormatter::rfc_9557().with_offset_conflict(offset_conflict).parse(text)
+    }
+
+    /// Returns the time zone of this date-time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 1, 1, 12, 0, 0), ZoneId::UTC);
+    /// assert_eq!(zdt.zone(), ZoneId::UTC);
+    /// ```
+    pub fn zone(&self) -> ZoneId {
+        self.zone.clone()
+    }
+
+    /// Returns the offset from UTC that the zone's rules give for this date-time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let winter = ZonedDateTime::of(LocalDateTime::of(2024, 1, 15, 12, 0, 0), new_york.clone());
+    /// let summer = ZonedDateTime::of(LocalDateTime::of(2024, 7, 15, 12, 0, 0), new_york);
+    /// assert_eq!(winter.offset(), ZoneOffset::of_hours(-5));
+    /// assert_eq!(summer.offset(), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn offset(&self) -> ZoneOffset {
+        ZoneOffset::from(self.date_time.offset())
+    }
+
+    /// Returns the abbreviation of the zone at this date-time, such as `EST` or `EDT`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let summer = ZonedDateTime::of(LocalDateTime::of(2024, 7, 15, 12, 0, 0), new_york);
+    /// assert_eq!(summer.zone_abbreviation(), "EDT");
+    /// ```
+    pub fn zone_abbreviation(&self) -> String {
+        self.zone.abbreviation_at(self.to_instant())
+    }
+
+    /// Returns the name of the time in effect in the zone at this date-time, such as
+    /// "Eastern Daylight Time". See [`ZoneId::display_name_at`].
+    ///
+    /// ### Example
+    /// ```rust
+    /// let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    /// let winter = ZonedDateTime::of(LocalDateTime::of(2024, 1, 15, 12, 0, 0), paris);
+    /// assert_eq!(winter.zone_display_name(TextStyle::Full), "Central European Standard Time");
+    /// ```
+    pub fn zone_display_name(&self, style: TextStyle) -> String {
+        self.zone.display_name_at(self.to_instant(), style)
     }
 
     /// Determines whether the current instance is before another instance.
     ///
+    /// Only the instants are compared, so the zones may differ.
+    ///
     /// ### Arguments
     /// - `other`: A reference to the instance to compare against.
     ///
@@ -61,15 +201,17 @@ impl ZonedDateTime {
     /// let datetime1 = ZonedDateTime::now_utc();
     /// let datetime2 = datetime1.plus_hours(1);
     ///
-    /// assert!(datetime1.is_before(datetime2));
-    /// assert!(!datetime2.is_before(datetime1));
+    /// assert!(datetime1.is_before(&datetime2));
+    /// assert!(!datetime2.is_before(&datetime1));
     /// ```
-    pub fn is_before(self, other: Self) -> bool {
-        self < other
+    pub fn is_before(&self, other: &Self) -> bool {
+        self.date_time < other.date_time
     }
 
     /// Determines whether the current instance is after another instance.
     ///
+    /// Only the instants are compared, so the zones may differ.
+    ///
     /// ### Arguments
     /// - `other`: A reference to the instance to compare against.
     ///
@@ -82,15 +224,17 @@ impl ZonedDateTime {
     /// let datetime1 = ZonedDateTime::now_utc();
     /// let datetime2 = datetime1.plus_hours(1);
     ///
-    /// assert!(!datetime1.is_after(datetime2));
-    /// assert!(datetime2.is_after(datetime1));
+    /// assert!(!datetime1.is_after(&datetime2));
+    /// assert!(datetime2.is_after(&datetime1));
     /// ```
-    pub fn is_after(self, other: Self) -> bool {
-        self > other
+    pub fn is_after(&self, other: &Self) -> bool {
+        self.date_time > other.date_time
     }
 
     /// Determines whether the current instance is on or before another instance.
     ///
+    /// Only the instants are compared, so the zones may differ.
+    ///
     /// ### Arguments
     /// - `other`: A reference to the instance to compare against.
     ///
@@ -104,16 +248,18 @@ impl ZonedDateTime {
     /// let datetime2 = datetime1.plus_hours(1);
     /// let datetime3 = datetime1.minus_hours(1);
     ///
-    /// assert!(datetime1.is_on_or_before(datetime1));
-    /// assert!(datetime1.is_on_or_before(datetime2));
-    /// assert!(!datetime1.is_on_or_before(datetime3));
+    /// assert!(datetime1.is_on_or_before(&datetime1));
+    /// assert!(datetime1.is_on_or_before(&datetime2));
+    /// assert!(!datetime1.is_on_or_before(&datetime3));
     /// ```
-    pub fn is_on_or_before(self, other: Self) -> bool {
-        self <= other
+    pub fn is_on_or_before(&self, other: &Self) -> bool {
+        self.date_time <= other.date_time
     }
 
     /// Determines whether the current other is on or after another instance.
     ///
+    /// Only the instants are compared, so the zones may differ.
+    ///
     /// ### Arguments
     /// - `other`: A reference to the instance to compare against.
     ///
@@ -127,16 +273,178 @@ impl ZonedDateTime {
     /// let datetime2 = datetime1.minus_hours(1);
     /// let datetime3 = datetime1.plus_hours(1);
     ///
-    /// assert!(datetime1.is_on_or_after(datetime1));
-    /// assert!(datetime1.is_on_or_after(datetime2));
-    /// assert!(!datetime1.is_on_or_after(datetime3));
+    /// assert!(datetime1.is_on_or_after(&datetime1));
+    /// assert!(datetime1.is_on_or_after(&datetime2));
+    /// assert!(!datetime1.is_on_or_after(&datetime3));
+    /// ```
+    pub fn is_on_or_after(&self, other: &Self) -> bool {
+        self.date_time >= other.date_time
+    }
+
+    /// Converts this date-time to an instant.
+    ///
+    /// ### Panics
+    /// Panics if the instant is outside the supported range, which only happens within a day
+    /// of either end, see [`ZonedDateTime::try_to_instant`].
+    pub fn to_instant(&self) -> Instant {
+        self.try_to_instant().expect("Instant exceeds the supported range")
+    }
+
+    /// Converts this date-time to an instant.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(9999, 12, 31, 23, 0, 0), ZoneId::try_of("America/New_York").unwrap());
+    /// assert_eq!(zdt.try_to_instant(), Err("Instant exceeds the supported range"));
+    /// ```
+    ///
+    /// ### Errors
+    /// Returns an error if the instant is outside the supported range, such as the instant of
+    /// `9999-12-31T23:00-05:00`, which is in the year 10000 in UTC.
+    pub fn try_to_instant(&self) -> Result<Instant, &'static str> {
+        self.date_time.checked_to_offset(UtcOffset::UTC).map(Instant::from).ok_or("Instant exceeds the supported range")
+    }
+
+    /// Returns a copy of this date-time using the earlier of the two valid offsets when the
+    /// local date-time is in an overlap. Outside an overlap the value is returned unchanged.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let ldt = LocalDateTime::of(2024, 11, 3, 1, 30, 0);
+    /// let later = ZonedDateTime::of_with_resolver(ldt, new_york, ZoneResolver::LaterOffset).unwrap();
+    /// assert_eq!(later.with_earlier_offset_at_overlap().offset(), ZoneOffset::of_hours(-4));
+    /// ```
+    pub fn with_earlier_offset_at_overlap(&self) -> Self {
+        match self.zone.valid_offsets_at(LocalDateTime::from(self.local())).as_slice() {
+            [earlier, _] => Self::from_parts(self.local().assume_offset(earlier.inner()), self.zone.clone()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns a copy of this date-time using the later of the two valid offsets when the
+    /// local date-time is in an overlap. Outside an overlap the value is returned unchanged.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let earlier = ZonedDateTime::of(LocalDateTime::of(2024, 11, 3, 1, 30, 0), new_york);
+    /// assert_eq!(earlier.with_later_offset_at_overlap().offset(), ZoneOffset::of_hours(-5));
+    /// ```
+    pub fn with_later_offset_at_overlap(&self) -> Self {
+        match self.zone.valid_offsets_at(LocalDateTime::from(self.local())).as_slice() {
+            [_, later] => Self::from_parts(self.local().assume_offset(later.inner()), self.zone.clone()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns a copy of this date-time in a different zone, keeping the instant.
+    ///
+    /// The local date-time changes to the one the new zone shows at that instant. This is
+    /// the conversion to use for "what time is this meeting for the other participant".
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let tokyo = ZoneId::try_of("Asia/Tokyo").unwrap();
+    /// let meeting = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), new_york);
+    ///
+    /// let in_tokyo = meeting.with_zone_same_instant(tokyo);
+    /// assert_eq!(in_tokyo.to_local_date_time(), LocalDateTime::of(2024, 7, 1, 22, 0, 0));
+    /// assert_eq!(in_tokyo.to_instant(), meeting.to_instant());
+    /// ```
+    pub fn with_zone_same_instant(&self, zone: ZoneId) -> Self {
+        Self::resolve_instant(self.date_time, zone)
+    }
+
+    /// Returns a copy of this date-time in a different zone, keeping the local date-time
+    /// where possible.
+    ///
+    /// The current offset is kept if it is valid in the new zone. In an overlap the other
+    /// valid offset is otherwise chosen the way [`ZonedDateTime::of`] does (the earlier one),
+    /// and a local date-time in a gap is moved forward by the length of the gap.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), new_york);
+    ///
+    /// let same_local = zdt.with_zone_same_local(paris);
+    /// assert_eq!(same_local.to_local_date_time(), LocalDateTime::of(2024, 7, 1, 9, 0, 0));
+    /// assert_eq!(same_local.offset(), ZoneOffset::of_hours(2));
+    /// ```
+    pub fn with_zone_same_local(&self, zone: ZoneId) -> Self {
+        Self::resolve_local(self.local(), zone, Some(self.date_time.offset()))
+    }
+
+    /// Returns a copy of this date-time whose zone is its current offset, so later
+    /// arithmetic ignores daylight saving time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), new_york);
+    /// assert_eq!(zdt.with_fixed_offset_zone().zone().id(), "-04:00");
+    /// ```
+    pub fn with_fixed_offset_zone(&self) -> Self {
+        Self { date_time: self.date_time, zone: ZoneId::from(self.offset()) }
+    }
+
+    /// Converts this date-time to an `OffsetDateTime` with the same local date-time and offset,
+    /// dropping the zone.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), new_york);
+    /// let odt = zdt.to_offset_date_time();
+    /// assert_eq!(odt, OffsetDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneOffset::of_hours(-4)));
+    /// ```
+    pub fn to_offset_date_time(&self) -> OffsetDateTime {
+        OffsetDateTime::from(self.date_time)
+    }
+
+    /// Obtains a `ZonedDateTime` from a local date-time, preferring the given offset when the
+    /// local date-time is in an overlap, as `java.time.ZonedDateTime.ofLocal` does.
+    ///
+    /// If `preferred_offset` is `None` or not valid, this behaves like [`ZonedDateTime::of`].
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let ldt = LocalDateTime::of(2024, 11, 3, 1, 30, 0);
+    /// let zdt = ZonedDateTime::of_local(ldt, new_york, Some(ZoneOffset::of_hours(-5)));
+    /// assert_eq!(zdt.offset(), ZoneOffset::of_hours(-5));
     /// ```
-    pub fn is_on_or_after(self, other: Self) -> bool {
-        self >= other
+    pub fn of_local(ldt: LocalDateTime, zone: ZoneId, preferred_offset: Option<ZoneOffset>) -> Self {
+        Self::resolve_local(ldt.inner(), zone, preferred_offset.map(ZoneOffset::inner))
     }
 
-    pub fn to_instant(self) -> Instant {
-        Instant::of_epoch_second(self.0.unix_timestamp())
+    /// Obtains a `ZonedDateTime` from a local date-time, an offset and a zone, checking that
+    /// the offset is valid for the local date-time in the zone, as
+    /// `java.time.ZonedDateTime.ofStrict` does.
+    ///
+    /// ### Errors
+    /// - `"Local date-time falls in a gap in the time zone"` if the local date-time does not exist.
+    /// - `"Offset is not valid for the local date-time in the time zone"` otherwise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let new_york = ZoneId::try_of("America/New_York").unwrap();
+    /// let ldt = LocalDateTime::of(2024, 7, 1, 9, 0, 0);
+    /// assert!(ZonedDateTime::of_strict(ldt, ZoneOffset::of_hours(-4), new_york.clone()).is_ok());
+    /// assert!(ZonedDateTime::of_strict(ldt, ZoneOffset::of_hours(-5), new_york).is_err());
+    /// ```
+    pub fn of_strict(ldt: LocalDateTime, offset: ZoneOffset, zone: ZoneId) -> Result<Self, &'static str> {
+        let valid = zone.valid_offsets_at(ldt);
+        if valid.contains(&offset) {
+            Ok(Self::from_parts(ldt.inner().assume_offset(offset.inner()), zone))
+        } else if valid.is_empty() {
+            Err("Local date-time falls in a gap in the time zone")
+        } else {
+            Err("Offset is not valid for the local date-time in the time zone")
+        }
     }
 
     /// Converts the current instance into a `LocalDate`.
@@ -150,8 +458,8 @@ impl ZonedDateTime {
     /// let local_date = date_time.to_local_date();
     /// println!("{}", local_date);
     /// ```
-    pub fn to_local_date(self) -> LocalDate {
-        LocalDate::from(self.0.date())
+    pub fn to_local_date(&self) -> LocalDate {
+        LocalDate::from(self.date_time.date())
     }
 
     /// Converts the current instance into a `LocalTime`.
@@ -165,8 +473,8 @@ impl ZonedDateTime {
     /// let local_time = date_time.to_local_time();
     /// println!("{}", local_time);
     /// ```
-    pub fn to_local_time(self) -> LocalTime {
-        LocalTime::from(self.0.time())
+    pub fn to_local_time(&self) -> LocalTime {
+        LocalTime::from(self.date_time.time())
     }
 
     /// Converts the current instance into a `LocalDateTime`.
@@ -180,8 +488,8 @@ impl ZonedDateTime {
     /// let local_date_time = date_time.to_local_date_time();
     /// println!("{}", local_date_time);
     /// ```
-    pub fn to_local_date_time(self) -> LocalDateTime {
-        LocalDateTime::from(time::PrimitiveDateTime::new(self.0.date(), self.0.time()))
+    pub fn to_local_date_time(&self) -> LocalDateTime {
+        LocalDateTime::from(self.local())
     }
 
     /// Returns the number of seconds from the Unix Epoch.
@@ -199,8 +507,8 @@ impl ZonedDateTime {
     /// let epoch_seconds = ZonedDateTime.now_utc().to_epoch_seconds();
     /// println!("Epoch seconds: {}", epoch_seconds);
     /// ```
-    pub fn epoch_seconds(self) -> i64 {
-        self.0.unix_timestamp()
+    pub fn epoch_seconds(&self) -> i64 {
+        self.date_time.unix_timestamp()
     }
 
     /// Returns the number of milliseconds elapsed since the Unix Epoch.
@@ -219,9 +527,9 @@ impl ZonedDateTime {
     /// let milliseconds = timestamp.epoch_milliseconds();
     /// println!("Epoch time in milliseconds: {}", milliseconds);
     /// ```
-    fn epoch_milliseconds(self) -> i128 {
+    fn epoch_milliseconds(&self) -> i128 {
         self.epoch_seconds() as i128 * 1_000
-            + (self.epoch_nanoseconds() % 1_000_000_000) as i128 / 1_000
+            + (self.epoch_nanoseconds() % 1_000_000_000) / 1_000
     }
 
     /// Returns the number of nanoseconds elapsed since the Unix Epoch.
@@ -239,8 +547,8 @@ impl ZonedDateTime {
     /// let nanoseconds = ZonedDateTime.now_utc().epoch_nanoseconds();
     /// println!("Epoch time in nanoseconds: {}", nanoseconds);
     /// ```
-    pub fn epoch_nanoseconds(self) -> i128 {
-        self.0.unix_timestamp_nanos()
+    pub fn epoch_nanoseconds(&self) -> i128 {
+        self.date_time.unix_timestamp_nanos()
     }
 
     /// Returns the year component of the date.
@@ -256,8 +564,8 @@ impl ZonedDateTime {
     /// let year = ZonedDateTime::now_utc().year();
     /// println!("Year: {}", year);
     /// ```
-    pub fn year(self) -> i32 {
-        self.0.year()
+    pub fn year(&self) -> i32 {
+        self.date_time.year()
     }
 
     /// Returns the `Month` corresponding to the month component of the date.
@@ -270,8 +578,8 @@ impl ZonedDateTime {
     /// let month = ZonedDateTime::now_utc().month();
     /// println!("Month: {}", month);
     /// ```
-    pub fn month(self) -> Month {
-        self.0.month().into()
+    pub fn month(&self) -> Month {
+        self.date_time.month().into()
     }
 
     /// Returns the numerical value associated with the month of the date.
@@ -284,8 +592,8 @@ impl ZonedDateTime {
     /// let month_value = ZonedDateTime::now_utc().month_value();
     /// println!("Month value: {}", month_value);
     /// ```
-    pub fn month_value(self) -> i32 {
-        u8::from(self.0.month()) as i32
+    pub fn month_value(&self) -> i32 {
+        u8::from(self.date_time.month()) as i32
     }
 
     /// Returns the day of the year of the date.
@@ -302,8 +610,8 @@ impl ZonedDateTime {
     /// let day_of_year = ZonedDateTime::now_utc().day_of_year();
     /// println!("Day of year: {}", day_of_year);
     /// ```
-    pub fn day_of_year(self) -> i32 {
-        self.0.ordinal() as i32
+    pub fn day_of_year(&self) -> i32 {
+        self.date_time.ordinal() as i32
     }
 
     /// Returns the day of the month of the date.
@@ -316,8 +624,8 @@ impl ZonedDateTime {
     /// let day_of_month = ZonedDateTime::now_utc().day_of_month();
     /// println!("Day of month: {}", day_of_month);
     /// ```
-    pub fn day_of_month(self) -> i32 {
-        self.0.day() as i32
+    pub fn day_of_month(&self) -> i32 {
+        self.date_time.day() as i32
     }
 
     /// Returns the `DayOfWeek` of the date.
@@ -330,8 +638,8 @@ impl ZonedDateTime {
     /// let day_of_week = ZonedDateTime::now_utc().day_of_week();
     /// println!("Day of week: {}", day_of_week);
     /// ```
-    pub fn day_of_week(self) -> DayOfWeek {
-        self.0.weekday().into()
+    pub fn day_of_week(&self) -> DayOfWeek {
+        self.date_time.weekday().into()
     }
 
     /// Returns the number of days in the month of the date.
@@ -347,8 +655,8 @@ impl ZonedDateTime {
     /// let date = ZonedDateTime::now_utc();
     /// println!("Days in month: {}", date.length_of_month());
     /// ```
-    pub fn length_of_month(self) -> i32 {
-        self.0.month().length(self.0.year()) as i32
+    pub fn length_of_month(&self) -> i32 {
+        self.date_time.month().length(self.date_time.year()) as i32
     }
 
     /// Determines if the current year represented by the date is a leap year.
@@ -366,8 +674,8 @@ impl ZonedDateTime {
     /// assert!(ZonedDateTime::of(2024, 3, 26, 0, 0, 0).is_leap_year()); // 2024 is a leap year.
     /// assert!(!ZonedDateTime::of(2023, 3, 26, 0, 0, 0).is_leap_year()); // 2023 is not a leap year.
     /// ```
-    pub fn is_leap_year(self) -> bool {
-        Year::of(self.0.year()).is_leap()
+    pub fn is_leap_year(&self) -> bool {
+        Year::of(self.date_time.year()).is_leap()
     }
 
     /// Returns the number of days in the year of the date.
@@ -383,8 +691,8 @@ impl ZonedDateTime {
     /// let days_in_year = date.length_of_year();
     /// println!("The year {} has {} days.", year, days_in_year);
     /// ```
-    pub fn length_of_year(self) -> i32 {
-        Year::of(self.0.year()).length()
+    pub fn length_of_year(&self) -> i32 {
+        Year::of(self.date_time.year()).length()
     }
 
     /// Returns the hour component of the time.
@@ -397,8 +705,8 @@ impl ZonedDateTime {
     /// let hour = ZonedDateTime::now_utc().hour();
     /// println!("Hour value: {}", hour);
     /// ```
-    pub fn hour(self) -> i32 {
-        self.0.hour() as i32
+    pub fn hour(&self) -> i32 {
+        self.date_time.hour() as i32
     }
 
     /// Returns the minute component of the time.
@@ -411,8 +719,8 @@ impl ZonedDateTime {
     /// let minute = ZonedDateTime::now_utc().minute();
     /// println!("Minute value: {}", minute);
     /// ```
-    pub fn minute(self) -> i32 {
-        self.0.minute() as i32
+    pub fn minute(&self) -> i32 {
+        self.date_time.minute() as i32
     }
 
     /// Returns the second component of the time.
@@ -425,8 +733,8 @@ impl ZonedDateTime {
     /// let second = ZonedDateTime::now_utc().second();
     /// println!("Second value: {}", second);
     /// ```
-    pub fn second(self) -> i32 {
-        self.0.second() as i32
+    pub fn second(&self) -> i32 {
+        self.date_time.second() as i32
     }
 
     /// Returns the millisecond component of the time.
@@ -440,8 +748,8 @@ impl ZonedDateTime {
     /// let milliseconds = time.millisecond();
     /// println!("Milliseconds: {}", milliseconds);
     /// ```
-    pub fn millisecond(self) -> i32 {
-        self.0.millisecond() as i32
+    pub fn millisecond(&self) -> i32 {
+        self.date_time.millisecond() as i32
     }
 
     /// Returns the nanosecond component of the time.
@@ -454,30 +762,23 @@ impl ZonedDateTime {
     /// let nanoseconds = ZonedDateTime::now_utc().nanosecond();
     /// println!("Nanoseconds value: {}", nanoseconds);
     /// ```
-    pub fn nanosecond(self) -> i32 {
-        self.0.nanosecond() as i32
+    pub fn nanosecond(&self) -> i32 {
+        self.date_time.nanosecond() as i32
     }
 
-    pub fn plus_years(self, years: i64) -> Self {
-        let date = self.0.date();
-        let time = self.0.time();
-        let pdt = time::PrimitiveDateTime::new(date, time);
-        let ldt: LocalDateTime = LocalDateTime::from(pdt);
-        let ldt2 = ldt.plus_years(years);
-        let pdt2: time::PrimitiveDateTime = ldt2.inner();
-        let off = self.0.offset();
-        Self(pdt2.assume_offset(off))
+    pub fn plus_years(&self, years: i64) -> Self {
+        let ldt = LocalDateTime::from(self.local()).plus_years(years);
+        self.with_local(ldt.inner())
     }
 
-    pub fn plus_months(self, months: i64) -> Self {
-        let date = self.0.date();
-        let time = self.0.time();
-        let offset = self.0.offset();
+    pub fn plus_months(&self, months: i64) -> Self {
+        let date = self.date_time.date();
+        let time = self.date_time.time();
 
         // Convert the current date to total months since year 0
         let total_months = date.year() as i64 * 12 + (date.month() as i64 - 1) + months;
-        let new_year = (total_months / 12) as i32;
-        let new_month_index = (total_months % 12 + 12) % 12 + 1; // Month index in 1..=12
+        let new_year = total_months.div_euclid(12) as i32;
+        let new_month_index = total_months.rem_euclid(12) + 1; // Month index in 1..=12
         let new_month = time::Month::try_from(new_month_index as u8).expect("invalid month index");
 
         // Clamp the day to the maximum day of the new month
@@ -486,135 +787,222 @@ impl ZonedDateTime {
         let new_date = time::Date::from_calendar_date(new_year, new_month, new_day)
             .expect("valid date guaranteed by clamping");
 
-        let pdt = time::PrimitiveDateTime::new(new_date, time);
-        Self(pdt.assume_offset(offset))
+        self.with_local(PrimitiveDateTime::new(new_date, time))
     }
 
-    pub fn plus_weeks(self, weeks: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_weeks(weeks).inner()).expect("Date overflow in plus_weeks"))
+    pub fn plus_weeks(&self, weeks: i64) -> Self {
+        self.with_local(self.local().checked_add(Duration::of_weeks(weeks).inner()).expect("Date overflow in plus_weeks"))
     }
 
-    pub fn plus_days(self, days: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_days(days).inner()).expect("Date overflow in plus_days"))
+    pub fn plus_days(&self, days: i64) -> Self {
+        self.with_local(self.local().checked_add(Duration::of_days(days).inner()).expect("Date overflow in plus_days"))
     }
 
-    pub fn plus_hours(self, hours: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_hours(hours).inner()).expect("Date overflow in plus_hours"))
+    pub fn plus_hours(&self, hours: i64) -> Self {
+        self.with_instant(self.date_time.checked_add(Duration::of_hours(hours).inner()).expect("Date overflow in plus_hours"))
     }
 
-    pub fn plus_minutes(self, minutes: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_minutes(minutes).inner()).expect("Date overflow in plus_minutes"))
+    pub fn plus_minutes(&self, minutes: i64) -> Self {
+        self.with_instant(self.date_time.checked_add(Duration::of_minutes(minutes).inner()).expect("Date overflow in plus_minutes"))
     }
 
-    pub fn plus_seconds(self, seconds: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_seconds(seconds).inner()).expect("Date overflow in plus_seconds"))
+    pub fn plus_seconds(&self, seconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_add(Duration::of_seconds(seconds).inner()).expect("Date overflow in plus_seconds"))
     }
 
-    pub fn plus_milliseconds(self, milliseconds: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_milliseconds(milliseconds).inner()).expect("Date overflow in plus_milliseconds"))
+    pub fn plus_milliseconds(&self, milliseconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_add(Duration::of_milliseconds(milliseconds).inner()).expect("Date overflow in plus_milliseconds"))
     }
 
-    pub fn plus_nanoseconds(self, nanoseconds: i64) -> Self {
-        Self(self.0.checked_add(Duration::of_nanoseconds(nanoseconds).inner()).expect("Date overflow in plus_nanoseconds"))
+    pub fn plus_nanoseconds(&self, nanoseconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_add(Duration::of_nanoseconds(nanoseconds).inner()).expect("Date overflow in plus_nanoseconds"))
     }
 
-    pub fn minus_years(self, years: i64) -> Self {
+    pub fn minus_years(&self, years: i64) -> Self {
         self.plus_years(-years)
     }
 
-    pub fn minus_months(self, months: i64) -> Self {
+    pub fn minus_months(&self, months: i64) -> Self {
         self.plus_months(-months)
     }
 
-    pub fn minus_weeks(self, weeks: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_weeks(weeks).inner()).expect("Date overflow in minus_weeks"))
+    pub fn minus_weeks(&self, weeks: i64) -> Self {
+        self.with_local(self.local().checked_sub(Duration::of_weeks(weeks).inner()).expect("Date overflow in minus_weeks"))
+    }
+
+    pub fn minus_days(&self, days: i64) -> Self {
+        self.with_local(self.local().checked_sub(Duration::of_days(days).inner()).expect("Date overflow in minus_days"))
+    }
+
+    pub fn minus_hours(&self, hours: i64) -> Self {
+        self.with_instant(self.date_time.checked_sub(Duration::of_hours(hours).inner()).expect("Date overflow in minus_hours"))
+    }
+
+    pub fn minus_minutes(&self, minutes: i64) -> Self {
+        self.with_instant(self.date_time.checked_sub(Duration::of_minutes(minutes).inner()).expect("Date overflow in minus_minutes"))
+    }
+
+    pub fn minus_seconds(&self, seconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_sub(Duration::of_seconds(seconds).inner()).expect("Date overflow in minus_seconds"))
+    }
+
+    pub fn minus_milliseconds(&self, milliseconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_sub(Duration::of_milliseconds(milliseconds).inner()).expect("Date overflow in minus_milliseconds"))
+    }
+
+    pub fn minus_nanoseconds(&self, nanoseconds: i64) -> Self {
+        self.with_instant(self.date_time.checked_sub(Duration::of_nanoseconds(nanoseconds).inner()).expect("Date overflow in minus_nanoseconds"))
+    }
+
+    pub fn with_year(&self, year: i32) -> Self {
+        self.with_local(self.local().replace_year(year).expect("invalid year"))
     }
 
-    pub fn minus_days(self, days: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_days(days).inner()).expect("Date overflow in minus_days"))
+    pub fn with_month(&self, month: i32) -> Self {
+        self.with_local(self.local().replace_month(Month::of(month).into()).expect("invalid month"))
     }
 
-    pub fn minus_hours(self, hours: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_hours(hours).inner()).expect("Date overflow in minus_hours"))
+    pub fn with_day_of_year(&self, day_of_year: u16) -> Self {
+        self.with_local(self.local().replace_ordinal(day_of_year).expect("invalid day of year"))
     }
 
-    pub fn minus_minutes(self, minutes: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_minutes(minutes).inner()).expect("Date overflow in minus_minutes"))
+    pub fn with_day_of_month(&self, day: u8) -> Self {
+        self.with_local(self.local().replace_day(day).expect("invalid day"))
     }
 
-    pub fn minus_seconds(self, seconds: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_seconds(seconds).inner()).expect("Date overflow in minus_seconds"))
+    pub fn with_hour(&self, hour: u8) -> Self {
+        self.with_local(self.local().replace_hour(hour).expect("invalid hour"))
     }
 
-    pub fn minus_milliseconds(self, milliseconds: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_milliseconds(milliseconds).inner()).expect("Date overflow in minus_milliseconds"))
+    pub fn with_minute(&self, minute: u8) -> Self {
+        self.with_local(self.local().replace_minute(minute).expect("invalid minute"))
     }
 
-    pub fn minus_nanoseconds(self, nanoseconds: i64) -> Self {
-        Self(self.0.checked_sub(Duration::of_nanoseconds(nanoseconds).inner()).expect("Date overflow in minus_nanoseconds"))
+    pub fn with_second(&self, second: u8) -> Self {
+        self.with_local(self.local().replace_second(second).expect("invalid second"))
     }
 
-    pub fn with_year(self, year: i32) -> Self {
-        Self(self.0.replace_year(year).expect("invalid year"))
+    pub fn with_millisecond(&self, millisecond: u16) -> Self {
+        self.with_local(self.local().replace_millisecond(millisecond).expect("invalid millisecond"))
     }
 
-    pub fn with_month(self, month: i32) -> Self {
-        Self(self.0.replace_month(Month::of(month).into()).expect("invalid month"))
+    pub fn with_nanosecond(&self, nanosecond: u32) -> Self {
+        self.with_local(self.local().replace_nanosecond(nanosecond).expect("invalid nanosecond"))
     }
 
-    pub fn with_day_of_year(self, day_of_year: u16) -> Self {
-        Self(self.0.replace_ordinal(day_of_year).expect("invalid day of year"))
+    pub(crate) fn from_parts(date_time: time::OffsetDateTime, zone: ZoneId) -> Self {
+        Self { date_time, zone }
     }
 
-    pub fn with_day_of_month(self, day: u8) -> Self {
-        Self(self.0.replace_day(day).expect("invalid day"))
+    /// Resolves a local date-time in `zone`, keeping `preferred` in an overlap when it is valid.
+    fn resolve_local(local: PrimitiveDateTime, zone: ZoneId, preferred: Option<UtcOffset>) -> Self {
+        Self { date_time: zone.resolve_local(local, preferred), zone }
     }
 
-    pub fn with_hour(self, hour: u8) -> Self {
-        Self(self.0.replace_hour(hour).expect("invalid hour"))
+    /// Places an instant in `zone` using the offset the zone gives for that instant.
+    fn resolve_instant(instant: time::OffsetDateTime, zone: ZoneId) -> Self {
+        Self { date_time: instant.to_offset(zone.offset_at(instant)), zone }
     }
 
-    pub fn with_minute(self, minute: u8) -> Self {
-        Self(self.0.replace_minute(minute).expect("invalid minute"))
+    fn local(&self) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(self.date_time.date(), self.date_time.time())
     }
 
-    pub fn with_second(self, second: u8) -> Self {
-        Self(self.0.replace_second(second).expect("invalid second"))
+    fn with_local(&self, local: PrimitiveDateTime) -> Self {
+        Self::resolve_local(local, self.zone.clone(), Some(self.date_time.offset()))
     }
 
-    pub fn with_millisecond(self, millisecond: u16) -> Self {
-        Self(self.0.replace_millisecond(millisecond).expect("invalid millisecond"))
+    /// Places an instant in `zone` like [`ZonedDateTime::of_instant`], or returns `None` if
+    /// the instant or its local date-time in `zone` is outside the supported range.
+    pub(crate) fn checked_resolve_instant(instant: time::OffsetDateTime, zone: ZoneId) -> Option<Self> {
+        let instant = instant.checked_to_offset(UtcOffset::UTC)?;
+        Some(Self { date_time: instant.checked_to_offset(zone.offset_at(instant))?, zone })
     }
 
-    pub fn with_nanosecond(self, nanosecond: u32) -> Self {
-        Self(self.0.replace_nanosecond(nanosecond).expect("invalid nanosecond"))
+    fn with_instant(&self, instant: time::OffsetDateTime) -> Self {
+        Self::resolve_instant(instant, self.zone.clone())
     }
+}
 
-    pub(crate) fn from(inner: time::OffsetDateTime) -> Self {
-        Self(inner)
+impl PartialOrd for ZonedDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    // pub(crate) fn inner(self) -> time::OffsetDateTime {
-    //     self.0
-    // }
+impl Ord for ZonedDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.date_time
+            .cmp(&other.date_time)
+            .then_with(|| self.local().cmp(&other.local()))
+            .then_with(|| self.zone.cmp(&other.zone))
+    }
 }
 
 impl TemporalInstant for ZonedDateTime {
     fn epoch_seconds(self) -> i64 {
-        Self::epoch_seconds(self)
+        Self::epoch_seconds(&self)
     }
 
     fn epoch_milliseconds(self) -> i128 {
-        Self::epoch_milliseconds(self)
+        Self::epoch_milliseconds(&self)
     }
 
     fn epoch_nanoseconds(self) -> i128 {
-        Self::epoch_nanoseconds(self)
+        Self::epoch_nanoseconds(&self)
     }
 }
 
+/// Formats the date-time like `java.time.ZonedDateTime.toString`: the ISO-8601 date-time
+/// and offset, followed by the zone ID in brackets unless the zone is just that offset, such
+/// as `2024-01-01T12:00+01:00[Europe/Paris]` or `2024-01-01T12:00Z`.
 impl fmt::Display for ZonedDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        let offset = self.offset();
+        write!(f, "{}{}", self.to_local_date_time(), offset)?;
+        if self.zone.id() != offset.id() {
+            write!(f, "[{}]", self.zone)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ZonedDateTime {
+    type Err = DateTimeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(text)
+    }
+}
+
+impl TryFrom<&str> for ZonedDateTime {
+    type Error = DateTimeParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Self::try_parse(text)
+    }
+}
+
+/// Serialized as an RFC 3339 string. RFC 3339 only carries an offset, so a deserialized
+/// value is placed in the UTC zone at the same instant. Use
+/// [`serde_time::rfc9557`](crate::serde_time::rfc9557) to keep the zone.
+#[cfg(feature = "serde")]
+impl Serialize for ZonedDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        time::serde::rfc3339::serialize(&self.date_time, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ZonedDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let odt = time::serde::rfc3339::deserialize(deserializer)?;
+        Ok(Self::resolve_instant(odt, ZoneId::UTC))
     }
 }
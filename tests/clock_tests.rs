@@ -17,15 +17,15 @@ mod tests {
     #[test]
     fn system_sets_zone() {
         let zid = ZoneId::try_of("America/New_York").unwrap();
-        let c = Clock::system(zid);
-        Clock::fixed(Instant::now(), zid);
+        let c = Clock::system(zid.clone());
+        Clock::fixed(Instant::now(), zid.clone());
         assert_eq!(c.zone(), zid);
     }
 
     #[test]
-    fn system_default_zone_is_utc() {
+    fn system_default_zone_uses_system_default() {
         let c = Clock::system_default_zone();
-        assert_eq!(c.zone(), ZoneId::UTC);
+        assert_eq!(c.zone(), ZoneId::system_default());
     }
 
     #[test]
@@ -94,10 +94,10 @@ mod tests {
         let z1 = ZoneId::try_of("Europe/London").unwrap();
         let z2 = ZoneId::try_of("Asia/Tokyo").unwrap();
 
-        let sys = Clock::system(z1);
+        let sys = Clock::system(z1.clone());
         assert_eq!(sys.zone(), z1);
 
-        let sys2 = sys.with_zone(z2);
+        let sys2 = sys.with_zone(z2.clone());
 
         // Variant should remain System
         assert!(matches!(sys2, Clock::System(_)));
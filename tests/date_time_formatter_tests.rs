@@ -0,0 +1,123 @@
+mod tests {
+    use joda_rs::{
+        DateTimeFormatter, Instant, LocalDate, LocalDateTime, LocalTime, Month, MonthDay, OffsetDateTime, TemporalAccessor,
+        Year, YearMonth, ZoneId, ZoneOffset, ZonedDateTime,
+    };
+
+    fn zone(id: &str) -> ZoneId {
+        ZoneId::try_of(id).unwrap()
+    }
+
+    #[test]
+    fn formats_numeric_and_text_fields() {
+        let ldt = LocalDateTime::of_date_time(LocalDate::of(2024, 3, 5), LocalTime::of_hms_nano(14, 7, 9, 123_456_789));
+
+        assert_eq!(DateTimeFormatter::of_pattern("yyyy-MM-dd HH:mm:ss.SSS").format(&ldt), "2024-03-05 14:07:09.123");
+        assert_eq!(DateTimeFormatter::of_pattern("d/M/yy h:mm a").format(&ldt), "5/3/24 2:07 PM");
+        assert_eq!(DateTimeFormatter::of_pattern("EEEE, MMMM d, uuuu").format(&ldt), "Tuesday, March 5, 2024");
+        assert_eq!(DateTimeFormatter::of_pattern("EEE dd MMM").format(&ldt), "Tue 05 Mar");
+        assert_eq!(DateTimeFormatter::of_pattern("'Day' D 'of' yyyy, 'o''clock' k").format(&ldt), "Day 65 of 2024, o'clock 14");
+        assert_eq!(DateTimeFormatter::of_pattern("yyyy G").format(&LocalDate::of(-43, 3, 15)), "0044 BC");
+        assert_eq!(DateTimeFormatter::of_pattern("uuuu").format(&LocalDate::of(-43, 3, 15)), "-0043");
+    }
+
+    #[test]
+    fn formats_offsets_and_zones() {
+        let summer = ZonedDateTime::of(LocalDateTime::of(2024, 7, 4, 9, 30, 0), zone("America/New_York"));
+        let formatter = DateTimeFormatter::of_pattern("HH:mm z (zzzz) VV Z X xxx");
+        assert_eq!(formatter.format(&summer), "09:30 EDT (Eastern Daylight Time) America/New_York -0400 -04 -04:00");
+
+        let utc = OffsetDateTime::of(LocalDateTime::of(2024, 7, 4, 9, 30, 0), ZoneOffset::UTC);
+        assert_eq!(DateTimeFormatter::of_pattern("X|XXX|x|xxx|Z|ZZZZZ").format(&utc), "Z|Z|+00|+00:00|+0000|Z");
+
+        let india = OffsetDateTime::of(LocalDateTime::of(2024, 7, 4, 9, 30, 0), ZoneOffset::of_hours_minutes(5, 30));
+        assert_eq!(DateTimeFormatter::of_pattern("X XX XXX O OOOO").format(&india), "+0530 +0530 +05:30 GMT+5:30 GMT+05:30");
+    }
+
+    #[test]
+    fn works_with_every_type() {
+        let formatter = DateTimeFormatter::of_pattern("uuuu-MM[-dd]['T'HH:mm][XXX]");
+
+        let date = LocalDate::of(2024, 2, 29);
+        assert_eq!(date.format(&formatter), "2024-02-29");
+        assert_eq!(LocalDate::parse_with("2024-02-29", &formatter), Ok(date));
+
+        let ldt = LocalDateTime::of(2024, 2, 29, 8, 15, 0);
+        assert_eq!(ldt.format(&formatter), "2024-02-29T08:15");
+        assert_eq!(formatter.parse::<LocalDateTime>("2024-02-29T08:15"), Ok(ldt));
+        assert_eq!(formatter.parse::<LocalTime>("2024-02-29T08:15"), Ok(LocalTime::of(8, 15, 0)));
+
+        let instant = Instant::of_epoch_second(1_709_194_500);
+        assert_eq!(instant.format(&formatter), "2024-02-29T08:15Z");
+        assert_eq!(formatter.parse::<Instant>("2024-02-29T09:15+01:00"), Ok(instant));
+
+        let year_month = YearMonth::of_year_month(2024, Month::February);
+        assert_eq!(year_month.format(&formatter), "2024-02");
+        assert_eq!(formatter.parse::<YearMonth>("2024-02"), Ok(year_month));
+        assert_eq!(formatter.parse::<Year>("2024-02"), Ok(Year::of(2024)));
+
+        let month_day = DateTimeFormatter::of_pattern("--MM-dd");
+        assert_eq!(MonthDay::of_month_day(Month::December, 25).format(&month_day), "--12-25");
+        assert_eq!(month_day.parse::<MonthDay>("--02-29"), Ok(MonthDay::of_month_day(Month::February, 29)));
+    }
+
+    #[test]
+    fn parses_text_offsets_and_zones() {
+        let formatter = DateTimeFormatter::of_pattern("EEE, dd MMM yyyy hh:mm:ss a xx");
+        let parsed: OffsetDateTime = formatter.parse("tue, 05 MAR 2024 02:07:09 pm -0800").unwrap();
+        assert_eq!(parsed, OffsetDateTime::of(LocalDateTime::of(2024, 3, 5, 14, 7, 9), ZoneOffset::of_hours(-8)));
+
+        let by_id: ZonedDateTime = DateTimeFormatter::of_pattern("yyyy-MM-dd HH:mm VV").parse("2024-07-04 09:30 Europe/Paris").unwrap();
+        assert_eq!(by_id, ZonedDateTime::of(LocalDateTime::of(2024, 7, 4, 9, 30, 0), zone("Europe/Paris")));
+
+        let by_name = DateTimeFormatter::of_pattern("yyyy-MM-dd HH:mm z");
+        let edt: ZonedDateTime = by_name.parse("2024-07-04 09:30 EDT").unwrap();
+        assert_eq!(edt.zone(), zone("America/New_York"));
+        assert_eq!(edt.offset(), ZoneOffset::of_hours(-4));
+        let pacific: ZonedDateTime = by_name.parse("2024-01-04 09:30 Pacific Standard Time").unwrap();
+        assert_eq!(pacific.offset(), ZoneOffset::of_hours(-8));
+
+        // Lord Howe Island has 30 minutes of daylight saving time, and standard time in July.
+        let lord_howe: ZonedDateTime = by_name.parse("2024-07-04 09:30 Lord Howe Daylight Time").unwrap();
+        assert_eq!(lord_howe.zone(), zone("Australia/Lord_Howe"));
+        assert_eq!(lord_howe.to_local_date_time(), LocalDateTime::of(2024, 7, 4, 9, 0, 0));
+        assert_eq!(lord_howe.offset(), ZoneOffset::of_hours_minutes(10, 30));
+    }
+
+    #[test]
+    fn parses_adjacent_values_and_resolves_smartly() {
+        let compact = DateTimeFormatter::of_pattern("yyyyMMddHHmmss");
+        assert_eq!(compact.parse::<LocalDateTime>("20240305140709"), Ok(LocalDateTime::of(2024, 3, 5, 14, 7, 9)));
+
+        let date = DateTimeFormatter::of_pattern("uuuu-MM-dd");
+        assert_eq!(date.parse::<LocalDate>("2023-02-31"), Ok(LocalDate::of(2023, 2, 28)));
+        assert!(date.parse::<LocalDate>("2023-13-01").is_err());
+        assert!(date.parse::<LocalDate>("2023-02-01 extra").is_err());
+        assert!(DateTimeFormatter::of_pattern("EEE uuuu-MM-dd").parse::<LocalDate>("Mon 2024-03-05").is_err());
+
+        let override_zone = date.clone().with_zone(zone("Asia/Tokyo"));
+        assert_eq!(override_zone.parse_fields("2024-03-05").unwrap().zone(), Some(zone("Asia/Tokyo")));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert!(DateTimeFormatter::try_of_pattern("yyyy-MM-dd 'T").is_err());
+        assert!(DateTimeFormatter::try_of_pattern("HHH").is_err());
+        assert!(DateTimeFormatter::try_of_pattern("VVV").is_err());
+        assert!(DateTimeFormatter::try_of_pattern("yyyy [MM").is_err());
+        assert!(DateTimeFormatter::try_of_pattern("qqq").is_err());
+        assert!(DateTimeFormatter::of_pattern("HH:mm").try_format(&LocalDate::of(2024, 1, 1)).is_err());
+    }
+
+    #[test]
+    fn formatting_past_the_end_of_the_range_in_the_formatter_zone_is_an_error() {
+        let odt = OffsetDateTime::of(LocalDateTime::of(9999, 12, 31, 23, 0, 0), ZoneOffset::of_hours(-5));
+        assert_eq!(DateTimeFormatter::iso_instant().try_format(&odt), Err("Instant exceeds the supported range"));
+
+        let instant = LocalDateTime::of(9999, 12, 31, 23, 0, 0).to_instant_utc();
+        let kiritimati = DateTimeFormatter::iso_zoned_date_time().with_zone(zone("Pacific/Kiritimati"));
+        assert_eq!(kiritimati.try_format(&instant), Err("Local date-time exceeds the supported range"));
+        let honolulu = DateTimeFormatter::iso_zoned_date_time().with_zone(zone("Pacific/Honolulu"));
+        assert_eq!(honolulu.try_format(&instant), Ok("9999-12-31T13:00:00-10:00[Pacific/Honolulu]".to_string()));
+    }
+}
@@ -0,0 +1,76 @@
+mod tests {
+    use joda_rs::{
+        DateTimeFormatter, Instant, LocalDate, LocalDateTime, LocalTime, MonthDay, OffsetDateTime, Year, YearMonth, ZoneId, ZoneOffset,
+        ZonedDateTime,
+    };
+
+    #[test]
+    fn every_type_parses_iso_text() {
+        assert_eq!("2024-03-05".parse::<LocalDate>(), Ok(LocalDate::of(2024, 3, 5)));
+        assert_eq!(LocalTime::try_parse("14:07"), Ok(LocalTime::of(14, 7, 0)));
+        assert_eq!(LocalTime::try_parse("14:07:09.000123"), Ok(LocalTime::of_hms_nano(14, 7, 9, 123_000)));
+        assert_eq!(LocalDateTime::try_from("2024-03-05T14:07:09"), Ok(LocalDateTime::of(2024, 3, 5, 14, 7, 9)));
+
+        let ldt = LocalDateTime::of(2024, 3, 5, 14, 7, 9);
+        assert_eq!("2024-03-05T14:07:09-05:30".parse(), Ok(OffsetDateTime::of(ldt, ZoneOffset::of_hours_minutes(-5, -30))));
+        assert_eq!(
+            "2024-03-05T14:07:09+01:00[Europe/Paris]".parse(),
+            Ok(ZonedDateTime::of(ldt, ZoneId::try_of("Europe/Paris").unwrap()))
+        );
+        assert_eq!(ZonedDateTime::try_parse("2024-03-05T14:07:09Z").map(|zdt| zdt.offset()), Ok(ZoneOffset::UTC));
+        assert_eq!(Instant::try_parse("2024-03-05T14:07:09+01:00"), Ok(ldt.to_instant_utc().minus_seconds(3600)));
+
+        assert_eq!("-44".parse(), Ok(Year::of(-44)));
+        assert_eq!("2024-02".parse(), Ok(YearMonth::of(2024, 2)));
+        assert_eq!("--02-29".parse(), Ok(MonthDay::of(2, 29)));
+    }
+
+    #[test]
+    fn errors_report_the_position_and_reason() {
+        let err = LocalDate::try_parse("2024-03-5").unwrap_err();
+        assert_eq!(err.input(), "2024-03-5");
+        assert_eq!(err.error_index(), 8);
+        assert_eq!(err.expected(), "Expected a number with more digits");
+
+        let err = "2024-03-05T14:07:09 +01:00".parse::<OffsetDateTime>().unwrap_err();
+        assert_eq!(err.error_index(), 19);
+
+        let err = LocalDateTime::try_parse("2024-03-05T14:07:09junk").unwrap_err();
+        assert_eq!(err.error_index(), 19);
+        assert_eq!(err.expected(), "Unparsed text found after the end of the pattern");
+
+        let err = LocalDate::try_parse("2024-13-01").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (5, "Invalid value for MonthOfYear"));
+        assert_eq!(err.to_string(), "Text \"2024-13-01\" could not be parsed at index 5: Invalid value for MonthOfYear");
+
+        let boxed: Box<dyn std::error::Error> = Box::new(err.clone());
+        assert_eq!(boxed.to_string(), err.to_string());
+        assert!(MonthDay::try_parse("--02-30").is_err());
+    }
+
+    #[test]
+    fn errors_found_after_matching_report_the_offending_field() {
+        let err = LocalDate::try_parse("2023-02-30").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (8, "Invalid date: the day-of-month is past the end of the month"));
+
+        let err = LocalDateTime::try_parse("2024-03-05T25:00").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (11, "Invalid value for HourOfDay"));
+
+        let err = Instant::try_parse("9999-12-31T23:59:59-18:00").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (19, "Instant exceeds the supported range"));
+
+        let err = "2024-03-05T14:07:09+05:00[Europe/Paris]".parse::<ZonedDateTime>().unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (19, "Offset does not match the time zone"));
+
+        // A field the type needs is missing, so there is no field to point at.
+        let err = DateTimeFormatter::iso_local_date().parse::<LocalDateTime>("2024-03-05").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (0, "Unable to obtain LocalDateTime from the parsed fields"));
+    }
+
+    #[test]
+    fn panicking_parse_still_panics() {
+        assert_eq!(LocalTime::parse("23:59:59"), LocalTime::of(23, 59, 59));
+        let result = std::panic::catch_unwind(|| LocalDate::parse("03/05/2024"));
+        assert!(result.is_err());
+    }
+}
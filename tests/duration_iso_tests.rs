@@ -0,0 +1,51 @@
+mod tests {
+    use joda_rs::Duration;
+
+    #[test]
+    fn parses_like_java() {
+        assert_eq!(Duration::try_parse("PT8H6M12.345S"), Ok(Duration::of_milliseconds(29_172_345)));
+        assert_eq!(Duration::try_parse("PT20.345S"), Ok(Duration::of_milliseconds(20_345)));
+        assert_eq!(Duration::try_parse("PT15M"), Ok(Duration::of_minutes(15)));
+        assert_eq!(Duration::try_parse("PT10H"), Ok(Duration::of_hours(10)));
+        assert_eq!(Duration::try_parse("P2D"), Ok(Duration::of_days(2)));
+        assert_eq!(Duration::try_parse("P2DT3H4M"), Ok(Duration::of_minutes(51 * 60 + 4)));
+        assert_eq!(Duration::try_parse("pt0,000000001s"), Ok(Duration::of_nanoseconds(1)));
+        assert_eq!(Duration::try_parse("+PT1S"), Ok(Duration::of_seconds(1)));
+    }
+
+    #[test]
+    fn parses_negative_components() {
+        assert_eq!(Duration::try_parse("PT-6H3M"), Ok(Duration::of_minutes(-357)));
+        assert_eq!(Duration::try_parse("-PT6H3M"), Ok(Duration::of_minutes(-363)));
+        assert_eq!(Duration::try_parse("-PT-6H+3M"), Ok(Duration::of_minutes(357)));
+        assert_eq!(Duration::try_parse("PT-0.5S"), Ok(Duration::of_milliseconds(-500)));
+        assert_eq!(Duration::try_parse("PT-1.5S"), Ok(Duration::of_milliseconds(-1500)));
+        assert_eq!(Duration::try_parse("P-1DT1S"), Ok(Duration::of_seconds(1 - 86_400)));
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        for text in ["", "P", "PT", "P1D T1H", "PT1H2H", "PT1M1H", "PT1.S1", "PT1.1234567891S", "P1W", "1h30m", "PT1S "] {
+            assert!(Duration::try_parse(text).is_err(), "{text}");
+        }
+        let err = Duration::try_parse("P2DT").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (4, "Expected hours, minutes or seconds after T"));
+        assert!(Duration::try_parse("PT9223372036854775808S").is_err());
+        assert!("PT1H".parse::<Duration>().is_ok());
+        assert!(std::panic::catch_unwind(|| Duration::parse("T1H")).is_err());
+    }
+
+    #[test]
+    fn iso_string_round_trips() {
+        for duration in [
+            Duration::of_seconds(0),
+            Duration::of_days(3).plus_nanoseconds(7),
+            Duration::of_milliseconds(-90_500),
+            Duration::of_seconds(i64::MAX),
+            Duration::of_seconds(i64::MIN),
+        ] {
+            assert_eq!(Duration::parse(&duration.to_iso_string()), duration);
+        }
+        assert_eq!(Duration::of_minutes(90).to_iso_string(), "PT1H30M");
+    }
+}
@@ -0,0 +1,83 @@
+mod tests {
+    use joda_rs::{InferredFormat, LocalDate, LocalDateTime, OffsetDateTime, TemporalKind, ZoneOffset};
+
+    #[test]
+    fn day_first_and_month_first_dates() {
+        let inferred = InferredFormat::infer(&["05/03/2024", "17/03/2024", "01/04/2024"]);
+        assert_eq!(inferred.pattern(), "dd/MM/uuuu");
+        assert_eq!(inferred.kind(), TemporalKind::LocalDate);
+        assert!(!inferred.is_ambiguous());
+        assert_eq!(inferred.confidence(), 1.0);
+        assert_eq!(inferred.formatter().parse::<LocalDate>("17/03/2024"), Ok(LocalDate::of(2024, 3, 17)));
+
+        let inferred = InferredFormat::infer(&["3/5/2024", "3/17/2024", "12/1/2024"]);
+        assert_eq!(inferred.pattern(), "M/d/uuuu");
+        assert_eq!(inferred.formatter().format(&LocalDate::of(2024, 7, 4)), "7/4/2024");
+
+        let inferred = InferredFormat::infer(&["2024.03.05", "2024.12.31"]);
+        assert_eq!(inferred.pattern(), "uuuu.MM.dd");
+        assert_eq!(InferredFormat::infer(&["20240305", "20241231"]).pattern(), "uuuuMMdd");
+    }
+
+    #[test]
+    fn ambiguous_samples_are_reported() {
+        let inferred = InferredFormat::infer(&["05/03/2024", "01/04/2024", "06/06/2024"]);
+        assert_eq!(inferred.pattern(), "dd/MM/uuuu");
+        assert!(inferred.is_ambiguous());
+        assert_eq!(inferred.ambiguities().len(), 1);
+        assert_eq!(inferred.ambiguities()[0].pattern(), "MM/dd/uuuu");
+        assert_eq!(inferred.ambiguities()[0].samples(), &[0, 1]);
+        assert_eq!(inferred.confidence(), 0.5);
+
+        let inferred = InferredFormat::infer(&["01/02/03"]);
+        assert_eq!(inferred.pattern(), "dd/MM/yy");
+        let others: Vec<&str> = inferred.ambiguities().iter().map(|ambiguity| ambiguity.pattern()).collect();
+        assert_eq!(others, ["MM/dd/yy", "yy/MM/dd"]);
+    }
+
+    #[test]
+    fn dates_with_month_names() {
+        let inferred = InferredFormat::infer(&["Mar 5, 2024", "Dec 25, 2024"]);
+        assert_eq!(inferred.pattern(), "MMM d, uuuu");
+        assert!(!inferred.is_ambiguous());
+        assert_eq!(InferredFormat::infer(&["05-Mar-2024", "25-DEC-2024"]).pattern(), "dd-MMM-uuuu");
+        assert_eq!(InferredFormat::infer(&["Tuesday, 5 March 2024"]).pattern(), "EEEE, d MMMM uuuu");
+    }
+
+    #[test]
+    fn date_times_and_offsets() {
+        let inferred = InferredFormat::infer(&["2024-03-05 14:07:09", "2024-03-05 09:30:00"]);
+        assert_eq!(inferred.pattern(), "uuuu-MM-dd HH:mm:ss");
+        assert_eq!(inferred.kind(), TemporalKind::LocalDateTime);
+        assert_eq!(inferred.formatter().parse::<LocalDateTime>("2024-03-05 14:07:09"), Ok(LocalDateTime::of(2024, 3, 5, 14, 7, 9)));
+
+        let inferred = InferredFormat::infer(&["2024-03-05T14:07:09.123+02:00", "2024-03-05T12:07:09.000Z"]);
+        assert_eq!(inferred.pattern(), "uuuu-MM-dd'T'HH:mm:ss.SSSXXX");
+        assert_eq!(inferred.kind(), TemporalKind::OffsetDateTime);
+        let odt: OffsetDateTime = inferred.formatter().parse("2024-03-05T14:07:09.123+02:00").unwrap();
+        assert_eq!(odt.offset(), 2 * 3600);
+
+        let inferred = InferredFormat::infer(&["2024-03-05T14:07:09+00:00"]);
+        assert_eq!(inferred.pattern(), "uuuu-MM-dd'T'HH:mm:ssxxx");
+        let odt = OffsetDateTime::of(LocalDateTime::of(2024, 3, 5, 14, 7, 9), ZoneOffset::UTC);
+        assert_eq!(inferred.formatter().format(&odt), "2024-03-05T14:07:09+00:00");
+
+        let inferred = InferredFormat::infer(&["3/5/2024 2:07 PM", "3/17/2024 11:45 AM"]);
+        assert_eq!(inferred.pattern(), "M/d/uuuu h:mm a");
+        assert_eq!(inferred.formatter().parse::<LocalDateTime>("3/5/2024 2:07 PM"), Ok(LocalDateTime::of(2024, 3, 5, 14, 7, 0)));
+    }
+
+    #[test]
+    fn outliers_and_blanks() {
+        let inferred = InferredFormat::infer(&["2024-03-05", "", "  ", "2024-03-06", "n/a", "2024-13-01"]);
+        assert_eq!(inferred.pattern(), "uuuu-MM-dd");
+        assert_eq!(inferred.total(), 4);
+        assert_eq!(inferred.matched(), 2);
+        assert_eq!(inferred.unmatched(), &[4, 5]);
+        assert_eq!(inferred.confidence(), 0.5);
+
+        assert_eq!(InferredFormat::try_infer(&["", " "]), Err("No samples to infer a format from"));
+        assert_eq!(InferredFormat::try_infer(&["hello", "world"]), Err("No format matches the samples"));
+        assert!(InferredFormat::try_infer::<&str>(&[]).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 mod tests {
-    use joda_rs::{Instant, ZoneId, ZoneOffset};
+    use joda_rs::{DateTimeFormatter, Instant, LocalDateTime, OffsetDateTime, ZoneId, ZoneOffset, ZonedDateTime};
 
     #[test]
     fn arithmetic_and_comparisons() {
@@ -81,4 +81,26 @@ mod tests {
         let b = base.plus_nanoseconds(4_242);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn parsing_past_either_end_of_the_range_is_an_error() {
+        for text in ["9999-12-31T23:59:59-18:00", "-9999-01-01T00:00+18:00", "9999-12-31T23:59:59.999999999-01:00"] {
+            assert_eq!(Instant::try_parse(text).unwrap_err().expected(), "Instant exceeds the supported range", "{text}");
+            assert!(OffsetDateTime::try_parse(text).unwrap().try_to_instant().is_err(), "{text}");
+        }
+        assert_eq!(Instant::try_parse("9999-12-31T23:59:59+00:00").unwrap(), LocalDateTime::of(9999, 12, 31, 23, 59, 59).to_instant_utc());
+        assert_eq!(Instant::try_parse("-9999-01-01T00:00-00:00").unwrap(), LocalDateTime::of(-9999, 1, 1, 0, 0, 0).to_instant_utc());
+
+        let formatter = DateTimeFormatter::iso_zoned_date_time();
+        assert_eq!(
+            formatter.parse::<ZonedDateTime>("9999-12-31T23:00-05:00[America/New_York]").unwrap_err().expected(),
+            "Instant exceeds the supported range"
+        );
+        assert_eq!(formatter.parse_fields("-9999-01-01T00:00+14:00[Etc/GMT-14]").unwrap().instant(), None);
+
+        let zdt = ZonedDateTime::of(LocalDateTime::of(9999, 12, 31, 23, 0, 0), ZoneId::try_of("America/New_York").unwrap());
+        assert_eq!(zdt.try_to_instant(), Err("Instant exceeds the supported range"));
+        let zdt = ZonedDateTime::of(LocalDateTime::of(-9999, 1, 1, 0, 0, 0), ZoneId::from(ZoneOffset::of_hours(1)));
+        assert_eq!(zdt.try_to_instant(), Err("Instant exceeds the supported range"));
+    }
 }
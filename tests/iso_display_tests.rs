@@ -0,0 +1,87 @@
+mod tests {
+    use joda_rs::{
+        Duration, Instant, LocalDate, LocalDateTime, LocalTime, MonthDay, OffsetDateTime, Period, Year, YearMonth, ZoneId,
+        ZoneOffset, ZonedDateTime,
+    };
+
+    #[test]
+    fn local_types_print_like_java() {
+        assert_eq!(LocalDate::of(2024, 1, 1).to_string(), "2024-01-01");
+        assert_eq!(LocalDate::of(-44, 3, 15).to_string(), "-0044-03-15");
+        assert_eq!(LocalDate::of(9999, 12, 31).to_string(), "9999-12-31");
+
+        assert_eq!(LocalTime::of(12, 0, 0).to_string(), "12:00");
+        assert_eq!(LocalTime::of(12, 0, 5).to_string(), "12:00:05");
+        assert_eq!(LocalTime::of_hms_nano(12, 0, 0, 500_000_000).to_string(), "12:00:00.500");
+        assert_eq!(LocalTime::of_hms_nano(12, 0, 0, 1_000).to_string(), "12:00:00.000001");
+        assert_eq!(LocalTime::of_hms_nano(12, 0, 0, 1).to_string(), "12:00:00.000000001");
+
+        assert_eq!(LocalDateTime::of(2024, 1, 1, 12, 0, 0).to_string(), "2024-01-01T12:00");
+        assert_eq!(Year::of(2024).to_string(), "2024");
+        assert_eq!(YearMonth::of(-5, 3).to_string(), "-0005-03");
+        assert_eq!(YearMonth::of(10_000, 1).to_string(), "+10000-01");
+        assert_eq!(MonthDay::of(12, 25).to_string(), "--12-25");
+    }
+
+    #[test]
+    fn offset_and_zoned_types_print_like_java() {
+        let ldt = LocalDateTime::of(2024, 1, 1, 12, 0, 0);
+        assert_eq!(OffsetDateTime::of(ldt, ZoneOffset::UTC).to_string(), "2024-01-01T12:00Z");
+        assert_eq!(OffsetDateTime::of(ldt, ZoneOffset::of_hours_minutes(5, 30)).to_string(), "2024-01-01T12:00+05:30");
+
+        let paris = ZonedDateTime::of(ldt, ZoneId::try_of("Europe/Paris").unwrap());
+        assert_eq!(paris.to_string(), "2024-01-01T12:00+01:00[Europe/Paris]");
+        assert_eq!(ZonedDateTime::of(ldt, ZoneId::from(ZoneOffset::of_hours(-3))).to_string(), "2024-01-01T12:00-03:00");
+        assert_eq!(ZonedDateTime::of(ldt, ZoneId::UTC).to_string(), "2024-01-01T12:00Z[UTC]");
+
+        assert_eq!(ldt.to_instant_utc().to_string(), "2024-01-01T12:00:00Z");
+        assert_eq!(Instant::of_epoch_millisecond(1_500).to_string(), "1970-01-01T00:00:01.500Z");
+        assert_eq!(ZoneOffset::of_hours(2).to_string(), "+02:00");
+        assert_eq!(ZoneId::try_of("Asia/Tokyo").unwrap().to_string(), "Asia/Tokyo");
+    }
+
+    #[test]
+    fn amounts_print_like_java() {
+        assert_eq!(Period::of(1, 2, 3).to_string(), "P1Y2M3D");
+        assert_eq!(Period::of(0, -1, 0).to_string(), "P-1M");
+        assert_eq!(Period::of(0, 0, 0).to_string(), "P0D");
+
+        assert_eq!(Duration::of_seconds(8 * 3600 + 6 * 60 + 12).plus_milliseconds(345).to_string(), "PT8H6M12.345S");
+        assert_eq!(Duration::of_days(2).to_string(), "PT48H");
+        assert_eq!(Duration::of_milliseconds(-500).to_string(), "PT-0.5S");
+        assert_eq!(Duration::of_milliseconds(-1500).to_string(), "PT-1.5S");
+        assert_eq!(Duration::of_minutes(-90).to_string(), "PT-1H-30M");
+        assert_eq!(Duration::of_seconds(0).to_string(), "PT0S");
+    }
+
+    #[test]
+    fn signs_formatting_would_not_print_are_rejected() {
+        assert!(LocalDate::try_parse("+9999-12-31").is_err());
+        assert!(LocalDate::try_parse("-0000-01-01").is_err());
+        assert!(YearMonth::try_parse("+2024-01").is_err());
+        assert_eq!(LocalDate::try_parse("0000-01-01"), Ok(LocalDate::of(0, 1, 1)));
+        assert_eq!(LocalDate::try_parse("-0001-01-01"), Ok(LocalDate::of(-1, 1, 1)));
+    }
+
+    #[test]
+    fn output_parses_back() {
+        let date = LocalDate::of(-9999, 2, 28);
+        assert_eq!(date.to_string().parse(), Ok(date));
+        let time = LocalTime::of_hms_nano(23, 59, 0, 120_000);
+        assert_eq!(time.to_string().parse(), Ok(time));
+        let ldt = LocalDateTime::of_date_time(LocalDate::of(-44, 3, 15), time);
+        assert_eq!(ldt.to_string().parse(), Ok(ldt));
+
+        let odt = OffsetDateTime::of(ldt, ZoneOffset::of_total_seconds(-(3600 + 30 * 60 + 15)));
+        assert_eq!(odt.to_string().parse(), Ok(odt));
+        let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneId::try_of("America/New_York").unwrap());
+        assert_eq!(zdt.to_string().parse(), Ok(zdt));
+        let instant = Instant::of_epoch_second_nano(1_700_000_000, 7);
+        assert_eq!(instant.to_string().parse(), Ok(instant));
+
+        assert_eq!(Year::of(-44).to_string().parse(), Ok(Year::of(-44)));
+        assert_eq!(YearMonth::of(-5, 3).to_string().parse(), Ok(YearMonth::of(-5, 3)));
+        assert_eq!(YearMonth::of(10_000, 1).to_string().parse(), Ok(YearMonth::of(10_000, 1)));
+        assert_eq!(MonthDay::of(2, 29).to_string().parse(), Ok(MonthDay::of(2, 29)));
+    }
+}
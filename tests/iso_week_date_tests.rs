@@ -0,0 +1,101 @@
+mod tests {
+    use joda_rs::{DateTimeFormatter, DayOfWeek, LocalDate, LocalDateTime, ResolverStyle, YearWeek};
+
+    #[test]
+    fn week_fields_of_dates() {
+        let date = LocalDate::of(2024, 1, 31);
+        assert_eq!(date.week_based_year(), 2024);
+        assert_eq!(date.week_of_week_based_year(), 5);
+        assert_eq!(date.year_week(), YearWeek::of(2024, 5));
+
+        // The days around January 1 may belong to the other week-based year.
+        assert_eq!(LocalDate::of(2024, 12, 30).week_based_year(), 2025);
+        assert_eq!(LocalDate::of(2024, 12, 30).week_of_week_based_year(), 1);
+        assert_eq!(LocalDate::of(2021, 1, 3).week_based_year(), 2020);
+        assert_eq!(LocalDate::of(2021, 1, 3).week_of_week_based_year(), 53);
+
+        let ldt = LocalDateTime::of(2021, 1, 1, 23, 0, 0);
+        assert_eq!((ldt.week_based_year(), ldt.week_of_week_based_year()), (2020, 53));
+    }
+
+    #[test]
+    fn of_iso_week() {
+        assert_eq!(LocalDate::of_iso_week(2024, 5, DayOfWeek::Wednesday), LocalDate::of(2024, 1, 31));
+        assert_eq!(LocalDate::of_iso_week(2025, 1, DayOfWeek::Monday), LocalDate::of(2024, 12, 30));
+        assert_eq!(LocalDate::of_iso_week(2020, 53, DayOfWeek::Sunday), LocalDate::of(2021, 1, 3));
+    }
+
+    #[test]
+    fn try_of_iso_week() {
+        assert_eq!(LocalDate::try_of_iso_week(2020, 53, DayOfWeek::Friday), Ok(LocalDate::of(2021, 1, 1)));
+        assert_eq!(
+            LocalDate::try_of_iso_week(2023, 53, DayOfWeek::Monday),
+            Err("Invalid date: week 53 is only valid in a week-based year of 53 weeks")
+        );
+        assert_eq!(LocalDate::try_of_iso_week(2024, 0, DayOfWeek::Monday), Err("Invalid value for WeekOfWeekBasedYear"));
+        assert_eq!(LocalDate::try_of_iso_week(2024, 54, DayOfWeek::Monday), Err("Invalid value for WeekOfWeekBasedYear"));
+        assert_eq!(LocalDate::try_of_iso_week(10_000, 1, DayOfWeek::Monday), Err("Invalid date: year out of range"));
+        assert_eq!(LocalDate::try_of_iso_week(9999, 52, DayOfWeek::Friday), Ok(LocalDate::of(9999, 12, 31)));
+        assert_eq!(LocalDate::try_of_iso_week(9999, 52, DayOfWeek::Saturday), Err("Invalid date: out of range"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn of_iso_week_rejects_week_53_of_a_short_year() {
+        LocalDate::of_iso_week(2023, 53, DayOfWeek::Monday);
+    }
+
+    #[test]
+    fn iso_week_date_format_and_parse() {
+        let formatter = DateTimeFormatter::iso_week_date();
+        assert_eq!(formatter.format(&LocalDate::of(2024, 1, 31)), "2024-W05-3");
+        assert_eq!(formatter.format(&LocalDateTime::of(2021, 1, 3, 9, 0, 0)), "2020-W53-7");
+        assert_eq!(formatter.format(&YearWeek::of(2024, 5)), "2024-W05");
+
+        assert_eq!(formatter.parse::<LocalDate>("2024-W05-3"), Ok(LocalDate::of(2024, 1, 31)));
+        assert_eq!(formatter.parse::<LocalDate>("2025-W01-1"), Ok(LocalDate::of(2024, 12, 30)));
+        assert_eq!(formatter.parse::<YearWeek>("2024-W05"), Ok(YearWeek::of(2024, 5)));
+        assert_eq!(formatter.parse::<YearWeek>("2024-W05-3"), Ok(YearWeek::of(2024, 5)));
+        assert!(formatter.parse::<LocalDate>("2024-W05").is_err());
+
+        let error = formatter.parse::<LocalDate>("2023-W53-1").unwrap_err();
+        assert_eq!(error.expected(), "Invalid date: week 53 is only valid in a week-based year of 53 weeks");
+        assert_eq!(formatter.parse::<LocalDate>("2024-W54-1").unwrap_err().expected(), "Invalid value for WeekOfWeekBasedYear");
+        assert_eq!(formatter.parse::<LocalDate>("2024-W05-8").unwrap_err().expected(), "Invalid value for DayOfWeek");
+        assert_eq!(formatter.parse::<LocalDate>("2024-05-3").unwrap_err().error_index(), 4);
+
+        // Smart resolving moves week 53 of a short year into the next year.
+        let smart = formatter.with_resolver_style(ResolverStyle::Smart);
+        assert_eq!(smart.parse::<LocalDate>("2023-W53-1"), Ok(LocalDate::of(2024, 1, 1)));
+    }
+
+    #[test]
+    fn week_pattern_letters() {
+        let formatter = DateTimeFormatter::of_pattern("YYYY-'W'ww-e");
+        assert_eq!(formatter.format(&LocalDate::of(2024, 12, 30)), "2025-W01-1");
+        assert_eq!(formatter.parse::<LocalDate>("2020-W53-5"), Ok(LocalDate::of(2021, 1, 1)));
+
+        let formatter = DateTimeFormatter::of_pattern("YYYY-'W'ww");
+        assert_eq!(formatter.format(&YearWeek::of(2024, 5)), "2024-W05");
+        assert_eq!(formatter.parse::<YearWeek>("2024-W05"), Ok(YearWeek::of(2024, 5)));
+
+        let date = LocalDate::of(2021, 1, 3);
+        assert_eq!(DateTimeFormatter::of_pattern("YY w ee eee cccc").format(&date), "20 53 07 Sun Sunday");
+        assert_eq!(DateTimeFormatter::of_pattern("c").format(&date), "7");
+        assert_eq!(DateTimeFormatter::try_of_pattern("cc"), Err("Pattern letter count must not be 2: c"));
+        assert_eq!(DateTimeFormatter::try_of_pattern("www"), Err("Too many pattern letters"));
+    }
+
+    #[test]
+    fn iso_ordinal_date_format_and_parse() {
+        let formatter = DateTimeFormatter::iso_ordinal_date();
+        assert_eq!(formatter.format(&LocalDate::of(2024, 2, 14)), "2024-045");
+        assert_eq!(formatter.format(&LocalDate::of(2024, 12, 31)), "2024-366");
+        assert_eq!(formatter.parse::<LocalDate>("2024-045"), Ok(LocalDate::of(2024, 2, 14)));
+        assert_eq!(formatter.parse::<LocalDate>("2024-366"), Ok(LocalDate::of(2024, 12, 31)));
+        assert!(formatter.parse::<LocalDate>("2023-366").is_err());
+        assert!(formatter.parse::<LocalDate>("2024-45").is_err());
+        assert_eq!(formatter.parse::<LocalDate>("+10000-001").unwrap_err().expected(), "Invalid date: year out of range");
+        assert_eq!(formatter.parse::<LocalDate>("-9999-001"), Ok(LocalDate::of(-9999, 1, 1)));
+    }
+}
@@ -0,0 +1,106 @@
+mod tests {
+    use joda_rs::{DateTimeFormatter, DayOfWeek, LocalDate, LocalDateTime, Locale, Month, TextStyle};
+
+    #[test]
+    fn locales_are_created_from_codes_and_tags() {
+        assert_eq!(Locale::of("FR", "fr"), Locale::FRANCE);
+        assert_eq!(Locale::for_language_tag("en_GB"), Ok(Locale::UK));
+        assert_eq!(Locale::for_language_tag("zh-Hans-CN"), Ok(Locale::CHINA));
+        assert_eq!(Locale::for_language_tag("es-419").map(|locale| locale.country().to_string()), Ok("419".to_string()));
+        assert_eq!(Locale::for_language_tag("de"), Ok(Locale::GERMAN));
+        assert!(Locale::for_language_tag("").is_err());
+        assert!(Locale::for_language_tag("english").is_err());
+        assert!(Locale::try_of("en", "U").is_err());
+        assert_eq!(Locale::of("pt", "BR").to_language_tag(), "pt-BR");
+        assert_eq!(Locale::JAPANESE.to_string(), "ja");
+    }
+
+    #[test]
+    fn month_names_in_every_language() {
+        let september = [
+            (Locale::ENGLISH, "September", "Sep"),
+            (Locale::FRENCH, "septembre", "sept."),
+            (Locale::GERMAN, "September", "Sept."),
+            (Locale::SPANISH, "septiembre", "sept"),
+            (Locale::ITALIAN, "settembre", "set"),
+            (Locale::PORTUGUESE, "setembro", "set."),
+            (Locale::DUTCH, "september", "sep"),
+            (Locale::JAPANESE, "9月", "9月"),
+            (Locale::CHINESE, "九月", "9月"),
+            (Locale::ARABIC, "سبتمبر", "سبتمبر"),
+        ];
+        for (locale, full, short) in september {
+            assert_eq!(Month::September.get_display_name(TextStyle::Full, locale), full, "{locale}");
+            assert_eq!(Month::September.get_display_name(TextStyle::Short, locale), short, "{locale}");
+            assert_eq!(Month::try_parse_display_name(full, locale), Ok(Month::September), "{locale}");
+            assert_eq!(Month::try_parse_display_name(short, locale), Ok(Month::September), "{locale}");
+        }
+        assert_eq!(Month::May.get_display_name(TextStyle::Full, Locale::of("sv", "SE")), "May");
+        assert_eq!(Month::January.get_display_name(TextStyle::Narrow, Locale::ITALY), "G");
+        assert_eq!(Month::September.get_display_name(TextStyle::ShortStandalone, Locale::GERMANY), "Sep");
+    }
+
+    #[test]
+    fn day_names_in_every_language() {
+        let tuesday = [
+            (Locale::ENGLISH, "Tuesday", "Tue"),
+            (Locale::FRENCH, "mardi", "mar."),
+            (Locale::GERMAN, "Dienstag", "Di."),
+            (Locale::SPANISH, "martes", "mar"),
+            (Locale::ITALIAN, "martedì", "mar"),
+            (Locale::PORTUGUESE, "terça-feira", "ter."),
+            (Locale::DUTCH, "dinsdag", "di"),
+            (Locale::JAPANESE, "火曜日", "火"),
+            (Locale::CHINESE, "星期二", "周二"),
+            (Locale::ARABIC, "الثلاثاء", "الثلاثاء"),
+        ];
+        for (locale, full, short) in tuesday {
+            assert_eq!(DayOfWeek::Tuesday.get_display_name(TextStyle::Full, locale), full, "{locale}");
+            assert_eq!(DayOfWeek::Tuesday.get_display_name(TextStyle::Short, locale), short, "{locale}");
+            assert_eq!(DayOfWeek::try_parse_display_name(full, locale), Ok(DayOfWeek::Tuesday), "{locale}");
+            assert_eq!(DayOfWeek::try_parse_display_name(short, locale), Ok(DayOfWeek::Tuesday), "{locale}");
+        }
+        assert_eq!(DayOfWeek::Saturday.get_display_name(TextStyle::ShortStandalone, Locale::GERMANY), "Sa");
+    }
+
+    #[test]
+    fn parsing_names_ignores_case_and_periods() {
+        assert_eq!(DayOfWeek::parse_display_name("TUE", Locale::US), DayOfWeek::Tuesday);
+        assert_eq!(Month::parse_display_name(" Septembre ", Locale::FRANCE), Month::September);
+        assert_eq!(Month::try_parse_display_name("FÉVR", Locale::FRANCE), Ok(Month::February));
+        assert_eq!(Month::try_parse_display_name("Mär", Locale::GERMANY), Ok(Month::March));
+        assert_eq!(Month::try_parse_display_name("septembre", Locale::US), Err("invalid month name"));
+        assert!(DayOfWeek::try_parse_display_name("T", Locale::US).is_err());
+    }
+
+    #[test]
+    fn first_day_of_week_follows_the_country() {
+        assert_eq!(Locale::US.first_day_of_week(), DayOfWeek::Sunday);
+        assert_eq!(Locale::ENGLISH.first_day_of_week(), DayOfWeek::Sunday);
+        assert_eq!(Locale::UK.first_day_of_week(), DayOfWeek::Monday);
+        assert_eq!(Locale::GERMANY.first_day_of_week(), DayOfWeek::Monday);
+        assert_eq!(Locale::BRAZIL.first_day_of_week(), DayOfWeek::Sunday);
+        assert_eq!(Locale::of("pt", "PT").first_day_of_week(), DayOfWeek::Sunday);
+        assert_eq!(Locale::JAPAN.first_day_of_week(), DayOfWeek::Sunday);
+        assert_eq!(Locale::of("ar", "AE").first_day_of_week(), DayOfWeek::Saturday);
+        assert_eq!(Locale::of("ar", "MA").first_day_of_week(), DayOfWeek::Monday);
+        assert_eq!(Locale::of("dv", "MV").first_day_of_week(), DayOfWeek::Friday);
+        assert_eq!(Locale::of("sv", "").first_day_of_week(), DayOfWeek::Monday);
+    }
+
+    #[test]
+    fn formatters_use_their_locale() {
+        let ldt = LocalDateTime::of(2025, 1, 5, 15, 30, 0);
+        let pattern = "EEE d MMM uuuu h:mm a";
+        assert_eq!(DateTimeFormatter::of_pattern(pattern).format(&ldt), "Sun 5 Jan 2025 3:30 PM");
+        assert_eq!(DateTimeFormatter::of_pattern(pattern).with_locale(Locale::FRANCE).format(&ldt), "dim. 5 janv. 2025 3:30 PM");
+        assert_eq!(DateTimeFormatter::of_pattern(pattern).with_locale(Locale::JAPAN).format(&ldt), "日 5 1月 2025 3:30 午後");
+        assert_eq!(DateTimeFormatter::of_pattern("LLL").with_locale(Locale::GERMANY).format(&ldt), "Jan");
+        assert_eq!(DateTimeFormatter::of_pattern("MMM").with_locale(Locale::GERMANY).format(&ldt), "Jan.");
+
+        let spanish = DateTimeFormatter::of_pattern("EEEE, d 'de' MMMM 'de' uuuu").with_locale(Locale::SPAIN);
+        assert_eq!(spanish.locale(), Locale::SPAIN);
+        assert_eq!(spanish.parse::<LocalDate>("MIÉRCOLES, 5 de febrero de 2025"), Ok(LocalDate::of(2025, 2, 5)));
+        assert!(spanish.parse::<LocalDate>("Wednesday, 5 de February de 2025").is_err());
+    }
+}
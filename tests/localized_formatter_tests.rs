@@ -0,0 +1,79 @@
+mod tests {
+    use joda_rs::{
+        DateTimeFormatter, FormatStyle, LocalDate, LocalDateTime, LocalTime, Locale, TemporalAccessor, ZoneId, ZonedDateTime,
+    };
+
+    #[test]
+    fn localized_dates_follow_cldr() {
+        let date = LocalDate::of(2025, 1, 5);
+        let medium = [
+            (Locale::US, "Jan 5, 2025"),
+            (Locale::UK, "5 Jan 2025"),
+            (Locale::FRANCE, "5 janv. 2025"),
+            (Locale::GERMANY, "05.01.2025"),
+            (Locale::SPAIN, "5 ene 2025"),
+            (Locale::ITALY, "5 gen 2025"),
+            (Locale::BRAZIL, "5 de jan. de 2025"),
+            (Locale::NETHERLANDS, "5 jan 2025"),
+            (Locale::JAPAN, "2025/01/05"),
+            (Locale::CHINA, "2025年1月5日"),
+        ];
+        for (locale, expected) in medium {
+            assert_eq!(DateTimeFormatter::of_localized_date(FormatStyle::Medium, locale).format(&date), expected, "{locale}");
+        }
+
+        let format = |style, locale| DateTimeFormatter::of_localized_date(style, locale).format(&date);
+        assert_eq!(format(FormatStyle::Full, Locale::US), "Sunday, January 5, 2025");
+        assert_eq!(format(FormatStyle::Long, Locale::GERMANY), "5. Januar 2025");
+        assert_eq!(format(FormatStyle::Full, Locale::SPAIN), "domingo, 5 de enero de 2025");
+        assert_eq!(format(FormatStyle::Full, Locale::JAPAN), "2025年1月5日日曜日");
+        assert_eq!(format(FormatStyle::Short, Locale::US), "1/5/25");
+        assert_eq!(format(FormatStyle::Short, Locale::NETHERLANDS), "05-01-2025");
+        assert_eq!(format(FormatStyle::Medium, Locale::of("sv", "SE")), "Jan 5, 2025");
+    }
+
+    #[test]
+    fn localized_times_and_date_times() {
+        let time = LocalTime::of(15, 30, 5);
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Medium, Locale::US).format(&time), "3:30:05 PM");
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Short, Locale::FRANCE).format(&time), "15:30");
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Short, Locale::JAPAN).format(&time), "15:30");
+        assert!(DateTimeFormatter::of_localized_time(FormatStyle::Long, Locale::US).try_format(&time).is_err());
+
+        let ldt = LocalDateTime::of(2025, 1, 5, 15, 30, 5);
+        assert_eq!(DateTimeFormatter::of_localized_date_time(FormatStyle::Medium, Locale::US).format(&ldt), "Jan 5, 2025, 3:30:05 PM");
+        assert_eq!(DateTimeFormatter::of_localized_date_time(FormatStyle::Short, Locale::GERMANY).format(&ldt), "05.01.25, 15:30");
+        assert_eq!(
+            DateTimeFormatter::of_localized_date_time_styles(FormatStyle::Long, FormatStyle::Short, Locale::NETHERLANDS).format(&ldt),
+            "5 januari 2025 om 15:30"
+        );
+
+        let new_york = ZonedDateTime::of(ldt, ZoneId::try_of("America/New_York").unwrap());
+        assert_eq!(
+            DateTimeFormatter::of_localized_date_time(FormatStyle::Full, Locale::US).format(&new_york),
+            "Sunday, January 5, 2025 at 3:30:05 PM Eastern Standard Time"
+        );
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Long, Locale::UK).format(&new_york), "15:30:05 EST");
+
+        let egypt = Locale::of("ar", "EG");
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Full, egypt).format(&new_york), "3:30:05 م غرينتش-05:00");
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Long, egypt).format(&new_york), "3:30:05 م غرينتش-5");
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Full, Locale::FRANCE).format(&new_york), "15:30:05 UTC\u{2212}05:00");
+        let paris = ZonedDateTime::of(ldt, ZoneId::try_of("Europe/Paris").unwrap());
+        assert_eq!(DateTimeFormatter::of_localized_time(FormatStyle::Full, Locale::GERMANY).format(&paris), "15:30:05 GMT+01:00");
+    }
+
+    #[test]
+    fn localized_formatters_parse_their_output() {
+        let date = LocalDate::of(2024, 9, 3);
+        for locale in [Locale::US, Locale::FRANCE, Locale::GERMANY, Locale::SPAIN, Locale::BRAZIL, Locale::JAPAN, Locale::CHINA] {
+            for style in [FormatStyle::Full, FormatStyle::Long, FormatStyle::Medium] {
+                let formatter = DateTimeFormatter::of_localized_date(style, locale);
+                assert_eq!(formatter.locale(), locale);
+                assert_eq!(LocalDate::parse_with(&date.format(&formatter), &formatter), Ok(date), "{locale} {style:?}");
+            }
+        }
+        let formatter = DateTimeFormatter::of_localized_date_time(FormatStyle::Medium, Locale::FRANCE);
+        assert_eq!(formatter.parse::<LocalDateTime>("3 sept. 2024, 08:15:00"), Ok(LocalDateTime::of(2024, 9, 3, 8, 15, 0)));
+    }
+}
@@ -0,0 +1,107 @@
+mod tests {
+    use joda_rs::{
+        Clock, DateTimeFormatter, Instant, LocalDateTime, LogFormat, LogTimestampParser, OffsetDateTime, ZoneId, ZoneOffset,
+    };
+
+    fn syslog_at(ldt: LocalDateTime, zone: ZoneId) -> LogTimestampParser {
+        LogTimestampParser::new(LogFormat::Rfc3164).with_clock(Clock::fixed_at_local(ldt, zone))
+    }
+
+    #[test]
+    fn rfc_3164_takes_the_year_and_zone_from_the_clock() {
+        let paris = ZoneId::try_of("Europe/Paris").unwrap();
+        let parser = syslog_at(LocalDateTime::of(2024, 10, 12, 9, 0, 0), paris);
+
+        let odt = parser.parse_offset_date_time("Oct 11 22:14:15").unwrap();
+        assert_eq!(odt.to_local_date_time(), LocalDateTime::of(2024, 10, 11, 22, 14, 15));
+        assert_eq!(odt.offset(), 2 * 3600);
+        let odt = parser.parse_offset_date_time("Feb  5 07:00:00").unwrap();
+        assert_eq!(odt.to_local_date_time(), LocalDateTime::of(2024, 2, 5, 7, 0, 0));
+        assert_eq!(odt.offset(), 3600);
+        assert_eq!(parser.parse_instant("Oct 11 20:14:15"), Ok(LocalDateTime::of(2024, 10, 11, 18, 14, 15).to_instant_utc()));
+    }
+
+    #[test]
+    fn rfc_3164_rolls_over_at_new_year() {
+        let new_year = syslog_at(LocalDateTime::of(2025, 1, 1, 0, 5, 0), ZoneId::UTC);
+        let year = |parser: &LogTimestampParser, text| parser.parse_offset_date_time(text).unwrap().to_local_date_time().year();
+        assert_eq!(year(&new_year, "Dec 31 23:59:58"), 2024);
+        assert_eq!(year(&new_year, "Jan  1 00:04:59"), 2025);
+        assert_eq!(year(&new_year, "Jan  1 00:10:00"), 2025);
+
+        let new_years_eve = syslog_at(LocalDateTime::of(2024, 12, 31, 23, 59, 0), ZoneId::UTC);
+        assert_eq!(year(&new_years_eve, "Jan  1 00:00:30"), 2025);
+        assert_eq!(year(&new_years_eve, "Jan  3 00:00:00"), 2024);
+        assert_eq!(year(&new_years_eve, "Dec 31 23:58:00"), 2024);
+
+        let leap_day = syslog_at(LocalDateTime::of(2026, 3, 1, 0, 0, 0), ZoneId::UTC);
+        assert_eq!(year(&leap_day, "Feb 29 12:00:00"), 2024);
+    }
+
+    #[test]
+    fn rfc_3164_near_the_end_of_the_range() {
+        let last_day = syslog_at(LocalDateTime::of(9999, 12, 31, 12, 0, 0), ZoneId::UTC);
+        let year = |text| last_day.parse_offset_date_time(text).unwrap().to_local_date_time().year();
+        assert_eq!(year("Feb 29 10:00:00"), 9996);
+        assert_eq!(year("Jan  1 10:00:00"), 9999);
+        assert_eq!(year("Dec 31 23:00:00"), 9999);
+
+        let new_york = ZoneId::try_of("America/New_York").unwrap();
+        let last_day = syslog_at(LocalDateTime::of(9999, 12, 31, 12, 0, 0), new_york);
+        assert_eq!(last_day.parse_instant("Dec 31 23:00:00").unwrap_err().expected(), "Instant exceeds the supported range");
+    }
+
+    #[test]
+    fn rfc_3164_rejects_malformed_text() {
+        let parser = syslog_at(LocalDateTime::of(2024, 10, 12, 9, 0, 0), ZoneId::UTC);
+        assert_eq!(parser.parse_instant("Foo 11 22:14:15").unwrap_err().error_index(), 0);
+        assert_eq!(parser.parse_instant("Oct  11 22:14:15").unwrap_err().error_index(), 5);
+        assert_eq!(parser.parse_instant("Oct 11 22:14").unwrap_err().error_index(), 7);
+        assert_eq!(parser.parse_instant("Oct 11 25:14:15").unwrap_err().error_index(), 7);
+        assert_eq!(parser.parse_instant("Oct 32 22:14:15").unwrap_err().expected(), "Invalid date");
+    }
+
+    #[test]
+    fn rfc_5424_and_nginx_iso_timestamps() {
+        let parser = LogTimestampParser::new(LogFormat::Rfc5424);
+        let odt = parser.parse_offset_date_time("2003-08-24T05:14:15.000003-07:00").unwrap();
+        assert_eq!(odt.to_local_date_time(), LocalDateTime::of(2003, 8, 24, 5, 14, 15).plus_nanoseconds(3_000));
+        assert_eq!(odt.offset(), -7 * 3600);
+        assert_eq!(parser.parse_instant("1985-04-12T23:20:50.52Z"), Ok(Instant::of_epoch_second_nano(482_196_050, 520_000_000)));
+        assert_eq!(parser.parse_instant("-").unwrap_err().expected(), "The timestamp is the NILVALUE");
+        for text in ["2003-08-24T05:14-07:00", "2003-08-24T05:14:15.0000003Z", "2003-08-24t05:14:15Z", "+2003-08-24T05:14:15Z", "2003-08-24T05:14:15+07:00:30"] {
+            assert!(parser.parse_instant(text).is_err(), "{text}");
+        }
+
+        let nginx = LogTimestampParser::new(LogFormat::NginxIso8601);
+        assert_eq!(nginx.parse_offset_date_time("2000-10-10T13:55:36-07:00").unwrap().offset(), -7 * 3600);
+    }
+
+    #[test]
+    fn common_log_format() {
+        let expected = OffsetDateTime::of(LocalDateTime::of(2000, 10, 10, 13, 55, 36), ZoneOffset::of_hours(-7));
+        let parser = LogTimestampParser::new(LogFormat::CommonLog);
+        assert_eq!(parser.parse_offset_date_time("10/Oct/2000:13:55:36 -0700"), Ok(expected));
+        assert_eq!(parser.parse_offset_date_time("[10/Oct/2000:13:55:36 -0700]"), Ok(expected));
+        assert_eq!(parser.parse_instant("[10/Oct/2000:13:55:36 -0700]"), Ok(expected.to_instant()));
+        assert_eq!(parser.parse_instant("[10/Okt/2000:13:55:36 -0700]").unwrap_err().error_index(), 4);
+
+        assert_eq!(DateTimeFormatter::common_log_format().format(&expected), "10/Oct/2000:13:55:36 -0700");
+    }
+
+    #[test]
+    fn epoch_timestamps() {
+        let journald = LogTimestampParser::new(LogFormat::Journald);
+        let instant = Instant::of_epoch_second_nano(1_700_000_000, 123_456_000);
+        assert_eq!(journald.parse_instant("1700000000123456"), Ok(instant));
+        assert_eq!(journald.parse_offset_date_time("1700000000123456").unwrap().offset(), 0);
+        assert!(journald.parse_instant("17000000001234x6").is_err());
+        assert!(journald.parse_instant("").is_err());
+
+        let msec = LogTimestampParser::new(LogFormat::NginxMsec);
+        assert_eq!(msec.parse_instant("1700000000.123"), Ok(Instant::of_epoch_millisecond(1_700_000_000_123)));
+        assert_eq!(msec.parse_instant("1700000000123"), Ok(Instant::of_epoch_millisecond(1_700_000_000_123)));
+        assert_eq!(msec.parse_instant("1700000000.").unwrap_err().error_index(), 11);
+        assert!(msec.parse_instant("999999999999999999").is_err());
+    }
+}
@@ -0,0 +1,136 @@
+mod tests {
+    use joda_rs::{Clock, LocalDate, LocalDateTime, NaturalDateParser, ZoneId, ZoneOffset};
+
+    fn parser() -> NaturalDateParser {
+        // Thursday 9 January 2025, 12:00.
+        NaturalDateParser::new(Clock::fixed_at_local(LocalDateTime::of(2025, 1, 9, 12, 0, 0), ZoneId::UTC))
+    }
+
+    fn date(text: &str) -> LocalDate {
+        parser().parse_date(text)
+    }
+
+    #[test]
+    fn relative_days_and_weekdays() {
+        assert_eq!(date("today"), LocalDate::of(2025, 1, 9));
+        assert_eq!(date("Tomorrow"), LocalDate::of(2025, 1, 10));
+        assert_eq!(date("yesterday"), LocalDate::of(2025, 1, 8));
+        assert_eq!(date("the day after tomorrow"), LocalDate::of(2025, 1, 11));
+        assert_eq!(date("day before yesterday"), LocalDate::of(2025, 1, 7));
+
+        assert_eq!(date("friday"), LocalDate::of(2025, 1, 10));
+        assert_eq!(date("thursday"), LocalDate::of(2025, 1, 9));
+        assert_eq!(date("this Thu"), LocalDate::of(2025, 1, 9));
+        assert_eq!(date("next thursday"), LocalDate::of(2025, 1, 16));
+        assert_eq!(date("next Friday"), LocalDate::of(2025, 1, 10));
+        assert_eq!(date("last friday"), LocalDate::of(2025, 1, 3));
+
+        assert_eq!(date("next week"), LocalDate::of(2025, 1, 16));
+        assert_eq!(date("last month"), LocalDate::of(2024, 12, 9));
+        assert_eq!(date("next year"), LocalDate::of(2026, 1, 9));
+    }
+
+    #[test]
+    fn amounts_from_now() {
+        let parser = parser();
+        assert_eq!(date("in 3 weeks"), LocalDate::of(2025, 1, 30));
+        assert_eq!(date("in a fortnight"), LocalDate::of(2025, 1, 23));
+        assert_eq!(date("2 days ago"), LocalDate::of(2025, 1, 7));
+        assert_eq!(date("one month from now"), LocalDate::of(2025, 2, 9));
+        assert_eq!(parser.parse_date_time("in 3 days"), LocalDateTime::of(2025, 1, 12, 12, 0, 0));
+        assert_eq!(parser.parse_date_time("in 3 days at 9am"), LocalDateTime::of(2025, 1, 12, 9, 0, 0));
+        assert_eq!(parser.parse_date_time("in 90 minutes"), LocalDateTime::of(2025, 1, 9, 13, 30, 0));
+        assert_eq!(parser.parse_date_time("13 hours ago"), LocalDateTime::of(2025, 1, 8, 23, 0, 0));
+        assert_eq!(parser.parse_date_time("now"), LocalDateTime::of(2025, 1, 9, 12, 0, 0));
+    }
+
+    #[test]
+    fn spans_and_ordinal_days() {
+        assert_eq!(date("end of month"), LocalDate::of(2025, 1, 31));
+        assert_eq!(date("start of the week"), LocalDate::of(2025, 1, 6));
+        assert_eq!(date("end of next week"), LocalDate::of(2025, 1, 19));
+        assert_eq!(date("beginning of 2026"), LocalDate::of(2026, 1, 1));
+        assert_eq!(date("last day of next month"), LocalDate::of(2025, 2, 28));
+        assert_eq!(date("first day of last month"), LocalDate::of(2024, 12, 1));
+        assert_eq!(date("last day of the year"), LocalDate::of(2025, 12, 31));
+        assert_eq!(date("100th day of 2025"), LocalDate::of(2025, 4, 10));
+
+        assert_eq!(date("2nd Tuesday of March"), LocalDate::of(2025, 3, 11));
+        assert_eq!(date("last friday of the month"), LocalDate::of(2025, 1, 31));
+        assert_eq!(date("first monday of next month"), LocalDate::of(2025, 2, 3));
+        assert_eq!(date("third wednesday of march 2026"), LocalDate::of(2026, 3, 18));
+        assert_eq!(date("1st sunday of january"), LocalDate::of(2025, 1, 5));
+    }
+
+    #[test]
+    fn calendar_dates_prefer_the_future() {
+        assert_eq!(date("march 14"), LocalDate::of(2025, 3, 14));
+        assert_eq!(date("14th of March, 2026"), LocalDate::of(2026, 3, 14));
+        assert_eq!(date("jan 5"), LocalDate::of(2026, 1, 5));
+        assert_eq!(date("9 january"), LocalDate::of(2025, 1, 9));
+        assert_eq!(date("Feb 29"), LocalDate::of(2028, 2, 29));
+        assert_eq!(date("2025-06-30"), LocalDate::of(2025, 6, 30));
+    }
+
+    #[test]
+    fn times_of_day() {
+        let parser = parser();
+        assert_eq!(parser.parse_date_time("next Friday at 17:00"), LocalDateTime::of(2025, 1, 10, 17, 0, 0));
+        assert_eq!(parser.parse_date_time("next thursday 5pm"), LocalDateTime::of(2025, 1, 16, 17, 0, 0));
+        assert_eq!(parser.parse_date_time("5:30 p.m. tomorrow"), LocalDateTime::of(2025, 1, 10, 17, 30, 0));
+        assert_eq!(parser.parse_date_time("tomorrow at noon"), LocalDateTime::of(2025, 1, 10, 12, 0, 0));
+        assert_eq!(parser.parse_date_time("march 14 at 9"), LocalDateTime::of(2025, 3, 14, 9, 0, 0));
+        assert_eq!(parser.parse_date_time("12am"), LocalDateTime::of(2025, 1, 9, 0, 0, 0));
+        assert_eq!(parser.parse_date_time("tomorrow"), LocalDateTime::of(2025, 1, 10, 0, 0, 0));
+    }
+
+    #[test]
+    fn zoned_results_use_the_clock_zone() {
+        let paris = ZoneId::try_of("Europe/Paris").unwrap();
+        let clock = Clock::fixed_at_local(LocalDateTime::of(2025, 3, 30, 1, 30, 0), paris);
+        let parser = NaturalDateParser::new(clock);
+
+        let tomorrow = parser.parse_zoned_date_time("tomorrow 9am");
+        assert_eq!(tomorrow.to_local_date_time(), LocalDateTime::of(2025, 3, 31, 9, 0, 0));
+        assert_eq!(tomorrow.offset(), ZoneOffset::of_hours(2));
+
+        // Clocks go forward at 02:00, so an elapsed hour later it is 03:30.
+        let later = parser.parse_zoned_date_time("in 1 hour");
+        assert_eq!(later.to_local_date_time(), LocalDateTime::of(2025, 3, 30, 3, 30, 0));
+    }
+
+    #[test]
+    fn errors_point_at_the_text() {
+        let parser = parser();
+        let error = parser.try_parse_date("next blursday").unwrap_err();
+        assert_eq!(error.error_index(), 5);
+        assert_eq!(error.expected(), "Expected a day of the week");
+
+        assert_eq!(parser.try_parse_date("tomorrow please").unwrap_err().error_index(), 9);
+        assert_eq!(parser.try_parse_date("3 days").unwrap_err().expected(), "Expected 'ago' or 'from now'");
+        assert_eq!(parser.try_parse_date("february 30 2025").unwrap_err().expected(), "Invalid date");
+        assert_eq!(parser.try_parse_date("5th friday of february").unwrap_err().expected(), "Invalid date: the month has no such day");
+        assert_eq!(parser.try_parse_date_time("tomorrow at 25:00").unwrap_err().expected(), "Invalid time");
+        assert_eq!(parser.try_parse_date("in 20000 years").unwrap_err().expected(), "Invalid date: out of range");
+        assert!(parser.try_parse_date("").is_err());
+    }
+
+    #[test]
+    fn dates_out_of_range_are_errors() {
+        let last = NaturalDateParser::new(Clock::fixed_at_local(LocalDateTime::of(9999, 12, 31, 12, 0, 0), ZoneId::UTC));
+        for text in ["tomorrow", "the day after tomorrow", "next friday", "monday", "end of this week", "first day of march"] {
+            assert_eq!(last.try_parse_date(text).unwrap_err().expected(), "Invalid date: out of range", "{text}");
+        }
+        assert_eq!(last.try_parse_date("this friday"), Ok(LocalDate::of(9999, 12, 31)));
+        assert_eq!(last.try_parse_date("last friday"), Ok(LocalDate::of(9999, 12, 24)));
+        assert_eq!(last.try_parse_date("5th friday of the month"), Ok(LocalDate::of(9999, 12, 31)));
+        assert!(last.try_parse_date("5th saturday of the month").is_err());
+
+        let first = NaturalDateParser::new(Clock::fixed_at_local(LocalDateTime::of(-9999, 1, 1, 12, 0, 0), ZoneId::UTC));
+        for text in ["yesterday", "day before yesterday", "last sunday", "start of last week"] {
+            assert_eq!(first.try_parse_date(text).unwrap_err().expected(), "Invalid date: out of range", "{text}");
+        }
+        assert_eq!(first.try_parse_date("start of this week"), Ok(LocalDate::of(-9999, 1, 1)));
+        assert_eq!(first.try_parse_date("tomorrow"), Ok(LocalDate::of(-9999, 1, 2)));
+    }
+}
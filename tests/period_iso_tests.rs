@@ -0,0 +1,43 @@
+mod tests {
+    use joda_rs::Period;
+
+    #[test]
+    fn parses_like_java() {
+        assert_eq!(Period::try_parse("P2Y"), Ok(Period::of_years(2)));
+        assert_eq!(Period::try_parse("P3M"), Ok(Period::of_months(3)));
+        assert_eq!(Period::try_parse("P4W"), Ok(Period::of_weeks(4)));
+        assert_eq!(Period::try_parse("P5D"), Ok(Period::of_days(5)));
+        assert_eq!(Period::try_parse("P1Y2M3D"), Ok(Period::of(1, 2, 3)));
+        assert_eq!(Period::try_parse("P1Y2M3W4D"), Ok(Period::of(1, 2, 25)));
+        assert_eq!(Period::try_parse("p1y2m3w4d"), Ok(Period::of(1, 2, 25)));
+        assert_eq!("P0D".parse(), Ok(Period::default()));
+    }
+
+    #[test]
+    fn parses_negative_components() {
+        assert_eq!(Period::try_parse("P-1Y2M"), Ok(Period::of(-1, 2, 0)));
+        assert_eq!(Period::try_parse("-P1Y2M"), Ok(Period::of(-1, -2, 0)));
+        assert_eq!(Period::try_parse("-P-1Y+2M"), Ok(Period::of(1, -2, 0)));
+        assert_eq!(Period::try_parse("P1W-3D"), Ok(Period::of_days(4)));
+        assert_eq!(Period::try_from("+P-2W"), Ok(Period::of_weeks(-2)));
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        for text in ["", "P", "PT1D", "P1D1Y", "P1M1Y", "P1.5D", "1Y", "P1Y ", "P2147483648D", "P306783379W"] {
+            assert!(Period::try_parse(text).is_err(), "{text}");
+        }
+        let err = Period::try_parse("P1Y2X").unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (3, "Unparsed text found after the end of the period"));
+        assert!(Period::try_parse("-P-2147483648D").is_err());
+        assert!(std::panic::catch_unwind(|| Period::parse("1:2:3")).is_err());
+    }
+
+    #[test]
+    fn iso_string_round_trips() {
+        for period in [Period::default(), Period::of(1, 2, 3), Period::of(0, -1, 0), Period::of(i32::MIN, i32::MAX, -7)] {
+            assert_eq!(Period::parse(&period.to_iso_string()), period);
+        }
+        assert_eq!(Period::of_weeks(2).to_iso_string(), "P14D");
+    }
+}
@@ -0,0 +1,87 @@
+mod tests {
+    use joda_rs::{
+        ChronoUnit, Clock, Duration, LocalDate, LocalDateTime, LocalTime, Period, RelativeTimeFormatter, RoundingMode,
+        YearMonth, ZoneId, ZonedDateTime,
+    };
+
+    fn clock() -> Clock {
+        // Thursday.
+        Clock::fixed_at_local(LocalDateTime::of(2025, 1, 9, 12, 0, 0), ZoneId::try_of("Europe/Paris").unwrap())
+    }
+
+    #[test]
+    fn instants_use_the_largest_unit_below_its_threshold() {
+        let formatter = RelativeTimeFormatter::new();
+        let now = clock().instant();
+        let format = |seconds: i64| formatter.format_now(&now.plus_seconds(seconds), &clock());
+
+        assert_eq!(format(0), "just now");
+        assert_eq!(format(-44), "just now");
+        assert_eq!(format(-45), "1 minute ago");
+        assert_eq!(format(5 * 60), "in 5 minutes");
+        assert_eq!(format(-44 * 60), "44 minutes ago");
+        assert_eq!(format(-45 * 60), "1 hour ago");
+        assert_eq!(format(-90 * 60), "2 hours ago");
+        assert_eq!(format(21 * 3600), "in 21 hours");
+        assert_eq!(format(-10 * 86_400), "1 week ago");
+        assert_eq!(format(14 * 86_400), "in 2 weeks");
+        assert_eq!(format(-60 * 86_400), "2 months ago");
+        assert_eq!(format(400 * 86_400), "in 1 year");
+    }
+
+    #[test]
+    fn calendar_days_are_named() {
+        let formatter = RelativeTimeFormatter::new();
+        let clock = clock();
+
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 9), &clock), "today");
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 8), &clock), "yesterday");
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 10), &clock), "tomorrow");
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 7), &clock), "last Tuesday");
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 14), &clock), "next Tuesday");
+        assert_eq!(formatter.format_now(&LocalDate::of(2025, 1, 2), &clock), "1 week ago");
+
+        // 22 hours back crosses midnight in Paris.
+        let zdt = ZonedDateTime::now_with_clock(&clock).minus_hours(22);
+        assert_eq!(formatter.format_now(&zdt, &clock), "yesterday");
+        assert_eq!(formatter.format_now(&zdt.to_instant(), &clock), "yesterday");
+
+        let counted = formatter.with_named_days(false);
+        assert_eq!(counted.format_now(&LocalDate::of(2025, 1, 7), &clock), "2 days ago");
+        assert_eq!(counted.format_now(&zdt, &clock), "1 day ago");
+        assert_eq!(counted.format_now(&zdt.plus_hours(1), &clock), "21 hours ago");
+    }
+
+    #[test]
+    fn thresholds_and_rounding_are_configurable() {
+        let formatter = RelativeTimeFormatter::new()
+            .with_just_now(Duration::of_seconds(0))
+            .with_threshold(ChronoUnit::Hours, 48)
+            .with_threshold(ChronoUnit::Days, 30);
+        assert_eq!(formatter.format_duration(Duration::of_seconds(-10)), "10 seconds ago");
+        assert_eq!(formatter.format_duration(Duration::of_hours(-36)), "36 hours ago");
+        assert_eq!(formatter.format_duration(Duration::of_days(21)), "in 21 days");
+        assert_eq!(formatter.with_threshold(ChronoUnit::Hours, 0).format_duration(Duration::of_hours(3)), "in 1 day");
+
+        let minutes = Duration::of_minutes(61);
+        assert_eq!(RelativeTimeFormatter::new().format_duration(minutes), "in 1 hour");
+        assert_eq!(RelativeTimeFormatter::new().with_rounding(RoundingMode::Up).format_duration(minutes), "in 2 hours");
+        assert_eq!(RelativeTimeFormatter::new().with_rounding(RoundingMode::Down).format_duration(minutes.plus_minutes(29)), "in 1 hour");
+
+        assert_eq!(RelativeTimeFormatter::new().format_period(Period::of(0, 1, 0)), "in 1 month");
+        assert_eq!(RelativeTimeFormatter::new().format_period(Period::of_days(-14)), "2 weeks ago");
+        assert_eq!(RelativeTimeFormatter::new().format_period(Period::of(-3, 0, 0)), "3 years ago");
+    }
+
+    #[test]
+    fn values_are_compared_with_a_reference() {
+        let formatter = RelativeTimeFormatter::new();
+        let meeting = LocalDateTime::of(2025, 1, 9, 9, 0, 0);
+        assert_eq!(formatter.format_relative_to(&meeting, &meeting.minus_minutes(30)), "in 30 minutes");
+        assert_eq!(formatter.format_relative_to(&meeting, &meeting.plus_days(3)), "last Thursday");
+        assert_eq!(formatter.format_relative_to(&LocalTime::of(9, 0, 0), &LocalTime::of(12, 0, 0)), "3 hours ago");
+        assert_eq!(formatter.format_now(&LocalDateTime::of(2025, 1, 9, 11, 0, 0), &clock()), "1 hour ago");
+
+        assert!(formatter.try_format_relative_to(&YearMonth::of(2025, 1), &YearMonth::of(2025, 2)).is_err());
+    }
+}
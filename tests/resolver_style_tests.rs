@@ -0,0 +1,70 @@
+mod tests {
+    use joda_rs::{DateTimeFormatter, LocalDate, LocalDateTime, LocalTime, MonthDay, ResolverStyle, YearMonth};
+
+    fn formatter(pattern: &str, style: ResolverStyle) -> DateTimeFormatter {
+        DateTimeFormatter::of_pattern(pattern).with_resolver_style(style)
+    }
+
+    #[test]
+    fn day_of_month_overflow() {
+        let date = |style, text| formatter("uuuu-MM-dd", style).parse::<LocalDate>(text);
+        let err = date(ResolverStyle::Strict, "2023-02-30").unwrap_err();
+        assert_eq!(err.expected(), "Invalid date: the day-of-month is past the end of the month");
+        assert_eq!(date(ResolverStyle::Strict, "+99999-01-01").unwrap_err().expected(), "Invalid date: year out of range");
+        assert_eq!(date(ResolverStyle::Smart, "+99999-01-01").unwrap_err().expected(), "Invalid date: year out of range");
+        assert_eq!(LocalDate::try_parse("+99999-01-01").unwrap_err().expected(), "Invalid date: year out of range");
+        assert_eq!(date(ResolverStyle::Smart, "2023-02-30"), Ok(LocalDate::of(2023, 2, 28)));
+        assert_eq!(date(ResolverStyle::Lenient, "2023-02-30"), Ok(LocalDate::of(2023, 3, 2)));
+
+        assert_eq!(date(ResolverStyle::Strict, "2024-02-29"), Ok(LocalDate::of(2024, 2, 29)));
+        assert!(date(ResolverStyle::Smart, "2023-02-32").is_err());
+        assert_eq!(date(ResolverStyle::Lenient, "2023-02-32"), Ok(LocalDate::of(2023, 3, 4)));
+        assert_eq!(date(ResolverStyle::Lenient, "2023-03-00"), Ok(LocalDate::of(2023, 2, 28)));
+    }
+
+    #[test]
+    fn out_of_range_fields() {
+        let date = |style, text| formatter("uuuu-MM-dd", style).parse::<LocalDate>(text);
+        assert!(date(ResolverStyle::Strict, "2023-13-01").is_err());
+        assert!(date(ResolverStyle::Smart, "2023-13-01").is_err());
+        assert_eq!(date(ResolverStyle::Lenient, "2023-13-01"), Ok(LocalDate::of(2024, 1, 1)));
+        assert_eq!(date(ResolverStyle::Lenient, "2023-00-15"), Ok(LocalDate::of(2022, 12, 15)));
+        assert_eq!(formatter("uuuu-MM", ResolverStyle::Lenient).parse::<YearMonth>("2023-25"), Ok(YearMonth::of(2025, 1)));
+
+        let day_of_year = |style, text| formatter("uuuu-DDD", style).parse::<LocalDate>(text);
+        assert!(day_of_year(ResolverStyle::Smart, "2023-366").is_err());
+        assert_eq!(day_of_year(ResolverStyle::Lenient, "2023-366"), Ok(LocalDate::of(2024, 1, 1)));
+
+        let time = |style, text| formatter("HH:mm:ss", style).parse::<LocalTime>(text);
+        assert!(time(ResolverStyle::Smart, "10:75:00").is_err());
+        assert_eq!(time(ResolverStyle::Lenient, "10:75:61"), Ok(LocalTime::of(11, 16, 1)));
+        assert!(formatter("--MM-dd", ResolverStyle::Lenient).parse::<MonthDay>("--02-30").is_err());
+    }
+
+    #[test]
+    fn hour_twenty_four() {
+        let ldt = |style, text| formatter("uuuu-MM-dd HH:mm", style).parse::<LocalDateTime>(text);
+        assert!(ldt(ResolverStyle::Strict, "2024-12-31 24:00").is_err());
+        assert_eq!(ldt(ResolverStyle::Smart, "2024-12-31 24:00"), Ok(LocalDateTime::of(2025, 1, 1, 0, 0, 0)));
+        assert!(ldt(ResolverStyle::Smart, "2024-12-31 24:01").is_err());
+        assert_eq!(ldt(ResolverStyle::Lenient, "2024-12-31 24:01"), Ok(LocalDateTime::of(2025, 1, 1, 0, 1, 0)));
+        assert_eq!(ldt(ResolverStyle::Lenient, "2024-12-31 49:00"), Ok(LocalDateTime::of(2025, 1, 2, 1, 0, 0)));
+
+        assert_eq!(formatter("HH:mm", ResolverStyle::Smart).parse::<LocalTime>("24:00"), Ok(LocalTime::of(0, 0, 0)));
+        assert_eq!(formatter("kk:mm", ResolverStyle::Strict).parse::<LocalTime>("24:00"), Ok(LocalTime::of(0, 0, 0)));
+        assert!(formatter("kk:mm", ResolverStyle::Strict).parse::<LocalTime>("00:00").is_err());
+        assert_eq!(formatter("kk:mm", ResolverStyle::Smart).parse::<LocalTime>("00:00"), Ok(LocalTime::of(0, 0, 0)));
+    }
+
+    #[test]
+    fn iso_formatters_are_strict_and_patterns_smart() {
+        assert_eq!(DateTimeFormatter::iso_local_date().resolver_style(), ResolverStyle::Strict);
+        assert_eq!(DateTimeFormatter::rfc_9557().resolver_style(), ResolverStyle::Strict);
+        assert_eq!(DateTimeFormatter::of_pattern("uuuu").resolver_style(), ResolverStyle::Smart);
+        assert!(LocalDate::try_parse("2023-02-29").is_err());
+        assert!(LocalDateTime::try_parse("2023-01-01T24:00").is_err());
+
+        let lenient = DateTimeFormatter::iso_local_date_time().with_resolver_style(ResolverStyle::Lenient);
+        assert_eq!(lenient.parse::<LocalDateTime>("2023-02-29T24:00"), Ok(LocalDateTime::of(2023, 3, 2, 0, 0, 0)));
+    }
+}
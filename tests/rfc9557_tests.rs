@@ -0,0 +1,69 @@
+mod tests {
+    use joda_rs::{DateTimeFormatter, LocalDateTime, OffsetConflict, OffsetDateTime, ZoneId, ZoneOffset, ZonedDateTime};
+
+    fn new_york() -> ZoneId {
+        ZoneId::try_of("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn round_trips_bracketed_zone_ids() {
+        let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 3, 10, 3, 30, 0), new_york());
+        assert_eq!(zdt.to_string(), "2024-03-10T03:30-04:00[America/New_York]");
+        assert_eq!(ZonedDateTime::try_parse("2024-03-10T03:30-04:00[America/New_York]"), Ok(zdt.clone()));
+
+        let formatter = DateTimeFormatter::rfc_9557();
+        assert_eq!(formatter.format(&zdt), "2024-03-10T03:30:00-04:00[America/New_York]");
+        assert_eq!(formatter.parse::<ZonedDateTime>(&zdt.to_string()), Ok(zdt));
+
+        // Both offsets of an overlap agree with the zone and pick the instant.
+        let earlier = ZonedDateTime::parse("2024-11-03T01:30-04:00[America/New_York]");
+        let later = ZonedDateTime::parse("2024-11-03T01:30-05:00[America/New_York]");
+        assert_eq!(later.to_instant().epoch_seconds() - earlier.to_instant().epoch_seconds(), 3600);
+        assert_eq!(later.offset(), ZoneOffset::of_hours(-5));
+    }
+
+    #[test]
+    fn accepts_critical_flags_and_suffix_tags() {
+        let expected = ZonedDateTime::of(LocalDateTime::of(2024, 7, 1, 12, 0, 0), ZoneId::try_of("Europe/Paris").unwrap());
+        assert_eq!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[!Europe/Paris]"), Ok(expected.clone()));
+        assert_eq!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[Europe/Paris][u-ca=iso8601][x-foo=bar]"), Ok(expected.clone()));
+        assert_eq!(ZonedDateTime::try_parse("2024-07-01T10:00Z[u-ca=iso8601]").map(|zdt| zdt.to_instant()), Ok(expected.to_instant()));
+
+        assert!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[Europe/Paris][!x-foo=bar]").is_err());
+        assert!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[Europe/Paris][!u-ca=hebrew]").is_err());
+        assert!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[Europe/Paris").is_err());
+        assert!(ZonedDateTime::try_parse("2024-07-01T12:00+02:00[Mars/Olympus]").is_err());
+    }
+
+    #[test]
+    fn offset_conflicts_follow_the_policy() {
+        let text = "2024-07-01T12:00+05:00[America/New_York]";
+        let err = ZonedDateTime::try_parse(text).unwrap_err();
+        assert_eq!(err.expected(), "Offset does not match the time zone");
+
+        let by_offset = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferOffset).unwrap();
+        assert_eq!(by_offset.to_string(), "2024-07-01T03:00-04:00[America/New_York]");
+
+        let by_zone = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferZone).unwrap();
+        assert_eq!(by_zone.to_string(), "2024-07-01T12:00-04:00[America/New_York]");
+
+        let formatter = DateTimeFormatter::rfc_9557().with_offset_conflict(OffsetConflict::PreferZone);
+        let odt: OffsetDateTime = formatter.parse(text).unwrap();
+        assert_eq!(odt.offset(), -4 * 3600);
+    }
+
+    #[test]
+    fn offset_conflicts_past_the_end_of_the_range_are_errors() {
+        let text = "9999-12-31T23:00Z[Pacific/Kiritimati]";
+        let err = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferOffset).unwrap_err();
+        assert_eq!((err.error_index(), err.expected()), (16, "Instant exceeds the supported range"));
+        let by_zone = ZonedDateTime::try_parse_with_offset_conflict(text, OffsetConflict::PreferZone).unwrap();
+        assert_eq!(by_zone.to_string(), "9999-12-31T23:00+14:00[Pacific/Kiritimati]");
+
+        let kiritimati = ZoneId::try_of("Pacific/Kiritimati").unwrap();
+        let ldt = LocalDateTime::of(9999, 12, 31, 23, 0, 0);
+        assert_eq!(OffsetConflict::PreferOffset.resolve(ldt, ZoneOffset::UTC, kiritimati.clone()), Err("Instant exceeds the supported range"));
+        assert_eq!(OffsetConflict::PreferZone.resolve(ldt, ZoneOffset::UTC, kiritimati.clone()), Ok(ZonedDateTime::of(ldt, kiritimati)));
+        assert_eq!(OffsetConflict::Reject.resolve(ldt, ZoneOffset::of_hours(-5), new_york()), Err("Instant exceeds the supported range"));
+    }
+}
@@ -0,0 +1,113 @@
+mod tests {
+    use joda_rs::{Clock, DateTimeFormatter, Instant, LocalDate, LocalDateTime, OffsetDateTime, ZoneId, ZoneOffset, ZonedDateTime};
+
+    fn instant() -> Instant {
+        LocalDateTime::of(1994, 11, 6, 8, 49, 37).to_instant_utc()
+    }
+
+    #[test]
+    fn http_dates_format_in_gmt() {
+        let formatter = DateTimeFormatter::rfc_1123_date_time();
+        assert_eq!(formatter.format(&instant()), "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        let odt = OffsetDateTime::of(LocalDateTime::of(1994, 11, 6, 3, 49, 37), ZoneOffset::of_hours(-5));
+        assert_eq!(formatter.format(&odt), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert!(formatter.try_format(&LocalDateTime::of(1994, 11, 6, 8, 49, 37)).is_err());
+    }
+
+    #[test]
+    fn http_dates_past_the_end_of_the_range_are_errors() {
+        let formatter = DateTimeFormatter::rfc_1123_date_time();
+        let err = formatter.parse::<Instant>("Fri, 31 Dec 9999 23:00:00 EST").unwrap_err();
+        assert_eq!(err.expected(), "Instant exceeds the supported range");
+
+        let odt = OffsetDateTime::of(LocalDateTime::of(9999, 12, 31, 23, 0, 0), ZoneOffset::of_hours(-5));
+        assert_eq!(formatter.try_format(&odt), Err("Instant exceeds the supported range"));
+    }
+
+    #[test]
+    fn http_dates_parse_all_three_forms() {
+        let formatter = DateTimeFormatter::rfc_1123_date_time();
+        for text in ["Sun, 06 Nov 1994 08:49:37 GMT", "Sunday, 06-Nov-94 08:49:37 GMT", "Sun Nov  6 08:49:37 1994"] {
+            assert_eq!(formatter.parse::<Instant>(text), Ok(instant()), "{text}");
+        }
+        let odt: OffsetDateTime = formatter.parse("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert_eq!(odt.offset(), 0);
+    }
+
+    #[test]
+    fn rfc_850_years_roll_with_the_current_year() {
+        let current = LocalDate::now().year();
+        let formatter = DateTimeFormatter::rfc_1123_date_time();
+        let parsed: OffsetDateTime = formatter.parse(&format!("01-Jan-{:02} 00:00:00 GMT", current.rem_euclid(100))).unwrap();
+        assert_eq!(parsed.year(), current);
+
+        let clock = Clock::fixed_at_local(LocalDateTime::of(2030, 6, 1, 0, 0, 0), ZoneId::UTC);
+        let formatter = DateTimeFormatter::rfc_1123_date_time().with_clock(clock.clone());
+        assert_eq!(formatter.clock(), clock);
+        for (digits, expected) in [(40, 2040), (80, 2080), (81, 1981), (29, 2029), (0, 2000)] {
+            let text = format!("01-Jan-{digits:02} 00:00:00 GMT");
+            let parsed: OffsetDateTime = formatter.parse(&text).unwrap();
+            assert_eq!(parsed.year(), expected, "{text}");
+        }
+        // Two-digit years of RFC 2822 keep the fixed 1950 to 2049 window.
+        let email: OffsetDateTime = DateTimeFormatter::rfc_2822_date_time().parse("1 Jan 94 00:00 GMT").unwrap();
+        assert_eq!(email.year(), 1994);
+    }
+
+    #[test]
+    fn email_dates_keep_their_offset() {
+        let formatter = DateTimeFormatter::rfc_2822_date_time();
+        let odt = OffsetDateTime::of(LocalDateTime::of(2003, 7, 1, 10, 52, 37), ZoneOffset::of_hours_minutes(2, 0));
+        assert_eq!(formatter.format(&odt), "Tue, 01 Jul 2003 10:52:37 +0200");
+        assert_eq!(formatter.parse::<OffsetDateTime>("Tue, 1 Jul 2003 10:52:37 +0200"), Ok(odt));
+        assert_eq!(formatter.format(&instant()), "Sun, 06 Nov 1994 08:49:37 +0000");
+        assert!(formatter.parse::<ZonedDateTime>("Tue, 01 Jul 2003 10:52:37 +0200").is_ok());
+    }
+
+    #[test]
+    fn obsolete_email_syntax_is_accepted() {
+        let formatter = DateTimeFormatter::rfc_2822_date_time();
+        let parse = |text: &str| formatter.parse::<OffsetDateTime>(text).unwrap();
+
+        let est = parse("Sun, 6 Nov 1994 03:49:37 EST");
+        assert_eq!(est.offset(), -5 * 3600);
+        assert_eq!(est.to_instant(), instant());
+        assert_eq!(parse("6 Nov 94 01:49:37 PDT").to_instant(), instant());
+        assert_eq!(parse("Sun,6 Nov 1994 08:49 UT").to_local_date_time(), LocalDateTime::of(1994, 11, 6, 8, 49, 0));
+        assert_eq!(parse("Thu, 13 Feb 14 23:32:00 -0330").to_local_date_time().year(), 2014);
+        assert_eq!(parse("Fri, 21 Nov 097 09:55:06 -0600").to_local_date_time().year(), 1997);
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 +0000 (Coordinated Universal Time)").to_instant(), instant());
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 z").offset(), 0);
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 A").offset(), 0);
+    }
+
+    #[test]
+    fn comments_and_folding_whitespace_are_skipped_between_tokens() {
+        let formatter = DateTimeFormatter::rfc_2822_date_time();
+        let parse = |text: &str| formatter.parse::<Instant>(text).unwrap();
+
+        assert_eq!(parse("(c) Sun, 6 Nov 1994 08:49:37 GMT"), instant());
+        assert_eq!(parse("Sun, 6 (x) Nov 1994 08:49:37 GMT"), instant());
+        assert_eq!(parse("Sun, 6 Nov 1994 08 : 49 : 37 GMT"), instant());
+        assert_eq!(parse("Sun (day) , 6(x)Nov(y)1994 08(h):(m)49 : 37(s)GMT"), instant());
+        assert_eq!(parse("Sun, 6 Nov 1994\r\n 08:49:37 (a (nested) \\) comment) GMT (PST)"), instant());
+        assert_eq!(parse("Sun, 6 Nov 1994 08 : 49 GMT"), instant().minus_seconds(37));
+
+        let error = formatter.parse::<Instant>("Sun, 6 Nov 1994 08:49:37 GMT (open (nested)").unwrap_err();
+        assert_eq!(error.expected(), "Expected ) to close the comment");
+        assert_eq!(error.error_index(), 29);
+    }
+
+    #[test]
+    fn invalid_text_is_rejected() {
+        let formatter = DateTimeFormatter::rfc_2822_date_time();
+        let error = formatter.parse::<Instant>("Mon, 06 Nov 1994 08:49:37 GMT").unwrap_err();
+        assert_eq!(error.expected(), "Conflict found: DayOfWeek differs from the date");
+        let error = formatter.parse::<Instant>("Sun, 06 Nov 1994 08:49:37 XYZ").unwrap_err();
+        assert_eq!(error.error_index(), 26);
+        assert!(formatter.parse::<Instant>("Sun, 06 Nov 1994 08:49:37 J").is_err());
+        assert!(formatter.parse::<Instant>("Sun, 31 Nov 1994 08:49:37 GMT").is_err());
+        assert!(formatter.parse::<Instant>("Sun, 06 Nov 1994 08:49:37").is_err());
+    }
+}
@@ -107,6 +107,17 @@ fn zoned_date_time_serde_round_trip() {
     assert_eq!(zdt, deserialized);
 }
 
+#[test]
+fn zoned_date_time_serde_defaults_to_rfc_3339() {
+    let paris = ZoneId::try_of("Europe/Paris").unwrap();
+    let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 7, 3, 11, 46, 40), paris);
+    let json = serde_json::to_string(&zdt).expect("serialization failed");
+    assert_eq!(json, r#""2024-07-03T11:46:40+02:00""#);
+    let deserialized: ZonedDateTime = serde_json::from_str(&json).expect("deserialization failed");
+    assert_eq!(deserialized.to_instant(), zdt.to_instant());
+    assert_eq!(deserialized.zone(), ZoneId::UTC);
+}
+
 #[test]
 fn instant_serde_round_trip() {
     let instant = Instant::of_epoch_millisecond(1734012645000);
@@ -368,3 +379,80 @@ fn positive_offset_round_trip() {
     let deserialized: ZoneOffset = serde_json::from_str(&json).expect("deserialization failed");
     assert_eq!(offset, deserialized);
 }
+
+#[test]
+fn zoned_date_time_rfc9557_keeps_the_zone() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Meeting {
+        #[serde(with = "joda_rs::serde_time::rfc9557")]
+        start: ZonedDateTime,
+    }
+
+    let new_york = ZoneId::try_of("America/New_York").unwrap();
+    let meeting = Meeting { start: ZonedDateTime::of(LocalDateTime::of(2024, 3, 10, 3, 30, 0), new_york.clone()) };
+
+    let json = serde_json::to_string(&meeting).expect("serialization failed");
+    assert_eq!(json, r#"{"start":"2024-03-10T03:30-04:00[America/New_York]"}"#);
+    let deserialized: Meeting = serde_json::from_str(&json).expect("deserialization failed");
+    assert_eq!(deserialized, meeting);
+    assert_eq!(deserialized.start.zone(), new_york);
+
+    let conflicting = r#"{"start":"2024-03-10T03:30-05:00[America/New_York]"}"#;
+    assert!(serde_json::from_str::<Meeting>(conflicting).is_err());
+}
+
+#[test]
+fn duration_iso_duration_uses_iso_text() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Job {
+        #[serde(with = "joda_rs::serde_time::iso_duration")]
+        timeout: Duration,
+    }
+
+    let job = Job { timeout: Duration::of_hours(8).plus_minutes(6).plus_milliseconds(12_345) };
+    let json = serde_json::to_string(&job).expect("serialization failed");
+    assert_eq!(json, r#"{"timeout":"PT8H6M12.345S"}"#);
+    let deserialized: Job = serde_json::from_str(&json).expect("deserialization failed");
+    assert_eq!(deserialized, job);
+
+    let days: Job = serde_json::from_str(r#"{"timeout":"P2DT-3H"}"#).expect("deserialization failed");
+    assert_eq!(days.timeout, Duration::of_hours(45));
+    assert!(serde_json::from_str::<Job>(r#"{"timeout":"1h30m"}"#).is_err());
+}
+
+#[test]
+fn period_iso_period_uses_iso_text() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Subscription {
+        #[serde(with = "joda_rs::serde_time::iso_period")]
+        term: Period,
+    }
+
+    let monthly: Subscription = serde_json::from_str(r#"{"term":"P1M"}"#).expect("deserialization failed");
+    assert_eq!(monthly.term, Period::of_months(1));
+    let yearly: Subscription = serde_json::from_str(r#"{"term":"P1Y"}"#).expect("deserialization failed");
+    assert_eq!(serde_json::to_string(&yearly).expect("serialization failed"), r#"{"term":"P1Y"}"#);
+
+    let trial: Subscription = serde_json::from_str(r#"{"term":"P2W"}"#).expect("deserialization failed");
+    assert_eq!(trial.term, Period::of_days(14));
+    assert_eq!(serde_json::to_string(&trial).expect("serialization failed"), r#"{"term":"P14D"}"#);
+    assert!(serde_json::from_str::<Subscription>(r#"{"term":"1:0:0"}"#).is_err());
+}
+
+#[test]
+fn year_week_serde_round_trip() {
+    let week = YearWeek::of(2020, 53);
+    let json = serde_json::to_string(&week).expect("serialization failed");
+    assert_eq!(json, r#"{"year":2020,"week":53}"#);
+    let deserialized: YearWeek = serde_json::from_str(&json).expect("deserialization failed");
+    assert_eq!(deserialized, week);
+}
+
+#[test]
+fn year_week_serde_rejects_invalid_weeks() {
+    for json in [r#"{"year":2024,"week":0}"#, r#"{"year":2024,"week":54}"#, r#"{"year":2023,"week":53}"#] {
+        let error = serde_json::from_str::<YearWeek>(json).unwrap_err();
+        assert!(error.to_string().contains("Invalid value for WeekOfWeekBasedYear"), "{json}: {error}");
+    }
+    assert!(serde_json::from_str::<YearWeek>(r#"{"year":10000,"week":1}"#).is_err());
+}
@@ -0,0 +1,47 @@
+mod tests {
+    use joda_rs::{Clock, LocalDate, LocalDateTime, ZoneId, ZonedDateTime};
+
+    fn set_tz(value: &str) {
+        // SAFETY: this is the only test in this binary, so no other thread reads the environment.
+        unsafe { std::env::set_var("TZ", value) };
+        let _ = ZoneId::refresh_system_default();
+    }
+
+    #[test]
+    fn system_default_honours_tz_and_falls_back_to_utc() {
+        set_tz("America/New_York");
+        assert_eq!(ZoneId::system_default().id(), "America/New_York");
+        assert_eq!(Clock::system_default_zone().zone().id(), "America/New_York");
+        assert_eq!(ZonedDateTime::now().zone().id(), "America/New_York");
+
+        set_tz(":Europe/Paris");
+        assert_eq!(ZoneId::system_default().id(), "Europe/Paris");
+
+        set_tz("/usr/share/zoneinfo/posix/Asia/Tokyo");
+        assert_eq!(ZoneId::system_default().id(), "Asia/Tokyo");
+
+        set_tz("");
+        assert_eq!(ZoneId::try_system_default(), Ok(ZoneId::UTC));
+
+        set_tz("Mars/Olympus_Mons");
+        assert_eq!(ZoneId::try_system_default(), Err("Unknown time zone in TZ"));
+        assert_eq!(ZoneId::system_default(), ZoneId::UTC);
+
+        // The zone is cached until it is refreshed.
+        set_tz("Europe/Paris");
+        unsafe { std::env::set_var("TZ", "Asia/Tokyo") };
+        assert_eq!(ZoneId::system_default().id(), "Europe/Paris");
+        assert_eq!(LocalDateTime::now().to_local_date(), LocalDateTime::now_with_zone(ZoneId::try_of("Europe/Paris").unwrap()).to_local_date());
+        assert_eq!(ZoneId::refresh_system_default().unwrap().id(), "Asia/Tokyo");
+        assert_eq!(ZoneId::system_default().id(), "Asia/Tokyo");
+
+        // Every now() agrees on the zone. Retry in the unlikely case midnight passes in between.
+        set_tz("Pacific/Kiritimati");
+        let zone = ZoneId::system_default();
+        let consistent = (0..3).any(|_| {
+            let expected = LocalDateTime::now_with_zone(zone.clone());
+            LocalDate::now() == expected.to_local_date() && LocalDateTime::now().to_local_date() == expected.to_local_date()
+        });
+        assert!(consistent);
+    }
+}
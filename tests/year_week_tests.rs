@@ -0,0 +1,96 @@
+mod tests {
+    use joda_rs::{Clock, DayOfWeek, LocalDate, LocalDateTime, YearWeek, ZoneId};
+
+    #[test]
+    fn construction_and_accessors() {
+        let week = YearWeek::of(2024, 5);
+        assert_eq!(week.year(), 2024);
+        assert_eq!(week.week(), 5);
+        assert_eq!(YearWeek::from(LocalDate::of(2024, 12, 30)), YearWeek::of(2025, 1));
+
+        let clock = Clock::fixed_at_local(LocalDateTime::of(2021, 1, 2, 12, 0, 0), ZoneId::UTC);
+        assert_eq!(YearWeek::now_with_clock(&clock), YearWeek::of(2020, 53));
+        let _ = YearWeek::now();
+    }
+
+    #[test]
+    #[should_panic]
+    fn of_rejects_week_53_of_a_short_year() {
+        YearWeek::of(2023, 53);
+    }
+
+    #[test]
+    fn try_of_validates_the_year_and_week() {
+        assert_eq!(YearWeek::try_of(2020, 53), Ok(YearWeek::of(2020, 53)));
+        assert_eq!(YearWeek::try_of(-9999, 1).map(YearWeek::year), Ok(-9999));
+        assert_eq!(YearWeek::try_of(2023, 53), Err("Invalid value for WeekOfWeekBasedYear"));
+        assert_eq!(YearWeek::try_of(2024, 0), Err("Invalid value for WeekOfWeekBasedYear"));
+        assert_eq!(YearWeek::try_of(10_000, 1), Err("Invalid value for WeekBasedYear"));
+        assert_eq!(YearWeek::try_of(-10_000, 1), Err("Invalid value for WeekBasedYear"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_year_rejects_years_out_of_range() {
+        YearWeek::of(2024, 5).with_year(10_000);
+    }
+
+    #[test]
+    fn length_of_year() {
+        assert_eq!(YearWeek::of(2020, 1).length_of_year(), 53);
+        assert_eq!(YearWeek::of(2024, 1).length_of_year(), 52);
+        assert_eq!(YearWeek::of(2026, 1).length_of_year(), 53);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let week = YearWeek::of(2020, 52);
+        assert_eq!(week.plus_weeks(1), YearWeek::of(2020, 53));
+        assert_eq!(week.plus_weeks(2), YearWeek::of(2021, 1));
+        assert_eq!(YearWeek::of(2021, 1).minus_weeks(2), week);
+        assert_eq!(YearWeek::of(2020, 53).plus_years(1), YearWeek::of(2021, 52));
+        assert_eq!(YearWeek::of(2020, 53).minus_years(5), YearWeek::of(2015, 53));
+        assert_eq!(week.with_week(10), YearWeek::of(2020, 10));
+        assert_eq!(week.with_year(2024), YearWeek::of(2024, 52));
+
+        assert_eq!(week.weeks_until(YearWeek::of(2021, 1)), 2);
+        assert_eq!(YearWeek::of(2021, 1).weeks_until(week), -2);
+        assert_eq!(YearWeek::of(2024, 1).weeks_until(YearWeek::of(2025, 1)), 52);
+
+        assert!(week.is_before(YearWeek::of(2020, 53)));
+        assert!(YearWeek::of(2021, 1).is_after(week));
+        assert!(week.is_on_or_before(week) && week.is_on_or_after(week));
+    }
+
+    #[test]
+    fn days_of_the_week() {
+        let week = YearWeek::of(2025, 1);
+        assert_eq!(week.at_day(DayOfWeek::Monday), LocalDate::of(2024, 12, 30));
+        assert_eq!(week.at_day(DayOfWeek::Sunday), LocalDate::of(2025, 1, 5));
+    }
+
+    #[test]
+    fn iteration() {
+        let weeks: Vec<YearWeek> = YearWeek::of(2020, 52).iter_until(YearWeek::of(2021, 2)).collect();
+        assert_eq!(weeks, [YearWeek::of(2020, 52), YearWeek::of(2020, 53), YearWeek::of(2021, 1)]);
+        assert_eq!(YearWeek::of(2024, 1).iter_until(YearWeek::of(2025, 1)).count(), 52);
+        assert_eq!(YearWeek::of(2024, 5).iter_until(YearWeek::of(2024, 5)).count(), 0);
+        assert_eq!(YearWeek::of(2024, 5).iter_until(YearWeek::of(2024, 1)).count(), 0);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(YearWeek::parse("2024-W05"), YearWeek::of(2024, 5));
+        assert_eq!("2020-W53".parse::<YearWeek>(), Ok(YearWeek::of(2020, 53)));
+        assert_eq!(YearWeek::try_from("2021-W01"), Ok(YearWeek::of(2021, 1)));
+        assert_eq!(YearWeek::of(2024, 5).to_string(), "2024-W05");
+
+        assert!(YearWeek::try_parse("2023-W53").is_err());
+        assert!(YearWeek::try_parse("2024-W5").is_err());
+        assert_eq!(YearWeek::try_parse("2024-W05-3").unwrap_err().error_index(), 8);
+        assert_eq!(YearWeek::try_parse("2024-W05x").unwrap_err().error_index(), 8);
+        assert_eq!(YearWeek::try_parse("2024-W05-3x").unwrap_err().error_index(), 8);
+        assert_eq!(YearWeek::try_parse("2024-X05").unwrap_err().error_index(), 4);
+        assert_eq!(YearWeek::try_parse("+10000-W01").unwrap_err().expected(), "Invalid date: out of range");
+    }
+}
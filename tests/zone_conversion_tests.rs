@@ -0,0 +1,103 @@
+mod tests {
+    use joda_rs::{Instant, LocalDateTime, OffsetDateTime, ZoneId, ZoneOffset, ZonedDateTime};
+
+    fn zone(id: &str) -> ZoneId {
+        ZoneId::try_of(id).unwrap()
+    }
+
+    #[test]
+    fn with_zone_same_instant_converts_meeting_times() {
+        let meeting = ZonedDateTime::of(LocalDateTime::of(2024, 3, 28, 9, 0, 0), zone("America/New_York"));
+
+        let london = meeting.with_zone_same_instant(zone("Europe/London"));
+        let kolkata = meeting.with_zone_same_instant(zone("Asia/Kolkata"));
+
+        // New York is already on daylight saving time, London is not yet.
+        assert_eq!(london.to_local_date_time(), LocalDateTime::of(2024, 3, 28, 13, 0, 0));
+        assert_eq!(kolkata.to_local_date_time(), LocalDateTime::of(2024, 3, 28, 18, 30, 0));
+        assert_eq!(london.to_instant(), meeting.to_instant());
+        assert_eq!(kolkata.zone(), zone("Asia/Kolkata"));
+    }
+
+    #[test]
+    fn with_zone_same_local_resolves_gaps_and_overlaps() {
+        let utc = |ldt| ZonedDateTime::of(ldt, ZoneId::UTC);
+
+        let in_gap = utc(LocalDateTime::of(2024, 3, 10, 2, 30, 0)).with_zone_same_local(zone("America/New_York"));
+        assert_eq!(in_gap.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 3, 30, 0));
+        assert_eq!(in_gap.offset(), ZoneOffset::of_hours(-4));
+
+        let in_overlap = utc(LocalDateTime::of(2024, 11, 3, 1, 30, 0)).with_zone_same_local(zone("America/New_York"));
+        assert_eq!(in_overlap.offset(), ZoneOffset::of_hours(-4));
+
+        // The later offset is kept when moving between zones that share it.
+        let chicago = ZonedDateTime::of(LocalDateTime::of(2024, 11, 3, 1, 30, 0), zone("America/Chicago"))
+            .with_later_offset_at_overlap();
+        let kept = chicago.with_zone_same_local(zone("America/Cancun")).with_zone_same_local(zone("America/New_York"));
+        assert_eq!(kept.offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(kept.to_local_date_time(), LocalDateTime::of(2024, 11, 3, 1, 30, 0));
+    }
+
+    #[test]
+    fn offset_date_time_to_zoned_date_time() {
+        let odt = OffsetDateTime::of(LocalDateTime::of(2024, 11, 3, 1, 30, 0), ZoneOffset::of_hours(-5));
+
+        let same_instant = odt.at_zone_same_instant(zone("Europe/Paris"));
+        assert_eq!(same_instant.to_local_date_time(), LocalDateTime::of(2024, 11, 3, 7, 30, 0));
+
+        let similar_local = odt.at_zone_similar_local(zone("America/New_York"));
+        assert_eq!(similar_local.offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(similar_local.to_instant(), odt.to_instant());
+
+        let fixed = odt.to_zoned_date_time();
+        assert_eq!(fixed.zone().id(), "-05:00");
+        assert_eq!(fixed.to_offset_date_time(), odt);
+        assert_eq!(fixed.plus_days(1).offset(), ZoneOffset::of_hours(-5));
+    }
+
+    #[test]
+    fn to_offset_date_time_and_fixed_offset_zone() {
+        let zdt = Instant::of_epoch_second(1_720_000_000).at_zone(zone("America/New_York"));
+        let odt = zdt.to_offset_date_time();
+
+        assert_eq!(odt.to_local_date_time(), zdt.to_local_date_time());
+        assert_eq!(odt.offset(), -4 * 3600);
+
+        let fixed = zdt.with_fixed_offset_zone();
+        assert_eq!(fixed.zone(), ZoneId::from(ZoneOffset::of_hours(-4)));
+        assert_eq!(fixed.plus_months(6).offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(zdt.plus_months(6).offset(), ZoneOffset::of_hours(-5));
+    }
+
+    #[test]
+    fn with_offset_same_local_changes_the_instant() {
+        let odt = OffsetDateTime::of(LocalDateTime::of(2024, 7, 1, 9, 0, 0), ZoneOffset::UTC);
+        let shifted = odt.with_offset_same_local(ZoneOffset::of_hours_minutes(5, 30));
+
+        assert_eq!(shifted.to_local_date_time(), odt.to_local_date_time());
+        assert_eq!(odt.epoch_seconds() - shifted.epoch_seconds(), 19_800);
+    }
+
+    #[test]
+    fn of_local_and_of_strict() {
+        let new_york = zone("America/New_York");
+        let overlap = LocalDateTime::of(2024, 11, 3, 1, 30, 0);
+
+        assert_eq!(ZonedDateTime::of_local(overlap, new_york.clone(), None).offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(ZonedDateTime::of_local(overlap, new_york.clone(), Some(ZoneOffset::of_hours(-5))).offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(ZonedDateTime::of_local(overlap, new_york.clone(), Some(ZoneOffset::UTC)).offset(), ZoneOffset::of_hours(-4));
+
+        assert_eq!(
+            ZonedDateTime::of_strict(overlap, ZoneOffset::of_hours(-5), new_york.clone()).unwrap().offset(),
+            ZoneOffset::of_hours(-5)
+        );
+        assert_eq!(
+            ZonedDateTime::of_strict(overlap, ZoneOffset::UTC, new_york.clone()).unwrap_err(),
+            "Offset is not valid for the local date-time in the time zone"
+        );
+        assert_eq!(
+            ZonedDateTime::of_strict(LocalDateTime::of(2024, 3, 10, 2, 30, 0), ZoneOffset::of_hours(-5), new_york).unwrap_err(),
+            "Local date-time falls in a gap in the time zone"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+mod tests {
+    use joda_rs::{Instant, LocalDateTime, TextStyle, ZoneId, ZoneOffset, ZonedDateTime};
+
+    fn zone(id: &str) -> ZoneId {
+        ZoneId::try_of(id).unwrap()
+    }
+
+    fn winter() -> Instant {
+        LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant_utc()
+    }
+
+    fn summer() -> Instant {
+        LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc()
+    }
+
+    #[test]
+    fn abbreviations_follow_daylight_saving_time() {
+        assert_eq!(zone("America/New_York").abbreviation_at(winter()), "EST");
+        assert_eq!(zone("America/New_York").abbreviation_at(summer()), "EDT");
+        assert_eq!(zone("Europe/Paris").abbreviation_at(summer()), "CEST");
+        assert_eq!(zone("Europe/London").abbreviation_at(summer()), "BST");
+        assert_eq!(zone("Asia/Kolkata").abbreviation_at(summer()), "IST");
+        assert_eq!(zone("Europe/Istanbul").abbreviation_at(summer()), "+03");
+        assert_eq!(ZoneId::UTC.abbreviation_at(summer()), "UTC");
+        assert_eq!(zone("UTC+05:30").abbreviation_at(summer()), "UTC+05:30");
+    }
+
+    #[test]
+    fn generic_names() {
+        assert_eq!(zone("America/New_York").display_name(TextStyle::Full), "Eastern Time");
+        assert_eq!(zone("America/New_York").display_name(TextStyle::Short), "ET");
+        assert_eq!(zone("US/Pacific").display_name(TextStyle::FullStandalone), "Pacific Time");
+        assert_eq!(zone("Europe/Paris").display_name(TextStyle::Full), "Central European Time");
+        assert_eq!(zone("Europe/Paris").generic_display_name_at(summer(), TextStyle::Short), "CET");
+        assert_eq!(zone("Asia/Tokyo").display_name(TextStyle::Full), "Japan Time");
+        assert_eq!(zone("Asia/Ulaanbaatar").display_name(TextStyle::Full), "Ulaanbaatar Time");
+        assert_eq!(zone("Europe/Istanbul").generic_display_name_at(summer(), TextStyle::Short), "GMT+3");
+        assert_eq!(zone("Etc/GMT-14").generic_display_name_at(summer(), TextStyle::Full), "GMT+14:00");
+    }
+
+    #[test]
+    fn generic_names_at_an_instant() {
+        let istanbul = zone("Europe/Istanbul");
+        let summer_2015 = LocalDateTime::of(2015, 7, 15, 12, 0, 0).to_instant_utc();
+        assert_eq!(istanbul.generic_display_name_at(summer_2015, TextStyle::Short), "EET");
+        assert_eq!(istanbul.generic_display_name_at(summer_2015, TextStyle::Full), "Turkey Time");
+
+        let new_york = zone("America/New_York");
+        assert_eq!(new_york.generic_display_name_at(summer(), TextStyle::Full), "Eastern Time");
+        assert_eq!(new_york.generic_display_name_at(winter(), TextStyle::Short), "ET");
+    }
+
+    #[test]
+    fn names_at_an_instant() {
+        let new_york = zone("America/New_York");
+        assert_eq!(new_york.display_name_at(winter(), TextStyle::Full), "Eastern Standard Time");
+        assert_eq!(new_york.display_name_at(summer(), TextStyle::Full), "Eastern Daylight Time");
+        assert_eq!(new_york.display_name_at(summer(), TextStyle::Short), "EDT");
+
+        let berlin = zone("Europe/Berlin");
+        assert_eq!(berlin.display_name_at(winter(), TextStyle::Full), "Central European Standard Time");
+        assert_eq!(berlin.display_name_at(summer(), TextStyle::Full), "Central European Summer Time");
+
+        let london = zone("Europe/London");
+        assert_eq!(london.display_name_at(winter(), TextStyle::Full), "Greenwich Mean Time");
+        assert_eq!(london.display_name_at(summer(), TextStyle::Full), "British Summer Time");
+
+        assert_eq!(zone("Asia/Kathmandu").display_name_at(summer(), TextStyle::Full), "Nepal Time");
+        assert_eq!(zone("Asia/Ulaanbaatar").display_name_at(summer(), TextStyle::Full), "GMT+08:00");
+    }
+
+    #[test]
+    fn offset_based_ids() {
+        assert_eq!(ZoneId::UTC.display_name(TextStyle::Full), "Coordinated Universal Time");
+        assert_eq!(ZoneId::UTC.display_name(TextStyle::Short), "UTC");
+        assert_eq!(zone("GMT").display_name(TextStyle::Full), "Greenwich Mean Time");
+
+        let india = ZoneId::from(ZoneOffset::of_hours_minutes(5, 30));
+        assert_eq!(india.display_name(TextStyle::Full), "GMT+05:30");
+        assert_eq!(india.display_name_at(summer(), TextStyle::Short), "GMT+5:30");
+        assert_eq!(zone("UTC-3").display_name(TextStyle::Short), "GMT-3");
+    }
+
+    #[test]
+    fn zoned_date_time_labels() {
+        let meeting = ZonedDateTime::of(LocalDateTime::of(2024, 3, 28, 9, 0, 0), zone("America/Chicago"));
+
+        assert_eq!(meeting.zone_abbreviation(), "CDT");
+        assert_eq!(meeting.zone_display_name(TextStyle::Full), "Central Daylight Time");
+        assert_eq!(meeting.plus_months(8).zone_display_name(TextStyle::Short), "CST");
+    }
+}
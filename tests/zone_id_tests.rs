@@ -1,5 +1,5 @@
 mod tests {
-    use joda_rs::ZoneId;
+    use joda_rs::{Instant, LocalDateTime, ZoneId, ZoneOffset, ZoneOffsetTransition};
     use time_tz::TimeZone;
 
     #[test]
@@ -12,8 +12,8 @@ mod tests {
         assert_eq!(c.unwrap().id(), "America/New_York");
         assert_eq!(d.unwrap_err(), "Unknown time zone");
 
-        let a2 = a;
-        let a3 = a2;
+        let a2 = a.clone();
+        let a3 = a2.clone();
         assert_eq!(a, a2);
         assert_eq!(a2, a3);
     }
@@ -44,4 +44,214 @@ mod tests {
         // And it should be present in the time_tz database
         assert!(time_tz::timezones::get_by_name(expected_name).is_some());
     }
+
+    #[test]
+    fn offset_at_instant_depends_on_the_instant_not_on_now() {
+        let new_york = ZoneId::try_of("America/New_York").unwrap();
+        let january = LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant_utc();
+        let july = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+
+        assert_eq!(new_york.offset_at_instant(january), ZoneOffset::of_hours(-5));
+        assert_eq!(new_york.offset_at_instant(july), ZoneOffset::of_hours(-4));
+        assert_eq!(ZoneId::UTC.offset_at_instant(july), ZoneOffset::UTC);
+    }
+
+    #[test]
+    fn offset_at_instant_switches_exactly_at_transition() {
+        let new_york = ZoneId::try_of("America/New_York").unwrap();
+        // 2024-03-10T07:00:00Z is 02:00 EST, the moment clocks jump to 03:00 EDT.
+        let transition = LocalDateTime::of(2024, 3, 10, 7, 0, 0).to_instant_utc();
+
+        assert_eq!(new_york.offset_at_instant(transition.minus_seconds(1)), ZoneOffset::of_hours(-5));
+        assert_eq!(new_york.offset_at_instant(transition), ZoneOffset::of_hours(-4));
+    }
+
+    #[test]
+    fn valid_offsets_at_reports_normal_gap_and_overlap() {
+        let new_york = ZoneId::try_of("America/New_York").unwrap();
+
+        assert_eq!(
+            new_york.valid_offsets_at(LocalDateTime::of(2024, 1, 15, 12, 0, 0)),
+            vec![ZoneOffset::of_hours(-5)]
+        );
+        assert!(new_york.valid_offsets_at(LocalDateTime::of(2024, 3, 10, 2, 30, 0)).is_empty());
+        assert_eq!(
+            new_york.valid_offsets_at(LocalDateTime::of(2024, 11, 3, 1, 30, 0)),
+            vec![ZoneOffset::of_hours(-4), ZoneOffset::of_hours(-5)]
+        );
+    }
+
+    #[test]
+    fn local_date_time_to_instant_uses_offset_for_that_date() {
+        let new_york = ZoneId::try_of("America/New_York").unwrap();
+
+        assert_eq!(
+            LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant(new_york.clone()),
+            LocalDateTime::of(2024, 1, 15, 17, 0, 0).to_instant_utc()
+        );
+        assert_eq!(
+            LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant(new_york),
+            LocalDateTime::of(2024, 7, 15, 16, 0, 0).to_instant_utc()
+        );
+    }
+
+    #[test]
+    fn instant_at_zone_round_trips_through_local_date_time() {
+        let paris = ZoneId::try_of("Europe/Paris").unwrap();
+        let instant = Instant::of_epoch_second(1_720_000_000);
+        let ldt = instant.at_zone(paris.clone()).to_local_date_time();
+
+        assert_eq!(ldt, LocalDateTime::of(2024, 7, 3, 11, 46, 40));
+        assert_eq!(ldt.to_instant(paris), instant);
+    }
+
+    #[test]
+    fn try_of_accepts_runtime_strings() {
+        let from_config = String::from("Europe/Paris");
+        let paris = ZoneId::try_of(&from_config).unwrap();
+        drop(from_config);
+
+        assert_eq!(paris.id(), "Europe/Paris");
+        assert_eq!("Europe/Paris".parse::<ZoneId>().unwrap(), paris);
+    }
+
+    #[test]
+    fn offset_and_prefixed_ids() {
+        let cases = [
+            ("Z", "Z", 0),
+            ("+05:30", "+05:30", 19_800),
+            ("-3", "-03:00", -10_800),
+            ("UTC+05:30", "UTC+05:30", 19_800),
+            ("GMT-3", "GMT-03:00", -10_800),
+            ("UT+0100", "UT+01:00", 3_600),
+            ("UTC+0", "UTC", 0),
+            ("GMT", "GMT", 0),
+            ("UT", "UT", 0),
+        ];
+        let instant = Instant::of_epoch_second(1_720_000_000);
+        for (text, id, seconds) in cases {
+            let zone = ZoneId::try_of(text).unwrap();
+            assert_eq!(zone.id(), id, "{text}");
+            assert_eq!(zone.offset_at_instant(instant).total_seconds(), seconds, "{text}");
+            assert!(zone.rules().is_fixed_offset(), "{text}");
+        }
+        assert_eq!(ZoneId::try_of("UTC"), Ok(ZoneId::UTC));
+    }
+
+    #[test]
+    fn region_ids_including_etc_zones() {
+        let etc = ZoneId::try_of("Etc/GMT+5").unwrap();
+        assert_eq!(etc.id(), "Etc/GMT+5");
+        assert_eq!(etc.offset_at_instant(Instant::of_epoch_second(0)), ZoneOffset::of_hours(-5));
+
+        assert_eq!(ZoneId::try_of("UTC+25:00"), Err("Invalid ID for ZoneOffset"));
+        assert_eq!(ZoneId::try_of("GMT+"), Err("Invalid ID for ZoneOffset"));
+        assert_eq!(ZoneId::try_of("Europe/Paris!"), Err("Invalid ID for region-based ZoneId"));
+        assert_eq!(ZoneId::try_of("A"), Err("Invalid ID for region-based ZoneId"));
+        assert_eq!(ZoneId::try_of("Mars/Olympus_Mons"), Err("Unknown time zone"));
+    }
+
+    #[test]
+    fn of_offset_from_and_normalized() {
+        let offset = ZoneOffset::of_hours_minutes(5, 30);
+        assert_eq!(ZoneId::of_offset("UTC", offset).unwrap().id(), "UTC+05:30");
+        assert_eq!(ZoneId::of_offset("", offset), Ok(ZoneId::from(offset)));
+        assert_eq!(ZoneId::from(offset).id(), "+05:30");
+
+        assert_eq!(ZoneId::of_offset("UTC", offset).unwrap().normalized(), ZoneId::from(offset));
+        assert_eq!(ZoneId::UTC.normalized(), ZoneId::from(ZoneOffset::UTC));
+        assert_eq!(ZoneId::try_of("Etc/GMT-14").unwrap().normalized().id(), "+14:00");
+        let paris = ZoneId::try_of("Europe/Paris").unwrap();
+        assert_eq!(paris.normalized(), paris);
+    }
+
+    #[test]
+    fn of_offset_rejects_unknown_prefix() {
+        assert_eq!(ZoneId::of_offset("CET", ZoneOffset::of_hours(1)), Err("Invalid prefix for ZoneId"));
+        assert_eq!(ZoneId::of_offset("utc", ZoneOffset::of_hours(1)), Err("Invalid prefix for ZoneId"));
+    }
+
+    #[test]
+    fn zoned_date_time_in_fixed_zone() {
+        let zone = ZoneId::try_of("UTC+05:30").unwrap();
+        let zdt = LocalDateTime::of(2024, 3, 10, 2, 30, 0).at_zone(zone.clone());
+        assert_eq!(zdt.zone(), zone);
+        assert_eq!(zdt.offset(), ZoneOffset::of_hours_minutes(5, 30));
+        assert_eq!(zdt.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 2, 30, 0));
+    }
+
+    #[test]
+    fn available_and_canonical_zone_ids() {
+        let available = ZoneId::available_zone_ids();
+        let canonical = ZoneId::canonical_zone_ids();
+
+        assert!(available.windows(2).all(|w| w[0] < w[1]));
+        assert!(available.iter().any(|id| id == "US/Eastern"));
+        assert!(canonical.iter().any(|id| id == "America/New_York"));
+        assert!(!canonical.iter().any(|id| id == "US/Eastern"));
+        assert!(canonical.len() < available.len());
+        for id in &available {
+            assert!(ZoneId::try_of(id).is_ok(), "{id}");
+        }
+    }
+
+    #[test]
+    fn aliases_agree_with_the_compiled_tz_data() {
+        // Zones with the same history are a zone and its links, so they share a canonical ID.
+        let history = |zone: &ZoneId| {
+            let rules = zone.rules();
+            let start = Instant::of_epoch_second(-10_000_000_000);
+            let mut transitions: Vec<ZoneOffsetTransition> = Vec::new();
+            while let Some(transition) = rules.next_transition(transitions.last().map_or(start, |t| t.instant())) {
+                transitions.push(transition);
+            }
+            let instants = std::iter::once(start).chain(transitions.iter().map(|t| t.instant()));
+            let abbreviations: Vec<String> = instants.map(|instant| rules.abbreviation(instant)).collect();
+            (rules.offset(start), transitions, abbreviations)
+        };
+        for id in ZoneId::available_zone_ids() {
+            let zone = ZoneId::try_of(&id).unwrap();
+            let canonical = zone.canonical();
+            assert_eq!(history(&zone), history(&canonical), "{id}");
+        }
+    }
+
+    #[test]
+    fn aliases_resolve_to_canonical_zones() {
+        let eastern = ZoneId::try_of("US/Eastern").unwrap();
+        assert!(eastern.is_alias());
+        assert!(eastern.is_deprecated());
+        assert_eq!(eastern.canonical(), ZoneId::try_of("America/New_York").unwrap());
+
+        let jersey = ZoneId::try_of("Europe/Jersey").unwrap();
+        assert!(jersey.is_alias());
+        assert!(!jersey.is_deprecated());
+        assert_eq!(jersey.canonical().id(), "Europe/London");
+
+        let paris = ZoneId::try_of("Europe/Paris").unwrap();
+        assert!(!paris.is_alias());
+        assert_eq!(paris.canonical(), paris);
+        assert_eq!(ZoneId::UTC.canonical(), ZoneId::UTC);
+    }
+
+    #[test]
+    fn parse_error_suggests_close_ids() {
+        assert_eq!("US/Estern".parse::<ZoneId>(), Err("Unknown time zone"));
+        let err = ZoneId::try_parse("US/Estern").unwrap_err();
+        assert_eq!(err.input(), "US/Estern");
+        assert_eq!(err.reason(), "Unknown time zone");
+        assert_eq!(err.suggestions(), ["US/Eastern"]);
+        assert_eq!(err.to_string(), "Unknown time zone \"US/Estern\"; did you mean \"US/Eastern\"?");
+
+        assert_eq!(ZoneId::suggestions("europe/paris"), vec!["Europe/Paris"]);
+        assert_eq!(ZoneId::suggestions("New York"), vec!["America/New_York"]);
+
+        let err = ZoneId::try_parse("Nowhere/Atall").unwrap_err();
+        assert!(err.suggestions().is_empty());
+        assert_eq!(err.to_string(), "Unknown time zone \"Nowhere/Atall\"");
+
+        let err = ZoneId::try_parse("UTC+25").unwrap_err();
+        assert_eq!(err.reason(), "Invalid ID for ZoneOffset");
+        assert!(err.suggestions().is_empty());
+    }
 }
@@ -18,4 +18,32 @@ mod tests {
         let z_neg = ZoneOffset::of_hours(-7);
         assert_eq!(z_neg.total_seconds(), -7 * 3600);
     }
+
+    #[test]
+    fn try_of_accepts_java_offset_formats() {
+        let expected = [
+            ("Z", 0),
+            ("+5", 5 * 3600),
+            ("-05", -5 * 3600),
+            ("+0530", 5 * 3600 + 30 * 60),
+            ("+05:30", 5 * 3600 + 30 * 60),
+            ("-05:30:15", -(5 * 3600 + 30 * 60 + 15)),
+            ("+053015", 5 * 3600 + 30 * 60 + 15),
+            ("+18:00", 18 * 3600),
+        ];
+        for (text, seconds) in expected {
+            assert_eq!(ZoneOffset::try_of(text).unwrap().total_seconds(), seconds, "{text}");
+        }
+        for text in ["", "5", "+5:30", "+18:01", "+05:60", "+05-30", "UTC"] {
+            assert_eq!(ZoneOffset::try_of(text), Err("Invalid ID for ZoneOffset"), "{text}");
+        }
+    }
+
+    #[test]
+    fn id_is_normalized() {
+        assert_eq!(ZoneOffset::UTC.id(), "Z");
+        assert_eq!(ZoneOffset::of_hours(5).id(), "+05:00");
+        assert_eq!(ZoneOffset::of_total_seconds(-(3 * 3600 + 30 * 60 + 5)).id(), "-03:30:05");
+        assert_eq!("+0530".parse::<ZoneOffset>().unwrap().id(), "+05:30");
+    }
 }
@@ -0,0 +1,97 @@
+mod tests {
+    use joda_rs::{LocalDateTime, ZoneId, ZoneOffset, ZoneResolver, ZonedDateTime};
+
+    fn new_york() -> ZoneId {
+        ZoneId::try_of("America/New_York").unwrap()
+    }
+
+    fn in_gap() -> LocalDateTime {
+        LocalDateTime::of(2024, 3, 10, 2, 30, 0)
+    }
+
+    fn in_overlap() -> LocalDateTime {
+        LocalDateTime::of(2024, 11, 3, 1, 30, 0)
+    }
+
+    #[test]
+    fn default_resolver_matches_of() {
+        assert_eq!(ZoneResolver::default(), ZoneResolver::ShiftForward);
+        for ldt in [in_gap(), in_overlap(), LocalDateTime::of(2024, 6, 1, 8, 0, 0)] {
+            assert_eq!(ZoneResolver::default().resolve(ldt, new_york()).unwrap(), ZonedDateTime::of(ldt, new_york()));
+        }
+    }
+
+    #[test]
+    fn gap_resolution_per_strategy() {
+        let forward = ZoneResolver::ShiftForward.resolve(in_gap(), new_york()).unwrap();
+        assert_eq!(forward.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 3, 30, 0));
+        assert_eq!(forward.offset(), ZoneOffset::of_hours(-4));
+
+        let later = ZoneResolver::LaterOffset.resolve(in_gap(), new_york()).unwrap();
+        assert_eq!(later, forward);
+
+        // Like java.time, the preferred offset only matters in an overlap.
+        let earlier = ZoneResolver::EarlierOffset.resolve(in_gap(), new_york()).unwrap();
+        assert_eq!(earlier, forward);
+
+        assert_eq!(
+            ZoneResolver::Strict.resolve(in_gap(), new_york()).unwrap_err(),
+            "Local date-time falls in a gap in the time zone"
+        );
+    }
+
+    #[test]
+    fn overlap_resolution_per_strategy() {
+        let forward = ZoneResolver::ShiftForward.resolve(in_overlap(), new_york()).unwrap();
+        let earlier = ZoneResolver::EarlierOffset.resolve(in_overlap(), new_york()).unwrap();
+        let later = ZoneResolver::LaterOffset.resolve(in_overlap(), new_york()).unwrap();
+
+        for zdt in [forward.clone(), earlier.clone(), later.clone()] {
+            assert_eq!(zdt.to_local_date_time(), in_overlap());
+        }
+        assert_eq!(forward.offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(earlier.offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(later.offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(later.epoch_seconds() - earlier.epoch_seconds(), 3600);
+
+        assert_eq!(
+            ZoneResolver::Strict.resolve(in_overlap(), new_york()).unwrap_err(),
+            "Local date-time is ambiguous in the time zone"
+        );
+    }
+
+    #[test]
+    fn strict_accepts_unambiguous_local_date_times() {
+        let ldt = LocalDateTime::of(2024, 6, 1, 8, 0, 0);
+        let zdt = ldt.at_zone_with_resolver(new_york(), ZoneResolver::Strict).unwrap();
+        assert_eq!(zdt, ldt.at_zone(new_york()));
+    }
+
+    #[test]
+    fn of_with_resolver_and_at_zone_with_resolver_agree() {
+        for resolver in [ZoneResolver::ShiftForward, ZoneResolver::EarlierOffset, ZoneResolver::LaterOffset] {
+            assert_eq!(
+                ZonedDateTime::of_with_resolver(in_gap(), new_york(), resolver),
+                in_gap().at_zone_with_resolver(new_york(), resolver)
+            );
+        }
+    }
+
+    #[test]
+    fn with_earlier_and_later_offset_at_overlap() {
+        let earlier = ZonedDateTime::of(in_overlap(), new_york());
+        let later = earlier.with_later_offset_at_overlap();
+
+        assert_eq!(later.offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(later.to_local_date_time(), in_overlap());
+        assert_eq!(later.with_earlier_offset_at_overlap(), earlier);
+        assert_eq!(earlier.with_earlier_offset_at_overlap(), earlier);
+    }
+
+    #[test]
+    fn overlap_adjusters_do_nothing_outside_overlap() {
+        let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 6, 1, 8, 0, 0), new_york());
+        assert_eq!(zdt.with_earlier_offset_at_overlap(), zdt);
+        assert_eq!(zdt.with_later_offset_at_overlap(), zdt);
+    }
+}
@@ -0,0 +1,215 @@
+mod tests {
+    use joda_rs::{
+        Instant, LocalDateTime, TzifZoneRulesProvider, Year, ZoneId, ZoneOffset, ZoneResolver, ZoneRules, ZoneRulesProvider,
+        ZonedDateTime,
+    };
+
+    const TYPES: [(i32, bool, &str); 2] = [(3600, false, "TST"), (7200, true, "TDT")];
+
+    /// Builds a TZif file with the given version, transitions `(epoch second, type index)`,
+    /// the two types above and a footer.
+    fn tzif(version: u8, transitions: &[(i64, u8)], footer: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let time_size = if version == 0 { 4 } else { 8 };
+        let block = |bytes: &mut Vec<u8>, time_size: usize| {
+            bytes.extend_from_slice(b"TZif");
+            bytes.push(version);
+            bytes.extend_from_slice(&[0; 15]);
+            for count in [0, 0, 0, transitions.len(), TYPES.len(), 8] {
+                bytes.extend_from_slice(&(count as u32).to_be_bytes());
+            }
+            for (at, _) in transitions {
+                if time_size == 4 {
+                    bytes.extend_from_slice(&(*at as i32).to_be_bytes());
+                } else {
+                    bytes.extend_from_slice(&at.to_be_bytes());
+                }
+            }
+            bytes.extend(transitions.iter().map(|(_, index)| index));
+            for (index, (offset, is_dst, _)) in TYPES.iter().enumerate() {
+                bytes.extend_from_slice(&offset.to_be_bytes());
+                bytes.push(u8::from(*is_dst));
+                bytes.push(index as u8 * 4);
+            }
+            bytes.extend_from_slice(b"TST\0TDT\0");
+        };
+        block(&mut bytes, 4);
+        if version != 0 {
+            block(&mut bytes, time_size);
+            bytes.extend_from_slice(format!("\n{footer}\n").as_bytes());
+        }
+        bytes
+    }
+
+    fn instant(year: i32, month: i32, day: i32, hour: i32) -> Instant {
+        LocalDateTime::of(year, month, day, hour, 0, 0).to_instant_utc()
+    }
+
+    /// EU-style rules: explicit transitions for 2020, the footer afterwards.
+    fn test_zone() -> Vec<u8> {
+        let transitions = [(instant(2020, 3, 29, 1).epoch_seconds(), 1), (instant(2020, 10, 25, 1).epoch_seconds(), 0)];
+        tzif(b'2', &transitions, "TST-1TDT,M3.5.0,M10.5.0/3")
+    }
+
+    #[test]
+    fn explicit_transitions_and_footer_rule() {
+        let rules = ZoneRules::from_tzif(&test_zone()).unwrap();
+
+        assert_eq!(rules.offset(instant(2019, 7, 1, 0)), ZoneOffset::of_hours(1));
+        assert_eq!(rules.offset(instant(2020, 7, 1, 0)), ZoneOffset::of_hours(2));
+        assert_eq!(rules.offset(instant(2024, 1, 1, 0)), ZoneOffset::of_hours(1));
+        assert_eq!(rules.offset(instant(2024, 7, 1, 0)), ZoneOffset::of_hours(2));
+        assert!(rules.is_daylight_savings(instant(2024, 7, 1, 0)));
+        assert_eq!(rules.standard_offset(instant(2024, 7, 1, 0)), ZoneOffset::of_hours(1));
+
+        let transitions = rules.transitions_in(Year::of(2024));
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].instant(), instant(2024, 3, 31, 1));
+        assert!(transitions[0].is_gap());
+        assert_eq!(transitions[1].instant(), instant(2024, 10, 27, 1));
+        assert!(transitions[1].is_overlap());
+
+        let previous = rules.previous_transition(instant(2021, 1, 1, 0)).unwrap();
+        assert_eq!(previous.instant(), instant(2020, 10, 25, 1));
+        assert_eq!(rules.next_transition(previous.instant()).unwrap().instant(), instant(2021, 3, 28, 1));
+        assert_eq!(rules.previous_transition(instant(2020, 1, 1, 0)), None);
+    }
+
+    #[test]
+    fn version_one_files_use_32_bit_data() {
+        let transitions = [(instant(2020, 3, 29, 1).epoch_seconds(), 1)];
+        let rules = ZoneRules::from_tzif(&tzif(0, &transitions, "")).unwrap();
+
+        assert_eq!(rules.offset(instant(2020, 1, 1, 0)), ZoneOffset::of_hours(1));
+        assert_eq!(rules.offset(instant(2030, 1, 1, 0)), ZoneOffset::of_hours(2));
+        assert_eq!(rules.next_transition(instant(2021, 1, 1, 0)), None);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        assert_eq!(ZoneRules::from_tzif(b"not a tzif file"), Err("Invalid TZif data"));
+        assert_eq!(ZoneRules::from_tzif(&test_zone()[..60]), Err("Invalid TZif data"));
+
+        let mut unknown_version = test_zone();
+        unknown_version[4] = b'9';
+        assert_eq!(ZoneRules::from_tzif(&unknown_version), Err("Unsupported TZif version"));
+    }
+
+    #[test]
+    fn rejects_offsets_out_of_range() {
+        assert_eq!(ZoneRules::from_tzif(&tzif(b'2', &[], "ABC9999999999999999")), Err("Invalid TZif data"));
+        assert_eq!(ZoneRules::from_tzif(&tzif(b'2', &[], "TST-26")), Err("Invalid TZif data"));
+        assert_eq!(ZoneRules::from_tzif(&tzif(b'2', &[], "TST-1TDT-26,M3.5.0,M10.5.0/3")), Err("Invalid TZif data"));
+        assert_eq!(ZoneRules::from_tzif(&tzif(b'2', &[], "TST-1TDT,M3.5.0,M10.5.0/999999")), Err("Invalid TZif data"));
+
+        // The offset of the first type, 3600 seconds, becomes 26 hours in both data blocks.
+        let mut bytes = tzif(b'2', &[], "TST-1");
+        let standard = 3600i32.to_be_bytes();
+        for start in (0..bytes.len() - 4).filter(|&start| bytes[start..start + 4] == standard).collect::<Vec<_>>() {
+            bytes[start..start + 4].copy_from_slice(&(26 * 3600i32).to_be_bytes());
+        }
+        assert_eq!(ZoneRules::from_tzif(&bytes), Err("Invalid TZif data"));
+    }
+
+    #[test]
+    fn far_instants_do_not_overflow() {
+        let rules = ZoneRules::from_tzif(&test_zone()).unwrap();
+        for instant in [instant(-9999, 1, 1, 0), instant(9999, 12, 31, 23)] {
+            assert_eq!(rules.offset(instant), ZoneOffset::of_hours(1));
+        }
+        assert_eq!(rules.next_transition(instant(9999, 1, 1, 0)).unwrap().instant(), instant(9999, 3, 28, 1));
+        assert_eq!(rules.previous_transition(instant(-9999, 1, 1, 0)), None);
+        // The footer gives no transition the range can hold after October 9999.
+        assert_eq!(rules.next_transition(instant(9999, 11, 1, 0)), None);
+        assert_eq!(rules.valid_offsets(LocalDateTime::of(9999, 12, 31, 23, 59, 59)), [ZoneOffset::of_hours(1)]);
+        assert_eq!(rules.valid_offsets(LocalDateTime::of(-9999, 1, 1, 0, 0, 0)), [ZoneOffset::of_hours(1)]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_entries_are_skipped() {
+        let root = std::env::temp_dir().join(format!("joda_rs_zoneinfo_dangling_{}", std::process::id()));
+        let region = root.join("Test");
+        std::fs::create_dir_all(&region).unwrap();
+        std::fs::write(region.join("Zone"), test_zone()).unwrap();
+        std::os::unix::fs::symlink(region.join("Missing"), region.join("Dangling")).unwrap();
+        std::os::unix::fs::symlink(root.join("Missing"), root.join("DanglingDirectory")).unwrap();
+
+        let provider = TzifZoneRulesProvider::from_directory(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(provider.unwrap().zone_ids(), ["Test/Zone"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn directory_symlink_loops_are_skipped() {
+        let root = std::env::temp_dir().join(format!("joda_rs_zoneinfo_{}", std::process::id()));
+        let region = root.join("Test");
+        std::fs::create_dir_all(&region).unwrap();
+        std::fs::write(region.join("Zone"), test_zone()).unwrap();
+        std::os::unix::fs::symlink(&root, region.join("Loop")).unwrap();
+
+        let provider = TzifZoneRulesProvider::from_directory(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(provider.unwrap().zone_ids(), ["Test/Zone"]);
+    }
+
+    #[test]
+    fn registered_provider_is_used_for_parsing_and_conversions() {
+        let provider = TzifZoneRulesProvider::new().with_zone("Test/Zone", &test_zone()).unwrap().with_version("2099z");
+        ZoneId::register_provider(provider);
+
+        let zone: ZoneId = "Test/Zone".parse().unwrap();
+        assert_eq!(zone.id(), "Test/Zone");
+        assert!(ZoneId::available_zone_ids().iter().any(|id| id == "Test/Zone"));
+        assert_eq!(ZoneId::tzdb_version().as_deref(), Some("2099z"));
+
+        let in_gap = ZonedDateTime::of(LocalDateTime::of(2024, 3, 31, 2, 30, 0), zone.clone());
+        assert_eq!(in_gap.to_local_date_time(), LocalDateTime::of(2024, 3, 31, 3, 30, 0));
+        assert_eq!(in_gap.offset(), ZoneOffset::of_hours(2));
+        assert_eq!(
+            zone.valid_offsets_at(LocalDateTime::of(2024, 10, 27, 2, 30, 0)),
+            vec![ZoneOffset::of_hours(2), ZoneOffset::of_hours(1)]
+        );
+        assert_eq!(
+            LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant(zone),
+            LocalDateTime::of(2024, 1, 15, 11, 0, 0).to_instant_utc()
+        );
+    }
+
+    #[test]
+    fn system_zoneinfo_matches_built_in_data() {
+        let Ok(bytes) = std::fs::read("/usr/share/zoneinfo/America/New_York") else {
+            eprintln!("Skipping system zoneinfo test: /usr/share/zoneinfo is not available");
+            return;
+        };
+        let system = ZoneRules::from_tzif(&bytes).unwrap();
+        let built_in = ZoneId::try_of("America/New_York").unwrap().rules();
+
+        for year in [1970, 2000, 2024, 2040] {
+            assert_eq!(system.transitions_in(Year::of(year)), built_in.transitions_in(Year::of(year)), "{year}");
+        }
+
+        let provider = TzifZoneRulesProvider::from_directory("/usr/share/zoneinfo").unwrap();
+        assert!(provider.rules("America/New_York").is_some());
+        assert!(provider.zone_ids().iter().any(|id| id == "Europe/Paris"));
+    }
+
+    #[test]
+    fn resolving_a_gap_at_the_end_of_the_range_is_an_error() {
+        // Clocks go forward from 23:00 to midnight on the last day of every year.
+        let tzif = tzif(b'2', &[], "TST-1TDT,J365/23,J1/1");
+        let provider = TzifZoneRulesProvider::new().with_zone("Test/YearEnd", &tzif).unwrap();
+        ZoneId::register_provider(provider);
+        let zone: ZoneId = "Test/YearEnd".parse().unwrap();
+
+        let in_gap = LocalDateTime::of(2024, 12, 31, 23, 30, 0);
+        let shifted = ZoneResolver::EarlierOffset.resolve(in_gap, zone.clone()).unwrap();
+        assert_eq!(shifted.to_local_date_time(), LocalDateTime::of(2025, 1, 1, 0, 30, 0));
+
+        let last_gap = LocalDateTime::of(9999, 12, 31, 23, 30, 0);
+        for resolver in [ZoneResolver::ShiftForward, ZoneResolver::EarlierOffset, ZoneResolver::LaterOffset] {
+            assert_eq!(resolver.resolve(last_gap, zone.clone()), Err("Local date-time exceeds the supported range"));
+        }
+    }
+}
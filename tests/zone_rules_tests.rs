@@ -0,0 +1,145 @@
+mod tests {
+    use joda_rs::{Duration, LocalDateTime, Year, ZoneId, ZoneOffset, ZoneOffsetTransition};
+
+    fn new_york() -> ZoneId {
+        ZoneId::try_of("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn offsets_and_daylight_savings() {
+        let rules = new_york().rules();
+        let january = LocalDateTime::of(2024, 1, 15, 12, 0, 0).to_instant_utc();
+        let july = LocalDateTime::of(2024, 7, 15, 12, 0, 0).to_instant_utc();
+
+        assert_eq!(rules.offset(january), ZoneOffset::of_hours(-5));
+        assert_eq!(rules.offset(july), ZoneOffset::of_hours(-4));
+        assert_eq!(rules.standard_offset(january), ZoneOffset::of_hours(-5));
+        assert_eq!(rules.standard_offset(july), ZoneOffset::of_hours(-5));
+        assert!(!rules.is_daylight_savings(january));
+        assert!(rules.is_daylight_savings(july));
+        assert_eq!(rules.daylight_savings(january), Duration::of_seconds(0));
+        assert_eq!(rules.daylight_savings(july), Duration::of_hours(1));
+    }
+
+    #[test]
+    fn transitions_in_year() {
+        let transitions = new_york().rules().transitions_in(Year::of(2024));
+        assert_eq!(transitions.len(), 2);
+
+        let spring = transitions[0];
+        assert_eq!(spring.instant(), LocalDateTime::of(2024, 3, 10, 7, 0, 0).to_instant_utc());
+        assert_eq!(spring.offset_before(), ZoneOffset::of_hours(-5));
+        assert_eq!(spring.offset_after(), ZoneOffset::of_hours(-4));
+        assert!(spring.is_gap());
+        assert_eq!(spring.duration(), Duration::of_hours(1));
+        assert_eq!(spring.date_time_before(), LocalDateTime::of(2024, 3, 10, 2, 0, 0));
+        assert_eq!(spring.date_time_after(), LocalDateTime::of(2024, 3, 10, 3, 0, 0));
+
+        let autumn = transitions[1];
+        assert_eq!(autumn.instant(), LocalDateTime::of(2024, 11, 3, 6, 0, 0).to_instant_utc());
+        assert!(autumn.is_overlap());
+        assert_eq!(autumn.duration(), Duration::of_hours(-1));
+        assert!(autumn.is_valid_offset(ZoneOffset::of_hours(-5)));
+        assert!(!spring.is_valid_offset(ZoneOffset::of_hours(-5)));
+    }
+
+    #[test]
+    fn next_and_previous_transition_are_strict() {
+        let rules = new_york().rules();
+        let spring = rules.transitions_in(Year::of(2024))[0];
+
+        assert_eq!(rules.next_transition(spring.instant().minus_seconds(1)), Some(spring));
+        assert_ne!(rules.next_transition(spring.instant()), Some(spring));
+        assert_eq!(rules.previous_transition(spring.instant().plus_nanoseconds(1)), Some(spring));
+        assert_ne!(rules.previous_transition(spring.instant()), Some(spring));
+    }
+
+    #[test]
+    fn transition_for_local_date_time() {
+        let rules = new_york().rules();
+        let gap = rules.transition(LocalDateTime::of(2024, 3, 10, 2, 30, 0)).unwrap();
+        let overlap = rules.transition(LocalDateTime::of(2024, 11, 3, 1, 30, 0)).unwrap();
+
+        assert!(gap.is_gap());
+        assert!(overlap.is_overlap());
+        assert_eq!(rules.transition(LocalDateTime::of(2024, 3, 10, 3, 0, 0)), None);
+        assert_eq!(rules.transition(LocalDateTime::of(2024, 7, 1, 12, 0, 0)), None);
+    }
+
+    #[test]
+    fn southern_hemisphere_and_half_hour_transitions() {
+        let lord_howe = ZoneId::try_of("Australia/Lord_Howe").unwrap().rules();
+        let transitions = lord_howe.transitions_in(Year::of(2024));
+
+        assert_eq!(transitions.len(), 2);
+        assert!(transitions[0].is_overlap());
+        assert_eq!(transitions[0].duration(), Duration::of_minutes(-30));
+        assert!(transitions[1].is_gap());
+    }
+
+    #[test]
+    fn zone_without_transitions() {
+        let rules = ZoneId::UTC.rules();
+        let now = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+
+        assert!(rules.transitions_in(Year::of(2024)).is_empty());
+        assert_eq!(rules.next_transition(now), None);
+        assert_eq!(rules.standard_offset(now), ZoneOffset::UTC);
+    }
+
+    #[test]
+    fn transitions_within_the_same_day() {
+        // Lisbon moved from +01:00 to +02:00 and back an hour later on 1992-09-27.
+        let rules = ZoneId::try_of("Europe/Lisbon").unwrap().rules();
+        let midnight = LocalDateTime::of(1992, 9, 27, 0, 0, 0).to_instant_utc();
+        let transitions = rules.transitions_in(Year::of(1992));
+
+        assert!(transitions.contains(&ZoneOffsetTransition::of(midnight, ZoneOffset::of_hours(1), ZoneOffset::of_hours(2))));
+        let next = rules.next_transition(midnight).unwrap();
+        assert_eq!(next.instant(), midnight.plus_seconds(3600));
+        assert_eq!(next.offset_after(), ZoneOffset::of_hours(1));
+        assert_eq!(rules.previous_transition(next.instant()).unwrap().instant(), midnight);
+    }
+
+    #[test]
+    #[should_panic(expected = "offsets must differ for a transition")]
+    fn transition_requires_different_offsets() {
+        let instant = LocalDateTime::of(2024, 1, 1, 0, 0, 0).to_instant_utc();
+        ZoneOffsetTransition::of(instant, ZoneOffset::UTC, ZoneOffset::UTC);
+    }
+
+    #[test]
+    fn compiled_tz_data_yields_transitions() {
+        let rules = new_york().rules();
+        assert!(!rules.is_fixed_offset());
+        let start = LocalDateTime::of(1900, 1, 1, 0, 0, 0).to_instant_utc();
+        let mut count = 0;
+        let mut cursor = start;
+        while let Some(transition) = rules.next_transition(cursor) {
+            cursor = transition.instant();
+            count += 1;
+        }
+        assert!(count > 200, "only {count} transitions for America/New_York");
+        assert!(rules.transition(LocalDateTime::of(2024, 3, 10, 2, 30, 0)).is_some());
+    }
+
+    #[test]
+    fn compiled_tz_data_yields_spans_back_to_the_previous_offset() {
+        // Noronha kept daylight saving time for a week in October 2000.
+        let noronha = ZoneId::try_of("America/Noronha").unwrap().rules();
+        let start = noronha.next_transition(LocalDateTime::of(2000, 10, 1, 0, 0, 0).to_instant_utc()).unwrap();
+        let end = noronha.next_transition(start.instant()).unwrap();
+        assert!(start.is_gap());
+        assert!(end.is_overlap());
+        assert_eq!(end.offset_after(), start.offset_before());
+    }
+
+    #[test]
+    fn compiled_tz_data_ends_in_2099() {
+        let rules = new_york().rules();
+        let last = rules.previous_transition(LocalDateTime::of(2100, 1, 1, 0, 0, 0).to_instant_utc()).unwrap();
+        assert_eq!(last.instant(), LocalDateTime::of(2099, 11, 1, 6, 0, 0).to_instant_utc());
+        assert_eq!(rules.next_transition(last.instant()), None);
+        assert!(rules.transitions_in(Year::of(2100)).is_empty());
+    }
+}
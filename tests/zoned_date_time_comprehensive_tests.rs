@@ -94,19 +94,19 @@ mod tests {
     fn comparison_helpers_for_zoned_date_time() {
         let a = ZonedDateTime::of(LocalDateTime::of(2020, 1, 1, 0, 0, 0), ZoneId::UTC);
         let b = a.plus_seconds(1);
-        let c = b;
+        let c = b.clone();
 
-        assert!(a.is_before(b));
-        assert!(!b.is_before(a));
+        assert!(a.is_before(&b));
+        assert!(!b.is_before(&a));
 
-        assert!(b.is_after(a));
-        assert!(!a.is_after(b));
+        assert!(b.is_after(&a));
+        assert!(!a.is_after(&b));
 
-        assert!(a.is_on_or_before(b));
-        assert!(b.is_on_or_before(c)); // equality case
+        assert!(a.is_on_or_before(&b));
+        assert!(b.is_on_or_before(&c)); // equality case
 
-        assert!(b.is_on_or_after(a));
-        assert!(b.is_on_or_after(c)); // equality case
+        assert!(b.is_on_or_after(&a));
+        assert!(b.is_on_or_after(&c)); // equality case
     }
 
     #[test]
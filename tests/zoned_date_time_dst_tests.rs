@@ -0,0 +1,91 @@
+mod tests {
+    use joda_rs::{Instant, LocalDateTime, ZoneId, ZoneOffset, ZonedDateTime};
+
+    fn new_york() -> ZoneId {
+        ZoneId::try_of("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn keeps_zone_and_resolves_offset_per_date() {
+        let winter = ZonedDateTime::of(LocalDateTime::of(2024, 1, 15, 12, 0, 0), new_york());
+        let summer = ZonedDateTime::of(LocalDateTime::of(2024, 7, 15, 12, 0, 0), new_york());
+
+        assert_eq!(winter.zone(), new_york());
+        assert_eq!(summer.zone(), new_york());
+        assert_eq!(winter.offset(), ZoneOffset::of_hours(-5));
+        assert_eq!(summer.offset(), ZoneOffset::of_hours(-4));
+    }
+
+    #[test]
+    fn plus_weeks_across_spring_forward_keeps_wall_clock_time() {
+        let before = ZonedDateTime::of(LocalDateTime::of(2024, 3, 5, 12, 0, 0), new_york());
+        let after = before.plus_weeks(1);
+
+        assert_eq!(after.to_local_date_time(), LocalDateTime::of(2024, 3, 12, 12, 0, 0));
+        assert_eq!(after.offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(after.zone(), new_york());
+        // One calendar week across the change is only 167 hours.
+        assert_eq!(after.epoch_seconds() - before.epoch_seconds(), 167 * 3600);
+    }
+
+    #[test]
+    fn plus_days_and_months_re_resolve_offset() {
+        let base = ZonedDateTime::of(LocalDateTime::of(2024, 10, 31, 9, 0, 0), new_york());
+        assert_eq!(base.offset(), ZoneOffset::of_hours(-4));
+
+        let days = base.plus_days(7);
+        assert_eq!(days.to_local_date_time(), LocalDateTime::of(2024, 11, 7, 9, 0, 0));
+        assert_eq!(days.offset(), ZoneOffset::of_hours(-5));
+
+        let months = base.plus_months(1);
+        assert_eq!(months.to_local_date_time(), LocalDateTime::of(2024, 11, 30, 9, 0, 0));
+        assert_eq!(months.offset(), ZoneOffset::of_hours(-5));
+    }
+
+    #[test]
+    fn plus_hours_works_on_the_instant() {
+        let base = ZonedDateTime::of(LocalDateTime::of(2024, 3, 10, 1, 30, 0), new_york());
+        let later = base.plus_hours(1);
+
+        assert_eq!(later.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 3, 30, 0));
+        assert_eq!(later.offset(), ZoneOffset::of_hours(-4));
+    }
+
+    #[test]
+    fn local_date_time_in_gap_is_shifted_forward() {
+        let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 3, 10, 2, 30, 0), new_york());
+
+        assert_eq!(zdt.to_local_date_time(), LocalDateTime::of(2024, 3, 10, 3, 30, 0));
+        assert_eq!(zdt.offset(), ZoneOffset::of_hours(-4));
+    }
+
+    #[test]
+    fn local_date_time_in_overlap_uses_earlier_offset() {
+        let zdt = ZonedDateTime::of(LocalDateTime::of(2024, 11, 3, 1, 30, 0), new_york());
+
+        assert_eq!(zdt.offset(), ZoneOffset::of_hours(-4));
+    }
+
+    #[test]
+    fn with_methods_keep_later_offset_in_overlap() {
+        let first = ZonedDateTime::of(LocalDateTime::of(2024, 11, 3, 0, 30, 0), new_york());
+        let second_pass = first.plus_hours(2);
+        assert_eq!(second_pass.to_local_date_time(), LocalDateTime::of(2024, 11, 3, 1, 30, 0));
+        assert_eq!(second_pass.offset(), ZoneOffset::of_hours(-5));
+
+        let adjusted = second_pass.with_minute(45);
+        assert_eq!(adjusted.to_local_date_time(), LocalDateTime::of(2024, 11, 3, 1, 45, 0));
+        assert_eq!(adjusted.offset(), ZoneOffset::of_hours(-5));
+    }
+
+    #[test]
+    fn instant_at_zone_keeps_zone_and_instant() {
+        let instant = Instant::of_epoch_second(1_720_000_000); // 2024-07-03T09:46:40Z
+        let zdt = instant.at_zone(new_york());
+
+        assert_eq!(zdt.zone(), new_york());
+        assert_eq!(zdt.offset(), ZoneOffset::of_hours(-4));
+        assert_eq!(zdt.to_instant(), instant);
+        assert_eq!(zdt.to_local_date_time(), LocalDateTime::of(2024, 7, 3, 5, 46, 40));
+    }
+}
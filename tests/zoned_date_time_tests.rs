@@ -19,4 +19,22 @@ mod tests {
             (odt_utc.year(), odt_utc.month() as u8, odt_utc.day_of_month(), odt_utc.hour(), odt_utc.minute(), odt_utc.second())
         );
     }
+
+    #[test]
+    fn comparisons_use_the_instant_only() {
+        let utc = ZonedDateTime::of(LocalDateTime::of(2024, 7, 3, 9, 46, 40), ZoneId::UTC);
+        let paris = utc.with_zone_same_instant(ZoneId::try_of("Europe/Paris").unwrap());
+
+        assert!(!paris.is_before(&utc));
+        assert!(!utc.is_after(&paris));
+        assert!(utc.is_on_or_before(&paris));
+        assert!(utc.is_on_or_after(&paris));
+        assert!(paris.is_on_or_before(&utc));
+        assert!(paris.is_on_or_after(&utc));
+        assert_ne!(utc, paris);
+
+        // Like java.time, the ordering breaks ties by local date-time, then by zone ID.
+        assert!(utc < paris);
+        assert!(utc.plus_seconds(1) > paris);
+    }
 }